@@ -0,0 +1,262 @@
+/*****************************************************************************************************************
+
+This source file implements Plonk circuit builder primitive.
+
+The builder allocates the circuit variables along with their witness values and lays out the gates
+row by row. When built, the variables used in several gate wires are wired together by the permutation
+cycles and the circuit is padded to the evaluation domain leaving the last rows to the zero-knowledge
+blinding of the permutation argument.
+
+The table lookups are laid out as the single rows of the l, r, o wires holding the key followed by its
+row values, constrained by the vector lookup argument against the table rather than by the generic gate,
+so that the number of the rows of the lookup does not depend on the table size. All the lookups of the
+circuit are against the same table, the domain fitting both the rows and the table.
+
+*****************************************************************************************************************/
+
+use algebra::{FftField, SquareRootField};
+use ff_fft::{EvaluationDomain, Radix2EvaluationDomain as D};
+use crate::gate::CircuitGate;
+use crate::wires::{GateWires, COLUMNS};
+use crate::lookup::LookupTable;
+use crate::constraints::ConstraintSystem;
+
+// number of the last domain rows reserved for the permutation argument blinding
+pub const ZK_ROWS: usize = 3;
+
+// reference to a circuit variable
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WireRef(pub usize);
+
+// generic gate row of the circuit under construction
+#[derive(Clone, Copy, Debug)]
+struct Row<F>
+{
+    wires: [Option<WireRef>; 3],    // left, right and output variables
+    c: [F; 5],                      // ql, qr, qo, qm, qc selectors
+}
+
+pub struct CircuitBuilder<F: FftField>
+{
+    pub public: usize,      // number of public inputs
+    values: Vec<F>,         // witness values of the variables
+    rows: Vec<Row<F>>,      // gate rows
+    table: Option<LookupTable<F>>,  // lookup table of the circuit
+    lookups: Vec<usize>,    // lookup rows
+}
+
+impl<F: FftField> CircuitBuilder<F>
+{
+    pub fn new() -> Self
+    {
+        CircuitBuilder
+        {
+            public: 0,
+            values: Vec::new(),
+            rows: Vec::new(),
+            table: None,
+            lookups: Vec::new(),
+        }
+    }
+
+    // This function returns the number of the laid out rows, the padding excluded
+    pub fn rows(&self) -> usize
+    {
+        self.rows.len()
+    }
+
+    // This function allocates a private variable
+    //     value: witness value of the variable
+    //     RETURN: variable reference
+    pub fn var(&mut self, value: F) -> WireRef
+    {
+        self.values.push(value);
+        WireRef(self.values.len() - 1)
+    }
+
+    // This function returns the witness value of the variable
+    pub fn value(&self, a: WireRef) -> F
+    {
+        self.values[a.0]
+    }
+
+    // This function allocates a public input variable, public inputs have to precede the gates
+    //     value: public input value
+    //     RETURN: variable reference
+    pub fn public_input(&mut self, value: F) -> WireRef
+    {
+        assert_eq!(self.rows.len(), self.public, "public inputs have to precede the gates");
+        let a = self.var(value);
+        self.generic([Some(a), None, None], [F::one(), F::zero(), F::zero(), F::zero(), F::zero()]);
+        self.public += 1;
+        a
+    }
+
+    // This function adds the generic gate
+    //     ql*l + qr*r + qo*o + qm*l*r + qc = 0
+    //     wires: left, right and output variables, unused wires are unconstrained and zero
+    //     c: ql, qr, qo, qm, qc selectors
+    pub fn generic(&mut self, wires: [Option<WireRef>; 3], c: [F; 5])
+    {
+        self.rows.push(Row {wires, c});
+    }
+
+    // This function constrains the sum of the variables
+    //     RETURN: a + b variable
+    pub fn add(&mut self, a: WireRef, b: WireRef) -> WireRef
+    {
+        let c = self.var(self.value(a) + &self.value(b));
+        self.generic([Some(a), Some(b), Some(c)], [F::one(), F::one(), -F::one(), F::zero(), F::zero()]);
+        c
+    }
+
+    // This function constrains the difference of the variables
+    //     RETURN: a - b variable
+    pub fn sub(&mut self, a: WireRef, b: WireRef) -> WireRef
+    {
+        let c = self.var(self.value(a) - &self.value(b));
+        self.generic([Some(a), Some(b), Some(c)], [F::one(), -F::one(), -F::one(), F::zero(), F::zero()]);
+        c
+    }
+
+    // This function constrains the product of the variables
+    //     RETURN: a * b variable
+    pub fn mul(&mut self, a: WireRef, b: WireRef) -> WireRef
+    {
+        let c = self.var(self.value(a) * &self.value(b));
+        self.generic([Some(a), Some(b), Some(c)], [F::zero(), F::zero(), -F::one(), F::one(), F::zero()]);
+        c
+    }
+
+    // This function allocates the variable constrained to the constant
+    //     value: constant value
+    //     RETURN: variable reference
+    pub fn constant(&mut self, value: F) -> WireRef
+    {
+        let a = self.var(value);
+        self.assert_constant(a, value);
+        a
+    }
+
+    // This function constrains the variable to the constant
+    pub fn assert_constant(&mut self, a: WireRef, value: F)
+    {
+        self.generic([Some(a), None, None], [F::one(), F::zero(), F::zero(), F::zero(), -value]);
+    }
+
+    // This function constrains the variables to be equal
+    pub fn assert_eq(&mut self, a: WireRef, b: WireRef)
+    {
+        self.generic([Some(a), Some(b), None], [F::one(), -F::one(), F::zero(), F::zero(), F::zero()]);
+    }
+
+    // This function constrains the variable to be boolean
+    //     a*a - a = 0
+    pub fn boolean(&mut self, a: WireRef)
+    {
+        self.generic([Some(a), Some(a), None], [-F::one(), F::zero(), F::zero(), F::one(), F::zero()]);
+    }
+
+    // This function constrains the conditional selection of the variables
+    //     out = sel*a + (1-sel)*b = sel*(a-b) + b
+    //     with sel being constrained to be boolean
+    //     sel: selector variable
+    //     RETURN: a variable if sel is one, b variable if sel is zero
+    pub fn select(&mut self, sel: WireRef, a: WireRef, b: WireRef) -> WireRef
+    {
+        let (z, o) = (F::zero(), F::one());
+        self.boolean(sel);
+        let d = self.sub(a, b);
+        let m = self.var(self.value(sel) * &self.value(d));
+        self.generic([Some(sel), Some(d), Some(m)], [z, z, -o, o, z]);
+        self.add(m, b)
+    }
+
+    // This function constrains the key to belong to the table and looks up its table column value
+    //     the lookup row holds the key and its row values, constrained by the vector lookup argument to be
+    //     the table row, the values of the key missing from the table being zero and failing the argument
+    //     table: lookup table of up to three columns
+    //     key: key variable
+    //     column: table column
+    //     RETURN: value variable, None if the table is not the one of the earlier lookups of the circuit
+    //         or the column is not in the table
+    pub fn lookup(&mut self, table: &LookupTable<F>, key: WireRef, column: usize) -> Option<WireRef>
+    {
+        let width = table.rows[0].len();
+        if width > COLUMNS || column >= width {return None}
+        if self.table.as_ref().map_or(false, |t| t != table) {return None}
+        if self.table.is_none() {self.table = Some(table.clone())}
+
+        let k = self.value(key);
+        let mut wires = [Some(key), None, None];
+        for c in 1..width
+        {
+            wires[c] = Some(self.var(table.get(k, c).unwrap_or_else(F::zero)));
+        }
+        self.lookups.push(self.rows.len());
+        self.generic(wires, [F::zero(); 5]);
+        wires[column]
+    }
+
+    // This function computes the domain size of the circuit
+    pub fn domain_size(&self) -> usize
+    {
+        let table = self.table.as_ref().map_or(0, |t| t.rows.len());
+        D::<F>::compute_size_of_domain(std::cmp::max(self.rows.len(), table) + ZK_ROWS).unwrap()
+    }
+
+    // This function builds the circuit
+    //     RETURN: circuit gates padded to the domain size along with the witness
+    pub fn build(&self) -> (Vec<CircuitGate<F>>, Vec<F>)
+    {
+        let n = self.domain_size();
+        let mut witness = vec![F::zero(); COLUMNS*n];
+
+        // positions of the variables in the witness
+        let mut positions = vec![Vec::new(); self.values.len()];
+        for (i, row) in self.rows.iter().enumerate()
+        {
+            for (col, w) in row.wires.iter().enumerate()
+            {
+                if let Some(a) = w
+                {
+                    positions[a.0].push(col*n + i);
+                    witness[col*n + i] = self.values[a.0];
+                }
+            }
+        }
+
+        // wire the positions of each variable into a permutation cycle
+        let mut perm = (0..COLUMNS*n).collect::<Vec<_>>();
+        for cycle in positions.iter()
+        {
+            (0..cycle.len()).for_each(|j| perm[cycle[j]] = cycle[(j+1) % cycle.len()]);
+        }
+
+        let wires = |i: usize| GateWires::wires((i, perm[i]), (n+i, perm[n+i]), (2*n+i, perm[2*n+i]));
+        let mut gates = self.rows.iter().enumerate().map
+        (
+            |(i, row)| CircuitGate::<F>::create_generic(wires(i), row.c[0], row.c[1], row.c[2], row.c[3], row.c[4])
+        ).collect::<Vec<_>>();
+        (gates.len()..n).for_each(|i| gates.push(CircuitGate::<F>::zero(wires(i))));
+
+        (gates, witness)
+    }
+}
+
+impl<F: FftField + SquareRootField> CircuitBuilder<F>
+{
+    // This function adds the lookup argument of the lookup rows of the circuit to its constraint system
+    //     cs: constraint system of the built circuit gates
+    //     RETURN: constraint system with the lookup argument, unchanged if the circuit has no lookups,
+    //         None if the constraint system is not of the circuit domain
+    pub fn with_lookup(&self, cs: ConstraintSystem<F>) -> Option<ConstraintSystem<F>>
+    {
+        if cs.domain.d1.size() != self.domain_size() {return None}
+        match &self.table
+        {
+            None => Some(cs),
+            Some(table) => cs.with_vector_lookup(table, &self.lookups),
+        }
+    }
+}
@@ -9,22 +9,26 @@ use oracle::poseidon::{SpongeConstants, PlonkSpongeConstants, ArithmeticSpongePa
 use ff_fft::{EvaluationDomain, DensePolynomial, Evaluations, Radix2EvaluationDomain as D};
 pub use super::polynomial::{WitnessOverDomains, WitnessShifts, WitnessEvals};
 pub use super::gate::{CircuitGate, GateType};
-pub use super::domains::EvaluationDomains;
-pub use super::wires::GateWires;
+pub use super::domains::{EvaluationDomains, PaddingDiagnostic};
+pub use super::wires::{GateWires, COLUMNS};
 use blake2::{Blake2b, Digest};
 use oracle::utils::EvalUtils;
 use array_init::array_init;
+use crate::polynomials::lookup::LookupConstraints;
+use crate::rle::GateRuns;
+use std::borrow::Cow;
 
 #[derive(Clone)]
 pub struct ConstraintSystem<F: FftField>
 {
     pub public: usize,                      // number of public inputs
     pub domain: EvaluationDomains<F>,       // evaluation domains
-    pub gates:  Vec<CircuitGate<F>>,        // circuit gates
+    pub gates:  Vec<CircuitGate<F>>,        // circuit gates, empty for the run-length encoded circuit
+    pub runs:   Option<GateRuns<F>>,        // gate runs of the run-length encoded circuit
 
     // POLYNOMIALS OVER THE MONOMIAL BASE
 
-    pub sigmam: [DensePolynomial<F>; 3],    // permutation polynomial array
+    pub sigmam: [DensePolynomial<F>; COLUMNS], // permutation polynomial array
     pub zkpm:   DensePolynomial<F>,         // zero-knowledge polynomial
 
     // generic constraint selector polynomials
@@ -34,6 +38,10 @@ pub struct ConstraintSystem<F: FftField>
     pub qmm:    DensePolynomial<F>,         // multiplication polynomial
     pub qc:     DensePolynomial<F>,         // constant wire polynomial
 
+    // generic constraint selectors identically zero, in the ql, qr, qo, qm, qc order,
+    // skipped by the quotient and the linearization computations
+    pub gnrc_zero: [bool; 5],
+
     // poseidon selector polynomials
     pub rcm:    [DensePolynomial<F>; PlonkSpongeConstants::SPONGE_WIDTH], // round constant polynomials
     pub psm:    DensePolynomial<F>,         // poseidon constraint selector polynomial
@@ -57,8 +65,8 @@ pub struct ConstraintSystem<F: FftField>
     pub qml:    Evaluations<F, D<F>>,       // multiplication evaluations over domain.d4
 
     // permutation polynomials
-    pub sigmal1:[Vec<F>; 3],                // permutation polynomial array evaluations over domain d1
-    pub sigmal4:[Evaluations<F, D<F>>; 3],  // permutation polynomial array evaluations over domain d8
+    pub sigmal1:[Vec<F>; COLUMNS],          // permutation polynomial array evaluations over domain d1
+    pub sigmal4:[Evaluations<F, D<F>>; COLUMNS], // permutation polynomial array evaluations over domain d8
     pub sid:    Vec<F>,                     // SID polynomial
 
     // poseidon selector polynomials
@@ -84,8 +92,40 @@ pub struct ConstraintSystem<F: FftField>
 
     // random oracle argument parameters
     pub fr_sponge_params: ArithmeticSpongeParams<F>,
+
+    // lookup argument constraints
+    pub lookup: Option<LookupConstraints<F>>,
 }
 
+// first constraint of the circuit unsatisfied by the witness
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnsatisfiedConstraint<F: FftField>
+{
+    WitnessLength {expected: usize, found: usize},  // witness length does not match the domain
+    Gate {row: usize, typ: GateType, wires: (F, F, F)}, // gate constraint with its l, r, o wire values
+    CopyConstraint {wire: usize, next: usize, values: (F, F)}, // distinct values of the permutation cycle
+    Lookup,                                         // looked up wire values out of the lookup table
+}
+
+impl<F: FftField> std::fmt::Display for UnsatisfiedConstraint<F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        match self
+        {
+            UnsatisfiedConstraint::WitnessLength {expected, found} =>
+                write!(f, "witness length {} does not match the expected {}", found, expected),
+            UnsatisfiedConstraint::Gate {row, typ, wires} =>
+                write!(f, "{:?} gate {} is unsatisfied by the wire values l = {}, r = {}, o = {}", typ, row, wires.0, wires.1, wires.2),
+            UnsatisfiedConstraint::CopyConstraint {wire, next, values} =>
+                write!(f, "wires {} and {} of the permutation cycle hold distinct values {} and {}", wire, next, values.0, values.1),
+            UnsatisfiedConstraint::Lookup => write!(f, "looked up wire values are not in the lookup table"),
+        }
+    }
+}
+
+impl<F: FftField> std::error::Error for UnsatisfiedConstraint<F> {}
+
 pub fn zk_w<F:FftField>(domain : D<F>) -> F {
     domain.group_gen.pow(&[domain.size - 3])
 }
@@ -105,8 +145,73 @@ pub fn zk_polynomial<F:FftField>(domain : D<F>) -> DensePolynomial<F> {
     ])
 }
 
+// This function computes the public input polynomial -sum_i public_i * L_i(x). When the
+// public inputs are sparse over the domain, instead of the full domain interpolation the
+// Lagrange basis polynomials of the nonzero public inputs are summed up directly, since
+// L_i(x) = sum_j w^(-ij) x^j / n costs the domain size of multiplications per input
+//     domain: evaluation domain
+//     public: public inputs assigned to the first domain rows
+//     RETURN: public input polynomial
+pub fn public_polynomial<F:FftField>(domain : D<F>, public : &[F]) -> DensePolynomial<F> {
+    let nonzero = public.iter().enumerate().filter(|(_, p)| !p.is_zero()).collect::<Vec<_>>();
+    if nonzero.len() == 0 {return DensePolynomial::<F>::zero()}
+    if 2 * nonzero.len() > domain.log_size_of_group as usize
+    {
+        return -Evaluations::<F, D<F>>::from_vec_and_domain(public.to_vec(), domain).interpolate()
+    }
+
+    let mut coeffs = vec![F::zero(); domain.size()];
+    for (i, p) in nonzero.iter()
+    {
+        let w = domain.group_gen_inv.pow(&[*i as u64]);
+        let mut c = -**p * &domain.size_inv;
+        coeffs.iter_mut().for_each(|x| {*x += &c; c *= &w});
+    }
+    DensePolynomial::<F>::from_coefficients_vec(coeffs)
+}
+
+impl<F: FftField> ConstraintSystem<F>
+{
+    // This function returns the gate of the domain row, expanded from its run for the run-length encoded circuit
+    pub fn gate(&self, row: usize) -> Cow<CircuitGate<F>>
+    {
+        match &self.runs
+        {
+            Some(runs) => Cow::Owned(runs.gate(row)),
+            None => Cow::Borrowed(&self.gates[row]),
+        }
+    }
+
+    // This function iterates over the gates of the domain rows in the row order
+    pub fn gate_iter<'a>(&'a self) -> Box<dyn Iterator<Item = Cow<'a, CircuitGate<F>>> + 'a>
+    {
+        match &self.runs
+        {
+            Some(runs) => Box::new((0..self.domain.d1.size()).map(move |i| Cow::Owned(runs.gate(i)))),
+            None => Box::new(self.gates.iter().map(Cow::Borrowed)),
+        }
+    }
+}
+
 impl<F: FftField + SquareRootField> ConstraintSystem<F>
 {
+    // This function computes the highest degree of the circuit gate constraints
+    pub fn max_gate_degree(&self) -> usize
+    {
+        self.gate_iter().map(|gate| gate.degree()).max().unwrap_or(0)
+    }
+
+    // This function diagnoses the padding of the circuit to the radix-2 evaluation domain. The domain
+    // has to be a power of two for the vanishing polynomial division and the permutation shifts, the
+    // circuits of the other sizes being padded with zero gates by create
+    //     RETURN: rows the circuit needs along with the domain size
+    pub fn padding(&self) -> PaddingDiagnostic
+    {
+        // the last three rows are the zero-knowledge ones
+        let gates = self.gate_iter().enumerate().filter(|(_, gate)| gate.typ != GateType::Zero).last().map_or(0, |(i, _)| i+1);
+        PaddingDiagnostic {rows: gates + 3, domain: self.domain.d1.size()}
+    }
+
     pub fn create
     (
         mut gates: Vec<CircuitGate<F>>,
@@ -115,34 +220,68 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
     ) -> Option<Self>
     {
         let domain = EvaluationDomains::<F>::create(gates.len())?;
+        let n = domain.d1.size();
+        let mut padding = (gates.len()..n).map(|i| CircuitGate::<F>::zero(GateWires::wires((i,i), (n+i,n+i), (2*n+i,2*n+i)))).collect();
+        gates.append(&mut padding);
+        Self::compile(domain, gates, None, fr_sponge_params, public)
+    }
+
+    // This function computes the constraint system polynomials from the gates of the domain rows, the
+    // gates being visited once in the row order, so that the gates of the runs are expanded one by one
+    //     domain: evaluation domains
+    //     gates: circuit gates of the domain rows, empty with the gate runs
+    //     runs: gate runs of the run-length encoded circuit
+    //     fr_sponge_params: Fr sponge parameters
+    //     public: number of the public inputs
+    //     RETURN: constraint system
+    pub(crate) fn compile
+    (
+        domain: EvaluationDomains<F>,
+        gates: Vec<CircuitGate<F>>,
+        runs: Option<GateRuns<F>>,
+        fr_sponge_params: ArithmeticSpongeParams<F>,
+        public: usize,
+    ) -> Option<Self>
+    {
         let mut sid = domain.d1.elements().map(|elm| {elm}).collect::<Vec<_>>();
 
         // sample the coordinate shifts
         let (r, o) = Self::sample_shifts(&domain.d1);
 
         let n = domain.d1.size();
-        let mut padding = (gates.len()..n).map(|i| CircuitGate::<F>::zero(GateWires::wires((i,i), (n+i,n+i), (2*n+i,2*n+i)))).collect();
-        gates.append(&mut padding);
-
-        let s =
-        [
-            sid.clone(),
-            domain.d1.elements().map(|elm| {r * &elm}).collect(),
-            domain.d1.elements().map(|elm| {o * &elm}).collect(),
-        ];
+        let shifts = [F::one(), r, o];
+        let s: [Vec<F>; COLUMNS] = array_init(|c| domain.d1.elements().map(|elm| {shifts[c] * &elm}).collect());
         let mut sigmal1 = s.clone();
 
-        // compute permutation polynomials
-        gates.iter().for_each
-        (
-            |gate|
+        // selector evaluations over domain d1 in the ql, qr, qo, qm, qc, poseidon, addition, vbmul1, vbmul2,
+        // endomul1, endomul2, endomul3 and the three round constant order
+        let mut selectors = vec![Vec::with_capacity(n); 15];
+        {
+            let mut visit = |gate: &CircuitGate<F>|
             {
-                sigmal1[0][gate.wires.l.0] = s[gate.wires.l.1 / n][gate.wires.l.1 % n];
-                sigmal1[1][gate.wires.r.0-n] = s[gate.wires.r.1 / n][gate.wires.r.1 % n];
-                sigmal1[2][gate.wires.o.0-2*n] = s[gate.wires.o.1 / n][gate.wires.o.1 % n];
+                // compute permutation polynomials
+                for (c, wire) in gate.wires.columns().iter().enumerate()
+                {
+                    sigmal1[c][wire.0 - c*n] = s[wire.1 / n][wire.1 % n];
+                }
+                let rc = gate.rc();
+                let values =
+                [
+                    gate.ql(), gate.qr(), gate.qo(), gate.qm(), gate.qc(), gate.ps(), gate.add1(), gate.vbmul1(), gate.vbmul2(),
+                    gate.endomul1(), gate.endomul2(), gate.endomul3(), rc[0], rc[1], rc[2],
+                ];
+                selectors.iter_mut().zip(values.iter()).for_each(|(e, v)| e.push(*v));
+            };
+            match &runs
+            {
+                Some(runs) => (0..n).for_each(|i| visit(&runs.gate(i))),
+                None => {if gates.len() != n {return None} gates.iter().for_each(|gate| visit(gate))}
             }
-        );
-        let sigmam: [DensePolynomial<F>; 3] = array_init
+        }
+        let mut selectors = selectors.into_iter().map(|e| Evaluations::<F, D<F>>::from_vec_and_domain(e, domain.d1).interpolate());
+        let mut selector = || selectors.next().unwrap();
+
+        let sigmam: [DensePolynomial<F>; COLUMNS] = array_init
             (|i| Evaluations::<F, D<F>>::from_vec_and_domain(sigmal1[i].clone(), domain.d1).interpolate());
 
         let mut s = sid[0..2].to_vec();
@@ -152,21 +291,24 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         let zkpm = zk_polynomial(domain.d1);
 
         // compute generic constraint polynomials
-        let qlm = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.ql()).collect(), domain.d1).interpolate();
-        let qrm = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.qr()).collect(), domain.d1).interpolate();
-        let qom = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.qo()).collect(), domain.d1).interpolate();
-        let qmm = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.qm()).collect(), domain.d1).interpolate();
+        let qlm = selector();
+        let qrm = selector();
+        let qom = selector();
+        let qmm = selector();
+        let qc = selector();
+        let gnrc_zero = [qlm.is_zero(), qrm.is_zero(), qom.is_zero(), qmm.is_zero(), qc.is_zero()];
 
         // compute poseidon constraint polynomials
-        let psm = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.ps()).collect(), domain.d1).interpolate();
+        let psm = selector();
 
         // compute ECC arithmetic constraint polynomials
-        let addm = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.add1()).collect(), domain.d1).interpolate();
-        let mul1m = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.vbmul1()).collect(), domain.d1).interpolate();
-        let mul2m = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.vbmul2()).collect(), domain.d1).interpolate();
-        let emul1m = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.endomul1()).collect(), domain.d1).interpolate();
-        let emul2m = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.endomul2()).collect(), domain.d1).interpolate();
-        let emul3m = Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.endomul3()).collect(), domain.d1).interpolate();
+        let addm = selector();
+        let mul1m = selector();
+        let mul2m = selector();
+        let emul1m = selector();
+        let emul2m = selector();
+        let emul3m = selector();
+        let rcm: [DensePolynomial<F>; PlonkSpongeConstants::SPONGE_WIDTH] = array_init(|_| selector());
 
         Some(ConstraintSystem
         {
@@ -186,10 +328,11 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
             qrm,
             qom,
             qmm,
-            qc: Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.qc()).collect(), domain.d1).interpolate(),
+            qc,
+            gnrc_zero,
 
             // poseidon constraint polynomials
-            rcm: array_init(|i| Evaluations::<F, D<F>>::from_vec_and_domain(gates.iter().map(|gate| gate.rc()[i]).collect(), domain.d1).interpolate()),
+            rcm,
             ps4: psm.evaluate_over_domain_by_ref(domain.d4),
             ps8: psm.evaluate_over_domain_by_ref(domain.d8),
             psm,
@@ -215,10 +358,12 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
             zkpm,
 
             gates,
+            runs,
             r,
             o,
             endo: F::zero(),
             fr_sponge_params,
+            lookup: None,
         })
     }
 
@@ -232,23 +377,122 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         witness: &Vec<F>
     ) -> bool
     {
-        if witness.len() != 3*self.domain.d1.size() {return false}
-        for i in self.public..self.gates.len()
+        let n = self.domain.d1.size();
+        if witness.len() != COLUMNS*n {return false}
+        let mut gates = self.gate_iter().skip(self.public).peekable();
+        while let Some(gate) = gates.next()
         {
             if
             // verify permutation consistency
-            witness[self.gates[i].wires.l.1] != witness[self.gates[i].wires.l.0] ||
-            witness[self.gates[i].wires.r.1] != witness[self.gates[i].wires.r.0] ||
-            witness[self.gates[i].wires.o.1] != witness[self.gates[i].wires.o.0] ||
+            witness[gate.wires.l.1] != witness[gate.wires.l.0] ||
+            witness[gate.wires.r.1] != witness[gate.wires.r.0] ||
+            witness[gate.wires.o.1] != witness[gate.wires.o.0] ||
 
             // verify witness against constraints
-            !self.gates[i].verify(if i+1==self.gates.len() {&self.gates[i]}
-                                                      else {&self.gates[i+1]}, witness, &self)
+            !gate.verify(gates.peek().unwrap_or(&gate), witness, &self)
             {
                 return false
             }
         }
-        true
+
+        // verify the looked up wire values against the lookup table
+        let w =
+        [
+            self.gate_iter().map(|gate| witness[gate.wires.l.0]).collect::<Vec<_>>(),
+            self.gate_iter().map(|gate| witness[gate.wires.r.0]).collect::<Vec<_>>(),
+            self.gate_iter().map(|gate| witness[gate.wires.o.0]).collect::<Vec<_>>(),
+        ];
+        self.lookup.is_none() || self.lookup_multiplicities(&[&w[0], &w[1], &w[2]]).is_some()
+    }
+
+    // This function validates the wire assignements (witness) against the copy constraints
+    // and the gate constraints, the public input contributing to the generic constraints
+    // of the public input rows, and against the lookup table
+    //     witness: wire assignement witness
+    //     RETURN: first unsatisfied constraint along with the wire values
+    pub fn verify_witness
+    (
+        &self,
+        witness: &[F]
+    ) -> Result<(), UnsatisfiedConstraint<F>>
+    {
+        self.verify_permutation(witness)?;
+        self.verify_gates(witness)?;
+
+        // verify the looked up wire values against the lookup table
+        let w =
+        [
+            self.gate_iter().map(|gate| witness[gate.wires.l.0]).collect::<Vec<_>>(),
+            self.gate_iter().map(|gate| witness[gate.wires.r.0]).collect::<Vec<_>>(),
+            self.gate_iter().map(|gate| witness[gate.wires.o.0]).collect::<Vec<_>>(),
+        ];
+        if self.lookup.is_some() && self.lookup_multiplicities(&[&w[0], &w[1], &w[2]]).is_none()
+        {
+            return Err(UnsatisfiedConstraint::Lookup)
+        }
+        Ok(())
+    }
+
+    // This function validates the wire assignements (witness) against the copy constraints
+    // of the permutation, each wire holding the value of the next wire of its cycle
+    //     witness: wire assignement witness
+    //     RETURN: first inconsistent wire pair along with the wire values
+    pub fn verify_permutation
+    (
+        &self,
+        witness: &[F]
+    ) -> Result<(), UnsatisfiedConstraint<F>>
+    {
+        self.verify_length(witness)?;
+        for gate in self.gate_iter()
+        {
+            for (wire, next) in gate.wires.columns().iter()
+            {
+                if witness[*wire] != witness[*next]
+                {
+                    return Err(UnsatisfiedConstraint::CopyConstraint {wire: *wire, next: *next, values: (witness[*wire], witness[*next])})
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // This function validates the wire assignements (witness) against the gate constraints,
+    // the public input contributing to the generic constraints of the public input rows
+    //     witness: wire assignement witness
+    //     RETURN: first unsatisfied gate along with the wire values
+    pub fn verify_gates
+    (
+        &self,
+        witness: &[F]
+    ) -> Result<(), UnsatisfiedConstraint<F>>
+    {
+        self.verify_length(witness)?;
+        let witness = witness.to_vec();
+        let mut gates = self.gate_iter().enumerate().peekable();
+        while let Some((row, gate)) = gates.next()
+        {
+            let wires = (witness[gate.wires.l.0], witness[gate.wires.r.0], witness[gate.wires.o.0]);
+            let satisfied = if gate.typ == GateType::Generic
+            {
+                let public = if row < self.public {witness[row]} else {F::zero()};
+                (gate.qm() * &wires.0 * &wires.1 + &(gate.ql() * &wires.0) + &(gate.qr() * &wires.1) +
+                    &(gate.qo() * &wires.2) + &gate.qc() - &public).is_zero()
+            }
+            else {gate.verify(gates.peek().map_or(&gate, |(_, next)| next), &witness, &self)};
+            if !satisfied {return Err(UnsatisfiedConstraint::Gate {row, typ: gate.typ.clone(), wires})}
+        }
+        Ok(())
+    }
+
+    fn verify_length(&self, witness: &[F]) -> Result<(), UnsatisfiedConstraint<F>>
+    {
+        let n = self.domain.d1.size();
+        if witness.len() != COLUMNS*n
+        {
+            return Err(UnsatisfiedConstraint::WitnessLength {expected: COLUMNS*n, found: witness.len()})
+        }
+        Ok(())
     }
 
     // sample coordinate shifts deterministically
@@ -266,6 +510,11 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         r
     }
 
+    // This function returns the coordinate shifts of the wire columns, the left wire being unshifted
+    pub fn shifts(&self) -> [F; COLUMNS] {
+        [F::one(), self.r, self.o]
+    }
+
     pub fn sample_shifts(domain: &D<F>) -> (F, F) {
         let mut i: u32 = 7;
         let r = Self::sample_shift(&domain, &mut i);
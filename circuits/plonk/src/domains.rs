@@ -1,5 +1,6 @@
 use algebra::FftField;
 use ff_fft::{EvaluationDomain, Radix2EvaluationDomain as D};
+use oracle::poseidon::{SpongeConstants, PlonkSpongeConstants};
 
 #[derive(Debug, Clone, Copy)]
 pub struct EvaluationDomains<F : FftField>
@@ -22,3 +23,102 @@ impl<F : FftField> EvaluationDomains<F> {
         })
     }
 }
+
+// domain size plan of a circuit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DomainPlan
+{
+    Pad(usize),             // single circuit over the padded domain of the given size
+    Split(usize, usize),    // circuit split into two sub-circuits over the domains of the given sizes
+}
+
+impl DomainPlan
+{
+    // This function computes the SRS size the plan requires
+    pub fn srs_size(&self) -> usize
+    {
+        match self
+        {
+            DomainPlan::Pad(n) => quotient_srs_size(*n),
+            DomainPlan::Split(n, m) => std::cmp::max(quotient_srs_size(*n), quotient_srs_size(*m)),
+        }
+    }
+
+    // This function computes the number of the padding rows of the plan
+    //     num_real_gates: number of the circuit gates
+    pub fn wasted_rows(&self, num_real_gates: usize) -> usize
+    {
+        match self
+        {
+            DomainPlan::Pad(n) => n - num_real_gates,
+            DomainPlan::Split(n, m) => n + m - num_real_gates,
+        }
+    }
+}
+
+// This function computes the SRS size needed for committing to the quotient polynomial
+// of the maximal supported degree SPONGE_BOX*(n-1) in a single segment
+//     n: evaluation domain size
+//     RETURN: SRS size, the opening argument rounds it to a power of two
+pub fn quotient_srs_size(n: usize) -> usize
+{
+    std::cmp::max(PlonkSpongeConstants::SPONGE_BOX * (n - 1), 1).next_power_of_two()
+}
+
+// This function plans the evaluation domain of the circuit. Padding a circuit just above a power
+// of two doubles the domain and the SRS. When at least a quarter of the padded domain would be wasted,
+// filling the halved domain and proving the remaining gates as a separate sub-circuit halves the SRS
+// and proves less rows in total, otherwise the split proves the same number of rows for the cost of
+// a second proof and the padding is accepted
+//     num_real_gates: number of the circuit gates, including public input ones
+//     RETURN: domain size plan
+pub fn plan_domain_size(num_real_gates: usize) -> DomainPlan
+{
+    let n = std::cmp::max(num_real_gates, 1).next_power_of_two();
+    if n < 4 || 4 * (n - num_real_gates) < n {return DomainPlan::Pad(n)}
+    DomainPlan::Split(n / 2, (num_real_gates - n / 2).next_power_of_two())
+}
+
+// This function computes the suggested domain size of the circuit
+//     num_real_gates: number of the circuit gates, including public input ones
+//     RETURN: the largest domain size of the plan along with the number of the padding rows
+pub fn optimal_domain_size(num_real_gates: usize) -> (usize, usize)
+{
+    let plan = plan_domain_size(num_real_gates);
+    let size = match plan {DomainPlan::Pad(n) => n, DomainPlan::Split(n, _) => n};
+    (size, plan.wasted_rows(num_real_gates))
+}
+
+// padding diagnostic of the radix-2 evaluation domain of a circuit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaddingDiagnostic
+{
+    pub rows: usize,    // rows the circuit needs, its gates up to the last nonzero one and the zero-knowledge rows
+    pub domain: usize,  // evaluation domain size
+}
+
+impl PaddingDiagnostic
+{
+    // This function checks whether the domain is more than twice the size of the rows the circuit needs,
+    // the circuit being padded beyond the smallest radix-2 domain that fits it
+    pub fn excessive(&self) -> bool
+    {
+        self.domain > 2 * self.rows
+    }
+}
+
+pub trait VanishingPolyEval<F: FftField>
+{
+    fn vanishing_poly_eval(&self, zeta: F) -> F;
+}
+
+impl<F: FftField> VanishingPolyEval<F> for D<F>
+{
+    // This function evaluates the vanishing polynomial of the domain
+    //     zeta: evaluation point
+    //     RETURN: zeta^n - 1
+    fn vanishing_poly_eval(&self, zeta: F) -> F
+    {
+        zeta.pow(&[self.size]) - &F::one()
+    }
+}
@@ -9,6 +9,7 @@ pub use super::{wires::{*}, constraints::ConstraintSystem};
 use std::io::{Read, Result as IoResult, Write, Error, ErrorKind};
 use algebra::bytes::{FromBytes, ToBytes};
 use num_traits::cast::{FromPrimitive, ToPrimitive};
+use oracle::poseidon::{SpongeConstants, PlonkSpongeConstants};
 
 #[repr(C)]
 #[derive(Clone, Debug)]
@@ -97,6 +98,27 @@ impl<F: FftField> CircuitGate<F>
         }
     }
 
+    // This function returns the degree of the gate constraint in the witness and selector polynomials,
+    // the gates constrained by the constraint of their preceding gate have zero degree
+    pub fn degree(&self) -> usize
+    {
+        match self.typ
+        {
+            GateType::Zero      => 0,
+            GateType::Generic   => 3,
+            GateType::Poseidon  => PlonkSpongeConstants::SPONGE_BOX + 1,
+            GateType::Add1      => 4,
+            GateType::Add2      => 4,
+            GateType::Vbmul1    => 3,
+            GateType::Vbmul2    => 6,
+            GateType::Vbmul3    => 0,
+            GateType::Endomul1  => 3,
+            GateType::Endomul2  => 3,
+            GateType::Endomul3  => 6,
+            GateType::Endomul4  => 0,
+        }
+    }
+
     // This function verifies the consistency of the wire
     // assignements (witness) against the constraints
     pub fn verify(&self, next: &Self, witness: &Vec<F>, cs: &ConstraintSystem<F>) -> bool
@@ -43,6 +43,16 @@ impl<F: FftField> CircuitGate<F>
         }
     }
 
+    // This function creates the public input gate of the row, constraining its left wire to the public
+    // input value of the row. The public inputs take the first rows of the circuit, their left wires being
+    // bound to the arbitrary positions of the other gates, the right and the output ones included, by the
+    // permutation cycles of the wires
+    //     wires: gate wires, the left one being wired to the positions the public input is bound to
+    pub fn create_public(wires: GateWires) -> Self
+    {
+        Self::create_generic(wires, F::one(), F::zero(), F::zero(), F::zero(), F::zero())
+    }
+
     pub fn verify_generic(&self, witness: &Vec<F>) -> bool
     {
         self.typ == GateType::Generic &&
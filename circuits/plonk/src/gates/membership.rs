@@ -0,0 +1,63 @@
+/*****************************************************************************************************************
+
+This source file implements public set membership gadget on top of the Plonk lookup argument.
+
+The gadget proves that the value x of a wire belongs to the public set {s_0, ..., s_k-1}
+without revealing which of the set elements it is equal to, by looking x up in the single column
+table of the set elements. The gadget consists of a single gate, whatever the set size:
+
+Gate 0
+    l = x
+    q_lookup = 1, x in {s_0, ..., s_k-1}
+
+The gate carries no generic constraint, its left wire being constrained by the lookup argument of the
+constraint system against the set table. The wiring of x to the left wire of the gate has to be enforced
+by the permutation of the gate wires.
+
+*****************************************************************************************************************/
+
+use algebra::FftField;
+use std::io::{Error, ErrorKind, Result as IoResult};
+use crate::gate::CircuitGate;
+use crate::wires::GateWires;
+use crate::lookup::LookupTable;
+use crate::builder::{CircuitBuilder, WireRef};
+
+impl<F: FftField> CircuitGate<F>
+{
+    // This function creates the membership gadget gate along with the set table it is looked up in
+    //     wires: gate wires, the value being on the left wire
+    //     set: the public set
+    //     RETURN: gadget gate and the set table, error if the set is empty
+    pub fn create_membership
+    (
+        wires: GateWires,
+        set: &[F],
+    ) -> IoResult<(Self, LookupTable<F>)>
+    {
+        Ok((Self::zero(wires), membership_table(set)?))
+    }
+}
+
+impl<F: FftField> CircuitBuilder<F>
+{
+    // This function constrains the variable to belong to the public set, the set being the
+    // lookup table of the circuit
+    //     value: variable to be proven to belong to the set
+    //     set: the public set
+    //     RETURN: error if the set is empty or the circuit is looked up in another table
+    pub fn prove_membership(&mut self, value: WireRef, set: &[F]) -> IoResult<()>
+    {
+        let table = membership_table(set)?;
+        self.lookup(&table, value, 0).map(|_| ()).ok_or_else
+        (
+            || Error::new(ErrorKind::InvalidInput, "the circuit is looked up in another table")
+        )
+    }
+}
+
+// This function creates the single column table of the set elements
+fn membership_table<F: FftField>(set: &[F]) -> IoResult<LookupTable<F>>
+{
+    LookupTable::create_vector(set.iter().map(|s| vec![*s]).collect())
+}
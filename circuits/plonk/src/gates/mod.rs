@@ -3,3 +3,4 @@ pub mod poseidon;
 pub mod addition;
 pub mod varbasemul;
 pub mod endosclmul;
+pub mod membership;
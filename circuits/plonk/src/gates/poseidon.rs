@@ -6,10 +6,14 @@ Constraint vector format:
 
     [rc; SPONGE_WIDTH]: round constants
 
+The permutation gadget consists of ROUNDS_FULL consecutive Poseidon gates followed by the zero gate, the
+gate i holding the sponge state entering the full round i and the zero gate holding the permutation output.
+The state of the first gate is the permutation input with the initial round constants added.
+
 *****************************************************************************************************************/
 
 use algebra::FftField;
-use oracle::poseidon::{SpongeConstants, PlonkSpongeConstants, sbox};
+use oracle::poseidon::{SpongeConstants, PlonkSpongeConstants, ArithmeticSponge, ArithmeticSpongeParams, Sponge, sbox};
 use crate::{wires::GateWires, constraints::ConstraintSystem};
 use crate::gate::{CircuitGate, GateType};
 
@@ -29,6 +33,48 @@ impl<F: FftField> CircuitGate<F>
         }
     }
 
+    // This function creates the gates of the Poseidon permutation gadget
+    //     wires: wires of the ROUNDS_FULL+1 gadget gates
+    //     params: sponge parameters of the constraint system
+    //     RETURN: Poseidon gates of the full rounds followed by the output zero gate
+    pub fn create_poseidon_permutation
+    (
+        wires: &[GateWires],
+        params: &ArithmeticSpongeParams<F>,
+    ) -> Vec<Self>
+    {
+        assert!(wires.len() == PlonkSpongeConstants::ROUNDS_FULL + 1);
+        let c = &params.round_constants;
+        let mut gates = (0..PlonkSpongeConstants::ROUNDS_FULL).map
+        (
+            |j| Self::create_poseidon(wires[j], [c[j+1][0], c[j+1][1], c[j+1][2]])
+        ).collect::<Vec<_>>();
+        gates.push(Self::zero(wires[PlonkSpongeConstants::ROUNDS_FULL]));
+        gates
+    }
+
+    // This function computes the witness of the Poseidon permutation gadget
+    //     input: permutation input state
+    //     params: sponge parameters of the constraint system
+    //     RETURN: left, right and output wire assignments of the gadget gates, the last ones
+    //         being the permutation output
+    pub fn poseidon_permutation_witness
+    (
+        input: [F; PlonkSpongeConstants::SPONGE_WIDTH],
+        params: &ArithmeticSpongeParams<F>,
+    ) -> Vec<(F, F, F)>
+    {
+        let mut sponge = <ArithmeticSponge<F, PlonkSpongeConstants> as Sponge<F, F>>::new();
+        sponge.state = input.iter().zip(params.round_constants[0].iter()).map(|(x, c)| *x + c).collect();
+        let mut witness = vec![(sponge.state[0], sponge.state[1], sponge.state[2])];
+        for j in 0..PlonkSpongeConstants::ROUNDS_FULL
+        {
+            sponge.full_round(j, params);
+            witness.push((sponge.state[0], sponge.state[1], sponge.state[2]));
+        }
+        witness
+    }
+
     pub fn verify_poseidon(&self, next: &Self, witness: &Vec<F>, cs: &ConstraintSystem<F>) -> bool
     {
         let rc = self.rc();
@@ -3,9 +3,16 @@ extern crate num_derive;
 
 pub mod gate;
 pub mod gates;
+pub mod builder;
 pub mod constraints;
 pub mod polynomials;
 pub mod polynomial;
 pub mod domains;
 pub mod scalars;
 pub mod wires;
+pub mod witness;
+pub mod lookup;
+pub mod rle;
+pub mod r1cs;
+#[cfg(feature = "wasm")]
+pub mod wasm;
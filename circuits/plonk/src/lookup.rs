@@ -0,0 +1,167 @@
+/*****************************************************************************************************************
+
+This source file implements Plonk circuit lookup table primitive.
+
+The table consists of rows of field elements, the first column being the key column with no duplicate
+keys unless the table is looked up by whole rows. The tables are loaded from the following text encodings:
+
+    CSV: one row per line, the elements separated by commas, the empty lines and the lines starting
+        with # being skipped
+    JSON: array of the rows, each row being an array of the elements given as numbers or strings
+
+Each element is encoded as an unsigned decimal integer or as an unsigned hexadecimal integer prefixed
+with 0x, optionally preceded by the minus sign denoting the field negation. All the rows have to be of
+the same length.
+
+*****************************************************************************************************************/
+
+use algebra::FftField;
+use std::io::{Read, Error, ErrorKind, Result as IoResult};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupTable<F: FftField>
+{
+    pub rows: Vec<Vec<F>>,  // table rows, the first element of the row being its key
+}
+
+fn invalid(msg: &str) -> Error
+{
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+impl<F: FftField> LookupTable<F>
+{
+    // This function creates the table validating shape of the rows and uniqueness of the keys
+    //     rows: table rows
+    //     RETURN: lookup table
+    pub fn create(rows: Vec<Vec<F>>) -> IoResult<Self>
+    {
+        let table = Self::create_vector(rows)?;
+        let rows = &table.rows;
+        for i in 0..rows.len()
+        {
+            if rows[i+1..].iter().any(|r| r[0] == rows[i][0]) {return Err(invalid("duplicate lookup table key"))}
+        }
+        Ok(table)
+    }
+
+    // This function creates the table of the vector lookup validating shape of the rows only,
+    // the rows being looked up as a whole
+    //     rows: table rows
+    //     RETURN: lookup table
+    pub fn create_vector(rows: Vec<Vec<F>>) -> IoResult<Self>
+    {
+        if rows.len() == 0 || rows[0].len() == 0 {return Err(invalid("empty lookup table"))}
+        if rows.iter().any(|r| r.len() != rows[0].len()) {return Err(invalid("lookup table rows of different length"))}
+        Ok(LookupTable {rows})
+    }
+
+    // This function parses the table from the CSV encoding
+    pub fn from_csv(reader: &mut impl Read) -> IoResult<Self>
+    {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let rows = text.lines()
+            .map(|l| l.trim())
+            .filter(|l| l.len() > 0 && !l.starts_with('#'))
+            .map(|l| l.split(',').map(|e| Self::element(e.trim())).collect::<IoResult<Vec<_>>>())
+            .collect::<IoResult<Vec<_>>>()?;
+        Self::create(rows)
+    }
+
+    // This function parses the table from the JSON encoding
+    pub fn from_json(reader: &mut impl Read) -> IoResult<Self>
+    {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let text = text.trim();
+        if !text.starts_with('[') || !text.ends_with(']') {return Err(invalid("lookup table is not a JSON array"))}
+
+        let mut rows = Vec::new();
+        let mut rest = text[1..text.len()-1].trim();
+        while rest.len() > 0
+        {
+            if !rest.starts_with('[') {return Err(invalid("lookup table row is not a JSON array"))}
+            let end = rest.find(']').ok_or(invalid("unterminated lookup table row"))?;
+            rows.push(rest[1..end].split(',').map
+            (
+                |e|
+                {
+                    let e = e.trim();
+                    Self::element(if e.len() >= 2 && e.starts_with('"') && e.ends_with('"') {&e[1..e.len()-1]} else {e})
+                }
+            ).collect::<IoResult<Vec<_>>>()?);
+            rest = rest[end+1..].trim();
+            if rest.starts_with(',') {rest = rest[1..].trim()}
+            else if rest.len() > 0 {return Err(invalid("lookup table rows are not comma separated"))}
+        }
+        Self::create(rows)
+    }
+
+    // This function parses the table element
+    fn element(e: &str) -> IoResult<F>
+    {
+        let (neg, e) = if e.starts_with('-') {(true, &e[1..])} else {(false, e)};
+        let (radix, digits) = if e.starts_with("0x") {(16, &e[2..])} else {(10, e)};
+        if digits.len() == 0 {return Err(invalid("empty lookup table element"))}
+
+        let mut x = F::zero();
+        for c in digits.chars()
+        {
+            let d = c.to_digit(radix).ok_or(invalid("invalid lookup table element"))?;
+            x = x * &F::from(radix as u64) + &F::from(d as u64);
+        }
+        Ok(if neg {-x} else {x})
+    }
+
+    // This function returns the key column of the table
+    pub fn keys(&self) -> Vec<F>
+    {
+        self.rows.iter().map(|r| r[0]).collect()
+    }
+
+    // This function returns the table column value of the key
+    pub fn get(&self, key: F, column: usize) -> Option<F>
+    {
+        self.rows.iter().find(|r| r[0] == key).map(|r| r[column])
+    }
+
+    // This function interpolates the table column as the function of the key
+    //     column: table column
+    //     RETURN: coefficients of the polynomial mapping the keys to the column values
+    pub fn interpolate(&self, column: usize) -> Vec<F>
+    {
+        let keys = self.keys();
+        let n = keys.len();
+
+        // vanishing polynomial of the keys
+        let mut m = vec![F::one()];
+        for k in keys.iter()
+        {
+            let mut next = vec![F::zero(); m.len() + 1];
+            for (i, c) in m.iter().enumerate()
+            {
+                next[i+1] += c;
+                next[i] -= &(*c * k);
+            }
+            m = next;
+        }
+
+        let mut coeffs = vec![F::zero(); n];
+        for (i, k) in keys.iter().enumerate()
+        {
+            // divide the vanishing polynomial by x - k
+            let mut q = vec![F::zero(); n];
+            let mut acc = F::zero();
+            for j in (0..n).rev()
+            {
+                acc = m[j+1] + &(acc * k);
+                q[j] = acc;
+            }
+            let denom = keys.iter().enumerate().filter(|(j, _)| *j != i).fold(F::one(), |d, (_, x)| d * &(*k - x));
+            let scale = self.rows[i][column] * &denom.inverse().unwrap();
+            coeffs.iter_mut().zip(q.iter()).for_each(|(c, q)| *c += &(*q * &scale));
+        }
+        coeffs
+    }
+}
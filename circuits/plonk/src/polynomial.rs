@@ -6,6 +6,7 @@ This source file implements Plonk prover polynomials primitive.
 
 use algebra::FftField;
 use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use crate::wires::COLUMNS;
 
 #[derive(Clone)]
 pub struct WitnessEvals<F: FftField>
@@ -16,6 +17,15 @@ pub struct WitnessEvals<F: FftField>
     pub z: Evaluations<F, D<F>>,    // permutation evaluations
 }
 
+impl<F: FftField> WitnessEvals<F>
+{
+    // This function returns the wire evaluations in the column order
+    pub fn w(&self) -> [&Evaluations<F, D<F>>; COLUMNS]
+    {
+        [&self.l, &self.r, &self.o]
+    }
+}
+
 #[derive(Clone)]
 pub struct WitnessShifts<F: FftField>
 {
@@ -5,7 +5,7 @@ This source file implements generic constraint polynomials.
 *****************************************************************************************************************/
 
 use algebra::{FftField, SquareRootField};
-use ff_fft::{Evaluations, DensePolynomial, Radix2EvaluationDomain as D};
+use ff_fft::{EvaluationDomain, Evaluations, DensePolynomial, Radix2EvaluationDomain as D};
 use crate::polynomial::WitnessOverDomains;
 use crate::constraints::ConstraintSystem;
 use crate::scalars::ProofEvaluations;
@@ -13,18 +13,17 @@ use oracle::utils::PolyUtils;
 
 impl<F: FftField + SquareRootField> ConstraintSystem<F>
 {
-    // generic constraint quotient poly contribution computation
+    // generic constraint quotient poly contribution computation, the identically zero selectors being skipped
     pub fn gnrc_quot(&self, polys: &WitnessOverDomains<F>, p: &DensePolynomial<F>) -> (Evaluations<F, D<F>>, DensePolynomial<F>)
     {
-        (
-            &(&(&polys.d4.this.l * &polys.d4.this.r) * &self.qml) +
-            &(
-                &(&(&polys.d4.this.l * &self.qll) +
-                &(&polys.d4.this.r * &self.qrl)) +
-                &(&polys.d4.this.o * &self.qol)
-            ),
-            &self.qc + &p
-        )
+        let [ql, qr, qo, qm, qc] = self.gnrc_zero;
+        let this = &polys.d4.this;
+        let mut t = Evaluations::<F, D<F>>::from_vec_and_domain(vec![F::zero(); self.domain.d4.size()], self.domain.d4);
+        if !qm {t += &(&(&this.l * &this.r) * &self.qml)}
+        if !ql {t += &(&this.l * &self.qll)}
+        if !qr {t += &(&this.r * &self.qrl)}
+        if !qo {t += &(&this.o * &self.qol)}
+        (t, if qc {p.clone()} else {&self.qc + &p})
     }
 
     pub fn gnrc_scalars(evals: &ProofEvaluations<F>) -> Vec<F>
@@ -32,11 +31,13 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         vec![evals.l * &evals.r, evals.l, evals.r, evals.o, F::one()]
     }
 
-    // generic constraint linearization poly contribution computation
+    // generic constraint linearization poly contribution computation, the identically zero selectors being skipped
     pub fn gnrc_lnrz(&self, evals: &ProofEvaluations<F>) -> DensePolynomial<F>
     {
+        let [ql, qr, qo, qm, qc] = self.gnrc_zero;
         let scalars = Self::gnrc_scalars(evals);
-        &(&(&(&self.qmm.scale(scalars[0]) + &self.qlm.scale(scalars[1])) +
-            &self.qrm.scale(scalars[2])) + &self.qom.scale(scalars[3])) + &self.qc
+        [(qm, &self.qmm), (ql, &self.qlm), (qr, &self.qrm), (qo, &self.qom)].iter().zip(scalars.iter()).
+            filter(|((zero, _), _)| !zero).
+            fold(if qc {DensePolynomial::from_coefficients_vec(Vec::new())} else {self.qc.clone()}, |f, ((_, q), s)| &f + &q.scale(*s))
     }
 }
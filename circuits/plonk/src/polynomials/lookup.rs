@@ -0,0 +1,260 @@
+/*****************************************************************************************************************
+
+This source file implements the lookup argument constraint polynomials.
+
+The lookup argument proves the left wire values of the lookup rows to belong to the table column. With the
+lookup selector q, the table column t and the multiplicities m of the table values among the looked up ones,
+it checks the log-derivative identity over the challenge g
+
+    sum q_j/(g + l_j) = sum m_j/(g + t_j)
+
+the sums ranging over the rows preceding the zero-knowledge rows. The sums are accumulated by the running
+sum polynomial phi
+
+    phi_0 = 0
+    phi_j+1 = phi_j + q_j/(g + l_j) - m_j/(g + t_j)
+
+constrained by
+
+    (phi(wx) - phi(x))(g + l(x))(g + t(x)) - q(x)(g + t(x)) + m(x)(g + l(x)) = 0
+
+disabled over the zero-knowledge rows, and by the boundary conditions phi(1) = phi(w^(n-3)) = 0.
+The table values precede the zero-knowledge rows, the rest of the table column repeating the first value.
+
+The vector lookup proves the l, r, o wire values of the lookup rows to be the rows of the table of up to
+three columns, the looked up values and the table columns being combined with the powers of the challenge
+theta drawn along with g
+
+    l_j = l_j + theta*r_j + theta^2*o_j
+    t_j = t0_j + theta*t1_j + theta^2*t2_j
+
+the single column lookup skipping theta.
+
+*****************************************************************************************************************/
+
+use algebra::{FftField, SquareRootField};
+use ff_fft::{DensePolynomial, DenseOrSparsePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDomain as D};
+use oracle::utils::EvalUtils;
+use crate::constraints::ConstraintSystem;
+use crate::lookup::LookupTable;
+use crate::wires::COLUMNS;
+use oracle::rndoracle::ProofError;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct LookupConstraints<F: FftField>
+{
+    pub table:  Vec<Vec<F>>,                // table columns over domain d1, one per looked up wire
+    pub lkpl1:  Vec<F>,                     // lookup selector over domain d1
+    pub tablem: Vec<DensePolynomial<F>>,    // table column polynomials
+    pub lkpm:   DensePolynomial<F>,         // lookup selector polynomial
+    pub table8: Vec<Evaluations<F, D<F>>>,  // table column polynomials over domain d8
+    pub lkpl8:  Evaluations<F, D<F>>,       // lookup selector polynomial over domain d8
+    pub rows:   HashMap<Vec<F>, usize>,     // first position of each of the table rows
+}
+
+impl<F: FftField + SquareRootField> ConstraintSystem<F>
+{
+    // This function adds the lookup argument to the constraint system
+    //     table: lookup table, its key column being looked up
+    //     rows: rows of the left wire values looked up in the table
+    //     RETURN: constraint system with the lookup argument, None if the table or the rows
+    //         overlap the zero-knowledge rows
+    pub fn with_lookup(self, table: &LookupTable<F>, rows: &[usize]) -> Option<Self>
+    {
+        self.with_lookup_columns(table, rows, 1)
+    }
+
+    // This function adds the vector lookup argument to the constraint system
+    //     table: lookup table, all of its columns being looked up
+    //     rows: rows of the l, r, o wire values looked up in the table
+    //     RETURN: constraint system with the lookup argument, None if the table or the rows
+    //         overlap the zero-knowledge rows or the table has more columns than the wires
+    pub fn with_vector_lookup(self, table: &LookupTable<F>, rows: &[usize]) -> Option<Self>
+    {
+        let width = table.rows[0].len();
+        if width > COLUMNS {return None}
+        self.with_lookup_columns(table, rows, width)
+    }
+
+    fn with_lookup_columns(self, table: &LookupTable<F>, rows: &[usize], width: usize) -> Option<Self>
+    {
+        let n = self.domain.d1.size();
+        if table.rows.len() > n-3 || rows.iter().any(|r| *r >= n-3) {return None}
+        let columns = (0..width).map
+        (
+            |c|
+            {
+                let mut column = table.rows.iter().map(|r| r[c]).collect::<Vec<_>>();
+                column.resize(n, column[0]);
+                column
+            }
+        ).collect::<Vec<_>>();
+
+        let mut selector = vec![F::zero(); n];
+        rows.iter().for_each(|r| selector[*r] = F::one());
+        self.with_lookup_evaluations(columns, selector)
+    }
+
+    // This function adds the lookup argument to the constraint system from its evaluations over domain d1,
+    // as stored in the LookupConstraints table and lkpl1 fields
+    //     columns: table columns, padded with their first value
+    //     selector: lookup selector
+    //     RETURN: constraint system with the lookup argument, None if the evaluations are not of the domain
+    //         size or the table has more columns than the wires
+    pub fn with_lookup_evaluations(mut self, columns: Vec<Vec<F>>, selector: Vec<F>) -> Option<Self>
+    {
+        let n = self.domain.d1.size();
+        if columns.len() == 0 || columns.len() > COLUMNS || selector.len() != n || columns.iter().any(|c| c.len() != n) {return None}
+
+        // the table rows are indexed once, so that the multiplicities are computed in a single pass,
+        // the padding repeating the first row not taking its position
+        let mut positions = HashMap::new();
+        (0..n).rev().for_each(|i| {positions.insert(columns.iter().map(|c| c[i]).collect::<Vec<_>>(), i);});
+
+        let tablem = columns.iter().map(|c| Evaluations::<F, D<F>>::from_vec_and_domain(c.clone(), self.domain.d1).interpolate()).collect::<Vec<_>>();
+        let lkpm = Evaluations::<F, D<F>>::from_vec_and_domain(selector.clone(), self.domain.d1).interpolate();
+        self.lookup = Some(LookupConstraints
+        {
+            table: columns,
+            lkpl1: selector,
+            table8: tablem.iter().map(|t| t.evaluate_over_domain_by_ref(self.domain.d8)).collect(),
+            lkpl8: lkpm.evaluate_over_domain_by_ref(self.domain.d8),
+            rows: positions,
+            tablem,
+            lkpm,
+        });
+        Some(self)
+    }
+
+    // This function combines the looked up values or the table values of the row
+    //     x: values of the row
+    //     theta: vector lookup challenge
+    //     RETURN: combined value
+    pub fn lookup_combine(x: &[F], theta: F) -> F
+    {
+        x.iter().rev().fold(F::zero(), |acc, x| acc * &theta + x)
+    }
+
+    // This function computes the multiplicities of the table rows among the looked up ones
+    //     w: l, r, o wire values over domain d1
+    //     RETURN: multiplicities over domain d1, None if a looked up row is not in the table
+    pub fn lookup_multiplicities(&self, w: &[&[F]]) -> Option<Vec<F>>
+    {
+        let lookup = self.lookup.as_ref()?;
+        let n = self.domain.d1.size();
+        let width = lookup.table.len();
+        let mut m = vec![F::zero(); n];
+        for j in (0..n-3).filter(|j| !lookup.lkpl1[*j].is_zero())
+        {
+            let i = *lookup.rows.get(&(0..width).map(|c| w[c][j]).collect::<Vec<_>>())?;
+            m[i] += &F::one();
+        }
+        Some(m)
+    }
+
+    // This function computes the running sum of the lookup argument
+    //     w: l, r, o wire values over domain d1
+    //     m: multiplicities over domain d1
+    //     gamma: lookup argument challenge
+    //     theta: vector lookup challenge
+    //     RETURN: running sum over domain d1 up to the row n-3, zero at the rest of the rows, the zero
+    //         denominator error of the column COLUMNS for the looked up wire values and of the column
+    //         COLUMNS+1 for the table values, the lookup columns following the wire ones
+    pub fn lookup_sum(&self, w: &[&[F]], m: &[F], gamma: F, theta: F) -> Result<Vec<F>, ProofError>
+    {
+        let lookup = self.lookup.as_ref().ok_or(ProofError::ProofCreation)?;
+        let n = self.domain.d1.size();
+        let width = lookup.table.len();
+        let row = |x: &dyn Fn(usize) -> F| Self::lookup_combine(&(0..width).map(|c| x(c)).collect::<Vec<_>>(), theta);
+        let mut den = (0..n-3).map(|j| gamma + &row(&|c| w[c][j])).
+            chain((0..n-3).map(|j| gamma + &row(&|c| lookup.table[c][j]))).collect::<Vec<_>>();
+        // the denominators are checked before the batch inversion, reporting the row of the zero one
+        if let Some(i) = den.iter().position(|d| d.is_zero())
+        {
+            return Err(ProofError::ZeroDenominator {column: COLUMNS + i / (n-3), row: i % (n-3)})
+        }
+        algebra::fields::batch_inversion::<F>(&mut den);
+
+        let mut phi = vec![F::zero(); n];
+        for j in 0..n-3
+        {
+            phi[j+1] = phi[j] + &(lookup.lkpl1[j] * &den[j]) - &(m[j] * &den[n-3+j]);
+        }
+        Ok(phi)
+    }
+
+    // lookup argument quotient poly contribution computation
+    //     w: l, r, o wire polynomials over domain d8
+    //     m: multiplicities polynomial
+    //     phi: running sum polynomial
+    //     gamma: lookup argument challenge
+    //     theta: vector lookup challenge
+    //     alpha: powers of alpha of the transition and the two boundary constraints
+    //     RETURN: transition constraint over domain d8 and the boundary constraint quotient polynomial,
+    //         None if the boundary conditions do not hold
+    pub fn lookup_quot
+    (
+        &self,
+        w: &[&Evaluations<F, D<F>>],
+        m: &DensePolynomial<F>,
+        phi: &DensePolynomial<F>,
+        gamma: F,
+        theta: F,
+        alpha: &[F],
+    ) -> Option<(Evaluations<F, D<F>>, DensePolynomial<F>)>
+    {
+        let lookup = self.lookup.as_ref()?;
+        let m8 = m.evaluate_over_domain_by_ref(self.domain.d8);
+        let phi8 = phi.evaluate_over_domain_by_ref(self.domain.d8);
+        let g = self.l08.scale(gamma);
+        let combine = |x: Vec<&Evaluations<F, D<F>>>| x.into_iter().rev().fold
+        (
+            Evaluations::<F, D<F>>::from_vec_and_domain(vec![F::zero(); self.domain.d8.size()], self.domain.d8),
+            |acc, x| &acc.scale(theta) + x
+        );
+        let gl = &combine(w[0..lookup.table.len()].to_vec()) + &g;
+        let gt = &combine(lookup.table8.iter().collect()) + &g;
+
+        let transition = &(&(&(&(&phi8.shift(8) - &phi8) * &gl) * &gt) - &(&(&lookup.lkpl8 * &gt) - &(&m8 * &gl))).
+            scale(alpha[0]) * &self.zkpl;
+
+        let divide = |root: F|
+        {
+            DenseOrSparsePolynomial::divide_with_q_and_r(&phi.into(), &DensePolynomial::from_coefficients_slice(&[-root, F::one()]).into())
+                .filter(|(_, r)| r.is_zero()).map(|(q, _)| q)
+        };
+        let bnd1 = divide(F::one())?;
+        let bnd2 = divide(self.sid[self.domain.d1.size() - 3])?;
+        Some((transition, &bnd1.scale(alpha[1]) + &bnd2.scale(alpha[2])))
+    }
+
+    // This function evaluates the lookup argument constraints contribution to t*zh at zeta
+    //     e: combined looked up value, m, q, combined table value and phi values at zeta and phi value at zeta*w
+    //     gamma: lookup argument challenge
+    //     alpha: powers of alpha of the transition and the two boundary constraints
+    //     zeta: evaluation point
+    //     zkp: zero-knowledge polynomial value at zeta
+    //     zh: vanishing polynomial value at zeta
+    //     w: root of unity of the row n-3
+    //     RETURN: lookup argument contribution
+    pub fn lookup_eval
+    (
+        e: [F; 6],
+        gamma: F,
+        alpha: &[F],
+        zeta: F,
+        zkp: F,
+        zh: F,
+        w: F,
+    ) -> F
+    {
+        let [l, m, q, t, phi, phi_w] = e;
+        let gl = gamma + &l;
+        let gt = gamma + &t;
+        let transition = ((phi_w - &phi) * &gl * &gt - &(q * &gt) + &(m * &gl)) * &alpha[0] * &zkp;
+        let boundary = phi * &zh *
+            &(alpha[1] * &(zeta - &F::one()).inverse().unwrap() + &(alpha[2] * &(zeta - &w).inverse().unwrap()));
+        transition + &boundary
+    }
+}
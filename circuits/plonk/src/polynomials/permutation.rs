@@ -10,6 +10,7 @@ use crate::scalars::{ProofEvaluations, RandomOracles};
 use crate::polynomial::WitnessOverDomains;
 use oracle::utils::{EvalUtils, PolyUtils};
 use crate::constraints::ConstraintSystem;
+use crate::wires::COLUMNS;
 
 impl<F: FftField + SquareRootField> ConstraintSystem<F>
 {
@@ -22,18 +23,19 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
     ) -> Evaluations<F, D<F>>
     {
         let l0 = &self.l08.scale(oracles.gamma);
+        let (w, shifts) = (lagrange.d8.this.w(), self.shifts());
 
-        &((&(&(&(&(&lagrange.d8.this.l + &(l0 + &self.l1.scale(oracles.beta))) *
-        &(&lagrange.d8.this.r + &(l0 + &self.l1.scale(oracles.beta * &self.r)))) *
-        &(&lagrange.d8.this.o + &(l0 + &self.l1.scale(oracles.beta * &self.o)))) *
-        &lagrange.d8.this.z)
-        -
-        &(&(&(&(&lagrange.d8.this.l + &(l0 + &self.sigmal4[0].scale(oracles.beta))) *
-        &(&lagrange.d8.this.r + &(l0 + &self.sigmal4[1].scale(oracles.beta)))) *
-        &(&lagrange.d8.this.o + &(l0 + &self.sigmal4[2].scale(oracles.beta)))) *
-        &lagrange.d8.next.z)).scale(oracles.alpha))
-        *
-        &self.zkpl
+        let shifted = (0..COLUMNS).fold
+        (
+            lagrange.d8.this.z.clone(),
+            |acc, c| &acc * &(w[c] + &(l0 + &self.l1.scale(oracles.beta * &shifts[c])))
+        );
+        let permuted = (0..COLUMNS).fold
+        (
+            lagrange.d8.next.z.clone(),
+            |acc, c| &acc * &(w[c] + &(l0 + &self.sigmal4[c].scale(oracles.beta)))
+        );
+        &(&shifted - &permuted).scale(oracles.alpha) * &self.zkpl
     }
 
     pub fn perm_lnrz
@@ -48,13 +50,13 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         (
             e,
             oracles,
-            (self.r, self.o),
+            &self.shifts(),
             alpha,
             self.domain.d1.size,
             self.zkpm.evaluate(oracles.zeta),
             self.sid[self.domain.d1.size as usize -3]
         );
-        &z.scale(scalars[0]) + &self.sigmam[2].scale(scalars[1])
+        &z.scale(scalars[0]) + &self.sigmam[COLUMNS-1].scale(scalars[1])
     }
 
     // permutation linearization poly contribution computation
@@ -62,7 +64,7 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
     (
         e: &Vec<ProofEvaluations<F>>,
         oracles: &RandomOracles<F>,
-        shift: (F, F),
+        shifts: &[F; COLUMNS],
         alpha: &[F],
         n: u64,
         z: F,
@@ -73,19 +75,61 @@ impl<F: FftField + SquareRootField> ConstraintSystem<F>
         let mut denominator = [oracles.zeta - &F::one(), oracles.zeta - &w];
         algebra::fields::batch_inversion::<F>(&mut denominator);
         let numerator = oracles.zeta.pow(&[n]) - &F::one();
+        let (wires, sigma) = (e[0].w(), e[0].sigma());
 
         vec!
         [
-            (e[0].l + &bz + &oracles.gamma) *
-            &(e[0].r + &(bz * &shift.0) + &oracles.gamma) *
-            &(e[0].o + &(bz * &shift.1) + &oracles.gamma) *
-            &oracles.alpha * &z +
+            (0..COLUMNS).fold(oracles.alpha * &z, |acc, c| acc * &(*wires[c] + &(bz * &shifts[c]) + &oracles.gamma)) +
             &(alpha[0] * &numerator * &denominator[0]) +
             &(alpha[1] * &numerator * &denominator[1])
             ,
-            -(e[0].l + &(oracles.beta * &e[0].sigma1) + &oracles.gamma) *
-            &(e[0].r + &(oracles.beta * &e[0].sigma2) + &oracles.gamma) *
-            &(e[1].z * &oracles.beta * &oracles.alpha * &z)
+            -(0..COLUMNS-1).fold
+            (
+                e[1].z * &oracles.beta * &oracles.alpha * &z,
+                |acc, c| acc * &(*wires[c] + &(oracles.beta * sigma[c]) + &oracles.gamma)
+            )
         ]
     }
+
+    // This function evaluates the quotient identity t*zh at zeta from the linearization polynomial value,
+    // the generic and the custom gate constraints being linearized into it along with the permutation one
+    // save for its term of z(zeta*w) and its boundary conditions
+    //     e: evaluations at zeta and zeta*w
+    //     p: public input polynomial value at zeta
+    //     aux: auxiliary and lookup argument constraints contribution at zeta
+    //     oracles: random oracles
+    //     alpha: powers of alpha of the two permutation boundary constraints
+    //     zkp: zero-knowledge polynomial value at zeta
+    //     zh: vanishing polynomial value at zeta
+    //     w: root of unity of the row n-3
+    //     RETURN: value of t*zh at zeta the proof claims
+    // The boundary constraints z(1) = z(w) = 1 of the rows 0 and n-3 enter the linearization with the scalars
+    // zh/(zeta-1) = n*L_0(zeta) and zh/(zeta-w), proportional to the Lagrange basis polynomials of the rows,
+    // their constant terms being subtracted here, so that the unnormalized z fails the identity
+    pub fn quot_eval
+    (
+        e: &Vec<ProofEvaluations<F>>,
+        p: F,
+        aux: F,
+        oracles: &RandomOracles<F>,
+        alpha: &[F],
+        zkp: F,
+        zh: F,
+        w: F,
+    ) -> F
+    {
+        let mut denominator = [oracles.zeta - &F::one(), oracles.zeta - &w];
+        algebra::fields::batch_inversion::<F>(&mut denominator);
+        let (wires, sigma) = (e[0].w(), e[0].sigma());
+
+        e[0].f + &p + &aux
+        -
+        &(0..COLUMNS-1).fold
+        (
+            (*wires[COLUMNS-1] + &oracles.gamma) * &e[1].z * &zkp * &oracles.alpha,
+            |acc, c| acc * &(*wires[c] + &(oracles.beta * sigma[c]) + &oracles.gamma)
+        )
+        -
+        &(zh * &(alpha[0] * &denominator[0] + &(alpha[1] * &denominator[1])))
+    }
 }
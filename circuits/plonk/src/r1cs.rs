@@ -0,0 +1,101 @@
+/*****************************************************************************************************************
+
+This source file implements the conversion of the R1CS instances into the Plonk circuits.
+
+The R1CS instance constrains the assignment z = (1, public inputs, private variables) by
+
+    <a_i, z> * <b_i, z> = <c_i, z>
+
+for the sparse rows a_i, b_i, c_i of the matrices A, B, C. The conversion lays out the circuit with the
+circuit builder: the public inputs take the first rows, each linear combination is accumulated by the
+generic gates summing two of its terms at a time, the constant term being folded into the selector qc,
+and each constraint ends with the multiplication gate of the combinations. The variables used in several
+gates are wired together by the permutation cycles of the builder.
+
+*****************************************************************************************************************/
+
+use algebra::FftField;
+use crate::builder::{CircuitBuilder, WireRef};
+use crate::gate::CircuitGate;
+
+// sparse linear combination of the assignment variables, the variable 0 being the constant one
+pub type LinearCombination<F> = Vec<(usize, F)>;
+
+#[derive(Clone, Debug)]
+pub struct R1CS<F: FftField>
+{
+    pub a: Vec<LinearCombination<F>>,   // rows of the matrix A
+    pub b: Vec<LinearCombination<F>>,   // rows of the matrix B
+    pub c: Vec<LinearCombination<F>>,   // rows of the matrix C
+    pub public: usize,                  // number of the public inputs following the constant one
+    pub variables: usize,               // number of the assignment variables, the constant one included
+}
+
+impl<F: FftField> R1CS<F>
+{
+    // This function converts the instance into the Plonk circuit
+    //     z: assignment, the first element being one and the public inputs following it
+    //     RETURN: circuit gates padded to the domain size along with the witness of the assignment,
+    //         the public inputs taking the first rows, None if the instance or the assignment are malformed
+    pub fn convert(&self, z: &[F]) -> Option<(Vec<CircuitGate<F>>, Vec<F>)>
+    {
+        if self.a.len() != self.b.len() || self.a.len() != self.c.len() ||
+            z.len() != self.variables || self.public >= self.variables || z[0] != F::one() {return None}
+        if [&self.a, &self.b, &self.c].iter().any(|m| m.iter().any(|lc| lc.iter().any(|(i, _)| *i >= self.variables))) {return None}
+
+        let mut builder = CircuitBuilder::<F>::new();
+        let mut vars = vec![None; self.variables];
+        for i in 1..=self.public {vars[i] = Some(builder.public_input(z[i]))}
+        for i in self.public+1..self.variables {vars[i] = Some(builder.var(z[i]))}
+
+        for ((a, b), c) in self.a.iter().zip(self.b.iter()).zip(self.c.iter())
+        {
+            let a = Self::combine(&mut builder, &vars, a);
+            let b = Self::combine(&mut builder, &vars, b);
+            let c = Self::combine(&mut builder, &vars, c);
+            builder.generic([Some(a), Some(b), Some(c)], [F::zero(), F::zero(), -F::one(), F::one(), F::zero()]);
+        }
+        Some(builder.build())
+    }
+
+    // This function constrains the variable to the linear combination
+    //     builder: circuit builder
+    //     vars: circuit variables of the assignment variables, None for the constant one
+    //     lc: linear combination
+    //     RETURN: variable of the combination value
+    fn combine(builder: &mut CircuitBuilder<F>, vars: &[Option<WireRef>], lc: &LinearCombination<F>) -> WireRef
+    {
+        let (zero, one) = (F::zero(), F::one());
+        let constant = lc.iter().filter(|(i, _)| *i == 0).fold(zero, |acc, (_, c)| acc + c);
+        let terms = lc.iter().filter(|(i, _)| *i != 0).map(|(i, c)| (vars[*i].unwrap(), *c)).collect::<Vec<_>>();
+
+        match terms.len()
+        {
+            0 =>
+            {
+                let v = builder.var(constant);
+                builder.assert_constant(v, constant);
+                v
+            }
+            1 =>
+            {
+                let v = builder.var(builder.value(terms[0].0) * &terms[0].1 + &constant);
+                builder.generic([Some(terms[0].0), None, Some(v)], [terms[0].1, zero, -one, zero, constant]);
+                v
+            }
+            _ =>
+            {
+                // the first gate sums the first two terms along with the constant one
+                let mut acc = builder.var(builder.value(terms[0].0) * &terms[0].1 + &(builder.value(terms[1].0) * &terms[1].1) + &constant);
+                builder.generic([Some(terms[0].0), Some(terms[1].0), Some(acc)], [terms[0].1, terms[1].1, -one, zero, constant]);
+                for (w, c) in terms[2..].iter()
+                {
+                    let next = builder.var(builder.value(acc) + &(builder.value(*w) * c));
+                    builder.generic([Some(acc), Some(*w), Some(next)], [one, *c, -one, zero, zero]);
+                    acc = next;
+                }
+                acc
+            }
+        }
+    }
+}
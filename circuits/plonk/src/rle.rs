@@ -0,0 +1,135 @@
+/*****************************************************************************************************************
+
+This source file implements the run-length encoding of the Plonk circuit gates.
+
+The repetitive circuits, like the ones consisting of the long runs of the identical hash rounds, are described
+by the runs of the gates sharing the type and the constraint coefficients, so that the circuit description does
+not carry the per-gate selector values. The wiring of the runs is described by the permutation cycles of the
+(column, row) wire positions of the circuit, the wires not in any of the cycles are not copy constrained.
+The constraint system keeps the runs along with the permutation of the wire positions in the cycles, the
+gates being expanded from their runs one by one when the selectors and the permutation are computed and
+when the witness is checked, so that the gate vector of the circuit is never held in memory.
+
+*****************************************************************************************************************/
+
+use algebra::{FftField, SquareRootField};
+use ff_fft::EvaluationDomain;
+use oracle::poseidon::ArithmeticSpongeParams;
+use std::collections::HashMap;
+use crate::{gate::CircuitGate, wires::{GateWires, COLUMNS}, constraints::{ConstraintSystem, EvaluationDomains}};
+
+#[derive(Clone)]
+pub struct GateRun<F: FftField>
+{
+    pub gate: CircuitGate<F>,   // gate of the run, its wires are assigned on the expansion
+    pub count: usize,           // number of the consecutive gates of the run
+}
+
+impl<F: FftField> GateRun<F>
+{
+    pub fn new(gate: CircuitGate<F>, count: usize) -> Self
+    {
+        GateRun {gate, count}
+    }
+}
+
+// gate runs of the circuit along with the permutation of the wire positions in the cycles
+#[derive(Clone)]
+pub struct GateRuns<F: FftField>
+{
+    runs: Vec<GateRun<F>>,          // gate runs
+    starts: Vec<usize>,             // first rows of the runs
+    perm: HashMap<usize, usize>,    // next wire positions of the cycle positions
+    n: usize,                       // domain size
+}
+
+impl<F: FftField> GateRuns<F>
+{
+    // This function lays out the gate runs over the domain
+    //     runs: gate runs
+    //     cycles: permutation cycles of the (column, row) wire positions, the l, r, o columns being 0, 1, 2
+    //     n: domain size
+    //     RETURN: gate runs, None if a cycle position is out of the circuit
+    pub fn create(runs: &[GateRun<F>], cycles: &[Vec<(usize, usize)>], n: usize) -> Option<Self>
+    {
+        let mut starts = Vec::with_capacity(runs.len());
+        let rows = runs.iter().fold(0, |row, run| {starts.push(row); row + run.count});
+        if rows > n {return None}
+
+        let mut perm = HashMap::new();
+        for cycle in cycles.iter()
+        {
+            if cycle.iter().any(|(col, row)| *col >= COLUMNS || *row >= rows) {return None}
+            for j in 0..cycle.len()
+            {
+                let (col, row) = cycle[j];
+                let (next_col, next_row) = cycle[(j+1) % cycle.len()];
+                perm.insert(col*n+row, next_col*n+next_row);
+            }
+        }
+        Some(GateRuns {runs: runs.to_vec(), starts, perm, n})
+    }
+
+    // This function returns the number of the rows of the runs
+    pub fn rows(&self) -> usize
+    {
+        self.runs.iter().map(|run| run.count).sum()
+    }
+
+    // This function expands the gate of the row from its run, the rows past the runs being the zero gates
+    pub fn gate(&self, row: usize) -> CircuitGate<F>
+    {
+        let n = self.n;
+        let wire = |i: usize| (i, *self.perm.get(&i).unwrap_or(&i));
+        let wires = GateWires::wires(wire(row), wire(n+row), wire(2*n+row));
+        // the last run starting at or before the row, the empty runs being skipped
+        let run = match self.starts.binary_search(&row)
+        {
+            Ok(i) => self.starts.iter().rposition(|s| *s == self.starts[i]),
+            Err(i) => if i == 0 {None} else {Some(i-1)},
+        };
+        match run.filter(|i| row < self.starts[*i] + self.runs[*i].count)
+        {
+            Some(i) => CircuitGate {typ: self.runs[i].gate.typ.clone(), wires, c: self.runs[i].gate.c.clone()},
+            None => CircuitGate::zero(wires),
+        }
+    }
+}
+
+// This function expands the gate runs into the circuit gates
+//     runs: gate runs
+//     cycles: permutation cycles of the (column, row) wire positions, the l, r, o columns being 0, 1, 2
+//     n: domain size
+//     RETURN: circuit gates, None if a cycle position is out of the circuit
+pub fn expand<F: FftField>
+(
+    runs: &[GateRun<F>],
+    cycles: &[Vec<(usize, usize)>],
+    n: usize,
+) -> Option<Vec<CircuitGate<F>>>
+{
+    let runs = GateRuns::create(runs, cycles, n)?;
+    Some((0..runs.rows()).map(|i| runs.gate(i)).collect())
+}
+
+impl<F: FftField + SquareRootField> ConstraintSystem<F>
+{
+    // This function creates the constraint system from the run-length encoded circuit, keeping the runs
+    //     runs: gate runs
+    //     cycles: permutation cycles of the (column, row) wire positions
+    //     fr_sponge_params: Fr sponge parameters
+    //     public: number of the public inputs
+    //     RETURN: constraint system, None if the circuit is malformed
+    pub fn create_rle
+    (
+        runs: &[GateRun<F>],
+        cycles: &[Vec<(usize, usize)>],
+        fr_sponge_params: ArithmeticSpongeParams<F>,
+        public: usize,
+    ) -> Option<Self>
+    {
+        let domain = EvaluationDomains::<F>::create(runs.iter().map(|run| run.count).sum())?;
+        let runs = GateRuns::create(runs, cycles, domain.d1.size())?;
+        Self::compile(domain, Vec::new(), Some(runs), fr_sponge_params, public)
+    }
+}
@@ -7,8 +7,15 @@ This source file implements Plonk prover polynomial evaluations primitive.
 use algebra::{FftField, Field};
 use oracle::{sponge::ScalarChallenge, utils::PolyUtils};
 use ff_fft::DensePolynomial;
+use crate::wires::COLUMNS;
+use std::fmt;
 
-#[derive(Clone)]
+// Polynomial evaluations of the proof. The selector polynomials are folded into the linearization
+// polynomial f and the last permutation polynomial sigma3 into the linearized permutation term, so
+// that their evaluations are not part of the proof. Every field holds the evaluations at all of the
+// evaluation points, zeta*w included, the batched opening argument opening each committed polynomial
+// at the same point set
+#[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "ocaml_types", derive(ocaml::ToValue, ocaml::FromValue))]
 pub struct ProofEvaluations<Fs> {
     pub l: Fs,
@@ -21,19 +28,77 @@ pub struct ProofEvaluations<Fs> {
     pub sigma2: Fs,
 }
 
-impl<F : FftField> ProofEvaluations<Vec<F>> {
-    pub fn combine(&self, pt : F) -> ProofEvaluations<F> {
-        ProofEvaluations::<F>
+impl<Fs> ProofEvaluations<Fs> {
+    // This function returns the wire evaluations in the column order
+    pub fn w(&self) -> [&Fs; COLUMNS] {
+        [&self.l, &self.r, &self.o]
+    }
+
+    // This function returns the evaluations of the permutation polynomials of the columns but the last one,
+    // the last one being folded into the linearization polynomial
+    pub fn sigma(&self) -> [&Fs; COLUMNS-1] {
+        [&self.sigma1, &self.sigma2]
+    }
+
+    // This function iterates over the evaluations in the declaration order
+    //     RETURN: iterator of the evaluation names and values
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Fs)> {
+        vec![
+            ("l", &self.l),
+            ("r", &self.r),
+            ("o", &self.o),
+            ("z", &self.z),
+            ("t", &self.t),
+            ("f", &self.f),
+            ("sigma1", &self.sigma1),
+            ("sigma2", &self.sigma2),
+        ].into_iter()
+    }
+
+    // This function maps the evaluations
+    //     f: the function applied to each of the evaluations in the declaration order
+    //     RETURN: mapped evaluations
+    pub fn map<T, M: FnMut(&Fs) -> T>(&self, mut f: M) -> ProofEvaluations<T> {
+        ProofEvaluations::<T>
         {
-            l: DensePolynomial::eval_polynomial(&self.l, pt),
-            r: DensePolynomial::eval_polynomial(&self.r, pt),
-            o: DensePolynomial::eval_polynomial(&self.o, pt),
-            z: DensePolynomial::eval_polynomial(&self.z, pt),
-            t: DensePolynomial::eval_polynomial(&self.t, pt),
-            f: DensePolynomial::eval_polynomial(&self.f, pt),
-            sigma1: DensePolynomial::eval_polynomial(&self.sigma1, pt),
-            sigma2: DensePolynomial::eval_polynomial(&self.sigma2, pt),
+            l: f(&self.l),
+            r: f(&self.r),
+            o: f(&self.o),
+            z: f(&self.z),
+            t: f(&self.t),
+            f: f(&self.f),
+            sigma1: f(&self.sigma1),
+            sigma2: f(&self.sigma2),
+        }
+    }
+}
+
+impl<F: Field> ProofEvaluations<F> {
+    // This function folds the evaluations at a single point with the powers of the polynomial scaler
+    // in the order of the opening: l, r, o, z, f, sigma1, sigma2, t. The opening interleaves them with
+    // the public input, auxiliary and lookup evaluations, each polynomial segment and the shifted quotient
+    // segment taking a power of their own, so that the fold is the combined inner product of the single
+    // segment polynomials above only
+    //     v: polynomial scaler
+    //     RETURN: sum_i v^i * eval_i
+    pub fn combine_with_powers(&self, v: F) -> F {
+        [self.l, self.r, self.o, self.z, self.f, self.sigma1, self.sigma2, self.t].iter().rev().
+            fold(F::zero(), |acc, e| acc * &v + e)
+    }
+}
+
+impl<F: Field> fmt::Display for ProofEvaluations<Vec<F>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, e) in self.iter() {
+            writeln!(f, "{}: [{}]", name, e.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))?;
         }
+        Ok(())
+    }
+}
+
+impl<F : FftField> ProofEvaluations<Vec<F>> {
+    pub fn combine(&self, pt : F) -> ProofEvaluations<F> {
+        self.map(|e| DensePolynomial::eval_polynomial(e, pt))
     }
 }
 
@@ -43,6 +108,8 @@ pub struct RandomOracles<F: Field>
 {
     pub beta: F,
     pub gamma: F,
+    pub lookup: F,
+    pub lookup_combiner: F,
     pub alpha_chal: ScalarChallenge<F>,
     pub alpha: F,
     pub zeta: F,
@@ -62,6 +129,8 @@ impl<F: Field> RandomOracles<F>
         {
             beta: F::zero(),
             gamma: F::zero(),
+            lookup: F::zero(),
+            lookup_combiner: F::zero(),
             alpha: F::zero(),
             zeta: F::zero(),
             v: F::zero(),
@@ -0,0 +1,74 @@
+/*****************************************************************************************************************
+
+This source file implements the witness generator calling into a WASM compiled witness function.
+
+The WASM module ABI:
+
+    memory: exported linear memory
+    alloc(size: i32) -> i32: allocates size bytes of the memory and returns their offset
+    witness(inputs: i32, ninputs: i32, output: i32, noutput: i32) -> i32:
+        computes the noutput witness elements from the ninputs input elements,
+        returns zero on success
+
+Field elements are passed through the memory in their canonical little-endian byte representation
+of the field serialization size each, the inputs and the outputs being consecutive elements at the given
+offsets. The output witness is laid out as the left, right and output wire columns of the domain size.
+
+*****************************************************************************************************************/
+
+use algebra::{FftField, bytes::{FromBytes, ToBytes}};
+use wasmtime::{Store, Module, Instance, Memory};
+use crate::witness::WitnessSource;
+
+pub struct WasmWitnessGenerator
+{
+    instance: Instance,
+    memory: Memory,
+}
+
+impl WasmWitnessGenerator
+{
+    // This function instantiates the witness generator
+    //     module: binary or text WASM module
+    pub fn create(module: &[u8]) -> Option<Self>
+    {
+        let store = Store::default();
+        let module = Module::new(store.engine(), module).ok()?;
+        let instance = Instance::new(&store, &module, &[]).ok()?;
+        let memory = instance.get_memory("memory")?;
+        Some(WasmWitnessGenerator {instance, memory})
+    }
+
+    fn alloc(&self, size: usize) -> Option<usize>
+    {
+        let alloc = self.instance.get_func("alloc")?.get1::<i32, i32>().ok()?;
+        alloc(size as i32).ok().map(|p| p as usize)
+    }
+}
+
+impl<F: FftField> WitnessSource<F> for WasmWitnessGenerator
+{
+    fn generate(&self, inputs: &[F], n: usize) -> Option<Vec<F>>
+    {
+        let size = {let mut bytes = Vec::new(); F::zero().write(&mut bytes).ok()?; bytes.len()};
+        let input = self.alloc(inputs.len() * size)?;
+        let output = self.alloc(3 * n * size)?;
+
+        // write the inputs into the memory
+        {
+            let memory = unsafe {self.memory.data_unchecked_mut()};
+            if memory.len() < output + 3 * n * size {return None}
+            for (i, x) in inputs.iter().enumerate()
+            {
+                x.write(&mut memory[input + i * size..input + (i + 1) * size]).ok()?;
+            }
+        }
+
+        let witness = self.instance.get_func("witness")?.get4::<i32, i32, i32, i32, i32>().ok()?;
+        if witness(input as i32, inputs.len() as i32, output as i32, (3 * n) as i32).ok()? != 0 {return None}
+
+        // read the witness from the memory
+        let memory = unsafe {self.memory.data_unchecked()};
+        (0..3 * n).map(|i| F::read(&memory[output + i * size..output + (i + 1) * size]).ok()).collect()
+    }
+}
@@ -7,6 +7,9 @@ This source file implements Plonk circuit gate wires primitive.
 use algebra::bytes::{FromBytes, ToBytes};
 use std::io::{Read, Result as IoResult, Write};
 
+// number of the wire columns of the circuit, the witness being laid out as COLUMNS blocks of the domain size
+pub const COLUMNS: usize = 3;
+
 #[derive(Clone, Copy, Debug)]
 pub struct GateWires
 {
@@ -61,6 +64,12 @@ impl GateWires
             o,
         }
     }
+
+    // This function returns the wire indexes and their permutations in the column order
+    pub fn columns(&self) -> [(usize, usize); COLUMNS]
+    {
+        [self.l, self.r, self.o]
+    }
 }
 
 #[repr(C)]
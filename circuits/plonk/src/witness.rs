@@ -0,0 +1,117 @@
+/*****************************************************************************************************************
+
+This source file implements the witness source interface of the circuits whose witness is generated
+outside of the circuit builder, along with the layouts of the flat witness vector:
+
+    Blocks: the left, right and output wire columns one after another, the wire index of the gate
+        wires being the witness index
+    Interleaved: the left, right and output wire values of the rows one after another
+
+The witness builder assembles the witness of the block layout from the left, right and output wire
+values assigned per gate, so that the wire indexing of the gates does not leak into the user code.
+
+*****************************************************************************************************************/
+
+use algebra::FftField;
+use crate::wires::COLUMNS;
+use crate::gate::GateType;
+use crate::constraints::ConstraintSystem;
+
+pub trait WitnessSource<F: FftField>
+{
+    // This function generates the computation witness
+    //     inputs: computation inputs
+    //     n: evaluation domain size
+    //     RETURN: witness of size 3*n laid out as the left, right and output wire columns
+    fn generate(&self, inputs: &[F], n: usize) -> Option<Vec<F>>;
+}
+
+// layout of the wire columns within the flat witness vector
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WitnessLayout
+{
+    Blocks,         // [l-block | r-block | o-block]
+    Interleaved,    // [l_0, r_0, o_0, l_1, r_1, o_1, ...]
+}
+
+impl Default for WitnessLayout
+{
+    fn default() -> Self {WitnessLayout::Blocks}
+}
+
+impl WitnessLayout
+{
+    // This function maps the wire index to the witness position
+    //     i: wire index within the block layout
+    //     n: evaluation domain size
+    //     RETURN: position of the wire value within the witness of the layout
+    pub fn position(&self, i: usize, n: usize) -> usize
+    {
+        match self
+        {
+            WitnessLayout::Blocks => i,
+            WitnessLayout::Interleaved => 3 * (i % n) + i / n,
+        }
+    }
+
+    // This function reshapes the witness of the layout into the block layout
+    pub fn to_blocks<F: Copy>(&self, witness: &[F], n: usize) -> Vec<F>
+    {
+        (0..COLUMNS*n).map(|i| witness[self.position(i, n)]).collect()
+    }
+}
+
+// witness assembly from the per gate wire assignments
+pub struct WitnessBuilder<'a, F: FftField>
+{
+    cs: &'a ConstraintSystem<F>,    // constraint system
+    values: Vec<Option<F>>,         // assigned wire values by the wire index
+}
+
+impl<'a, F: FftField> WitnessBuilder<'a, F>
+{
+    // This function creates the builder of the constraint system witness
+    pub fn new(cs: &'a ConstraintSystem<F>) -> Self
+    {
+        WitnessBuilder {cs, values: vec![None; COLUMNS*cs.domain.d1.size as usize]}
+    }
+
+    // This function assigns the left wire value of the gate
+    pub fn left(&mut self, gate: usize, value: F) -> &mut Self
+    {
+        self.values[self.cs.gate(gate).wires.l.0] = Some(value);
+        self
+    }
+
+    // This function assigns the right wire value of the gate
+    pub fn right(&mut self, gate: usize, value: F) -> &mut Self
+    {
+        self.values[self.cs.gate(gate).wires.r.0] = Some(value);
+        self
+    }
+
+    // This function assigns the output wire value of the gate
+    pub fn output(&mut self, gate: usize, value: F) -> &mut Self
+    {
+        self.values[self.cs.gate(gate).wires.o.0] = Some(value);
+        self
+    }
+
+    // This function assigns the left, right and output wire values of the gate
+    pub fn gate(&mut self, gate: usize, l: F, r: F, o: F) -> &mut Self
+    {
+        self.left(gate, l).right(gate, r).output(gate, o)
+    }
+
+    // This function produces the witness of the block layout, the unassigned wires of the zero
+    // gates being zero
+    //     RETURN: witness, None if a wire of a non-zero gate is not assigned
+    pub fn finalize(&self) -> Option<Vec<F>>
+    {
+        if self.cs.gate_iter().any
+        (
+            |g| g.typ != GateType::Zero && [g.wires.l.0, g.wires.r.0, g.wires.o.0].iter().any(|i| self.values[*i].is_none())
+        ) {return None}
+        Some(self.values.iter().map(|v| v.unwrap_or(F::zero())).collect())
+    }
+}
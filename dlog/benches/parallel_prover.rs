@@ -0,0 +1,57 @@
+/*********************************************************************************************************
+
+This source file benchmarks the Plonk prover with the parallel wire commitments and evaluations against
+the sequential wire commitments, and with the parallel quotient contributions against the sequentially
+accumulated ones, on the 2^16 domain for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::Index};
+use groupmap::GroupMap;
+use std::time::Instant;
+use colored::Colorize;
+use rand::{SeedableRng, rngs::StdRng};
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 1 << 16; // Plonk domain size
+
+// This function times the proof creation
+//     group_map: hash-to-curve map of the opening argument
+//     witness: computation witness
+//     index: Index
+//     config: proof creation options
+//     name: label of the timing
+fn prove(group_map: &<Affine as CommitmentCurve>::Map, witness: &Vec<Fp>, index: &Index<Affine>, config: ProverConfig<Affine, SpongeQ>, name: &str)
+{
+    let start = Instant::now();
+    ProverProof::prove::<SpongeQ, SpongeR, _>(group_map, ProverWitness::Field(witness), index, vec![], config).unwrap();
+    println!("{}{:?}", name.yellow(), start.elapsed());
+}
+
+fn main()
+{
+    let srs = SRS::create(N);
+    let pipelined = common::index(&srs, N);
+    let mut sequential = common::index(&srs, N);
+    sequential.pipelined_commit = false;
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let witness = common::witness(Fp::from(5u64), N);
+
+    prove(&group_map, &witness, &sequential, ProverConfig::new(), "sequential wire commitments: ");
+    prove(&group_map, &witness, &pipelined, ProverConfig::new(), "parallel wire commitments: ");
+
+    // the quotient contributions are accumulated sequentially in the low memory mode
+    prove(&group_map, &witness, &pipelined, ProverConfig::new().with_rng(&mut StdRng::seed_from_u64(7)), "parallel quotient contributions: ");
+    prove(&group_map, &witness, &pipelined, ProverConfig::new().with_low_mem().with_rng(&mut StdRng::seed_from_u64(7)), "sequential quotient contributions: ");
+}
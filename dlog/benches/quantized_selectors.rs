@@ -0,0 +1,68 @@
+/*********************************************************************************************************
+
+This source file benchmarks the commitments to the generic constraint selectors of the values drawn
+from the {-1, 0, 1} set over the Lagrange base against their multi-scalar multiplication commitments
+for the following computation:
+
+    a_0 = x, a_i+1 = a_i + x
+
+with x being the public input
+
+The Lagrange base is precomputed once per domain, its cost being amortized over the verifier indexes
+created against it.
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem};
+use oracle::poseidon::ArithmeticSpongeParams;
+use commitment_dlog::srs::SRS;
+use algebra::tweedle::{dee::Affine, fp::Fp};
+use plonk_protocol_dlog::index::{Index, SRSSpec};
+use std::time::{Duration, Instant};
+use colored::Colorize;
+
+const ROWS: usize = 1000;
+const INDEXES: u32 = 10; // number of the verifier indexes sharing the Lagrange base
+
+fn main()
+{
+    let mut builder = CircuitBuilder::<Fp>::new();
+    let x = builder.public_input(Fp::from(3u64));
+    let mut a = x;
+    (1..ROWS).for_each(|_| a = builder.add(a, x));
+
+    let (gates, _witness) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+
+    let start = Instant::now();
+    let basis = srs.lagrange_basis(index.cs.domain.d1);
+    let precomputation = start.elapsed();
+    println!("{}{:?}", "Lagrange base precomputation: ".yellow(), precomputation);
+
+    let mut msm = Duration::default();
+    let mut quantized = Duration::default();
+    for _ in 0..INDEXES
+    {
+        let start = Instant::now();
+        index.verifier_index();
+        msm += start.elapsed();
+
+        let start = Instant::now();
+        index.verifier_index_with_lagrange_basis(&basis);
+        quantized += start.elapsed();
+    }
+
+    println!("{}{:?}", "verifier index with MSM selector commitments: ".yellow(), msm / INDEXES);
+    println!("{}{:?}", "verifier index with quantized selector commitments: ".green(), quantized / INDEXES);
+    println!("{}{:?}", "verifier index with quantized selector commitments, amortized Lagrange base: ".green(),
+        (quantized + precomputation) / INDEXES);
+}
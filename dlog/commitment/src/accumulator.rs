@@ -0,0 +1,116 @@
+/*****************************************************************************************************************
+
+This source file implements the aggregation of the polynomial commitment opening proofs into a single opening.
+
+The expensive part of the opening proof verification is the check of its sg component, the commitment to the
+challenge polynomial b(X) = prod_i (1 + u_i X^(2^(k-1-i))) of the opening challenges u_i, which takes the
+multi-scalar multiplication of the SRS size. The opening proofs are verified succinctly, with their sg components
+taken as given, and the checks of the sg components are accumulated: with the challenges r, x squeezed from the
+transcript of the accumulators, the single opening proof of the combined challenge polynomial sum_i r^i b_i(X)
+at x against the combined commitment sum_i r^i sg_i proves all of the sg components at once.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField, ProjectiveCurve, VariableBaseMSM, One, Zero};
+use ff_fft::DensePolynomial;
+use groupmap::GroupMap;
+use oracle::{FqSponge, utils::PolyUtils};
+use rand_core::RngCore;
+use crate::commitment::{CommitmentCurve, PolyComm, OpeningProof, b_poly, b_poly_coefficients, ceil_log2};
+use crate::srs::SRS;
+pub use crate::CommitmentField;
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+// This function absorbs the accumulators into the transcript and squeezes the combination challenge
+// of the accumulators and the evaluation point of the combined challenge polynomial
+//     accumulators: opening challenges and sg components of the opening proofs
+//     RETURN: combination challenge and evaluation point
+fn accumulator_challenges<G: AffineCurve, EFqSponge: FqSponge<Fq<G>, G, Fr<G>>>
+(
+    sponge: &mut EFqSponge,
+    accumulators: &[(Vec<Fr<G>>, G)],
+) -> (Fr<G>, Fr<G>)
+{
+    for (chal, sg) in accumulators.iter()
+    {
+        sponge.absorb_fr(chal);
+        sponge.absorb_g(&[*sg]);
+    }
+    let r = sponge.challenge();
+    let x = sponge.challenge();
+    (r, x)
+}
+
+impl<G: CommitmentCurve> OpeningProof<G> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function aggregates the accumulators of the succinctly verified opening proofs into a single
+    // opening proof of the combined challenge polynomial
+    //     srs: SRS of the opening proofs, of the power of two size
+    //     group_map: hash-to-curve map for the opening argument, has to match the verifier's one
+    //     accumulators: opening challenges and sg components of the opening proofs
+    //     sponge: aggregation transcript sponge, has to match the verifier's one
+    //     RETURN: aggregated opening proof, None unless the SRS size is a power of two
+    pub fn aggregate<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>
+    (
+        srs: &SRS<G>,
+        group_map: &M,
+        accumulators: &[(Vec<Fr<G>>, G)],
+        sponge: EFqSponge,
+        rng: &mut dyn RngCore,
+    ) -> Option<Self>
+    {
+        if 1 << ceil_log2(srs.g.len()) != srs.g.len() {return None}
+        let mut sponge = sponge;
+        let (r, x) = accumulator_challenges(&mut sponge, accumulators);
+
+        let mut b = DensePolynomial::<Fr<G>>::zero();
+        let mut scale = Fr::<G>::one();
+        for (chal, _) in accumulators.iter()
+        {
+            b += &DensePolynomial::from_coefficients_vec(b_poly_coefficients(chal)).scale(scale);
+            scale *= &r;
+        }
+        let omega = PolyComm {unshifted: vec![Fr::<G>::zero()], shifted: None};
+        Some(srs.open(group_map, vec![(&b, None, omega)], &vec![x], Fr::<G>::one(), Fr::<G>::one(), sponge, rng))
+    }
+}
+
+impl<G: CommitmentCurve> SRS<G> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function verifies the aggregated opening proof against the accumulators
+    //     group_map: hash-to-curve map for the opening argument, has to match the prover's one
+    //     accumulators: opening challenges and sg components of the succinctly verified opening proofs
+    //     proof: aggregated opening proof
+    //     sponge: aggregation transcript sponge, has to match the prover's one
+    //     RETURN: verification status
+    pub fn verify_aggregated<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>
+    (
+        &self,
+        group_map: &M,
+        accumulators: &[(Vec<Fr<G>>, G)],
+        proof: &OpeningProof<G>,
+        sponge: EFqSponge,
+        rng: &mut dyn RngCore,
+    ) -> bool
+    {
+        let mut sponge = sponge;
+        let (r, x) = accumulator_challenges(&mut sponge, accumulators);
+
+        let mut scale = Fr::<G>::one();
+        let mut scalars = Vec::with_capacity(accumulators.len());
+        let mut eval = Fr::<G>::zero();
+        for (chal, _) in accumulators.iter()
+        {
+            scalars.push(scale.into_repr());
+            eval += &(scale * &b_poly(chal, x));
+            scale *= &r;
+        }
+        let sg = accumulators.iter().map(|(_, sg)| *sg).collect::<Vec<_>>();
+        let comm = PolyComm {unshifted: vec![VariableBaseMSM::multi_scalar_mul(&sg, &scalars).into_affine()], shifted: None};
+
+        let evals = vec![eval];
+        self.verify(group_map, &mut vec![(sponge, vec![x], Fr::<G>::one(), Fr::<G>::one(), vec![(&comm, vec![&evals], None)], proof)], rng)
+    }
+}
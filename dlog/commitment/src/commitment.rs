@@ -28,7 +28,7 @@ pub use crate::CommitmentField;
 type Fr<G> = <G as AffineCurve>::ScalarField;
 type Fq<G> = <G as AffineCurve>::BaseField;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "ocaml_types", derive(ocaml::ToValue, ocaml::FromValue))]
 pub struct PolyComm<C>
 {
@@ -100,7 +100,7 @@ impl<C: AffineCurve> PolyComm<C>
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "ocaml_types", derive(ocaml::ToValue, ocaml::FromValue))]
 pub struct OpeningProof<G: AffineCurve> {
     pub lr: Vec<(G, G)>, // vector of rounds of L & R commitments
@@ -228,6 +228,10 @@ pub trait CommitmentCurve : AffineCurve {
     fn to_coordinates(&self) -> Option<(Self::BaseField, Self::BaseField)>;
     fn of_coordinates(x : Self::BaseField, y : Self::BaseField) -> Self;
 
+    // This function checks the point to be on the curve and in its prime order subgroup,
+    // the point at infinity being valid
+    fn is_valid(&self) -> bool;
+
     // Combine where x1 = one
     fn combine_one(g1: &Vec<Self>, g2: &Vec<Self>, x2:Self::ScalarField) -> Vec<Self> {
         crate::combine::window_combine(g1, g2, Self::ScalarField::one(), x2)
@@ -254,6 +258,10 @@ impl<P : SWModelParameters> CommitmentCurve for SWJAffine<P> where P::BaseField
         SWJAffine::<P>::new(x, y, false)
     }
 
+    fn is_valid(&self) -> bool {
+        self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+    }
+
     fn combine_one(g1: &Vec<Self>, g2: &Vec<Self>, x2:Self::ScalarField) -> Vec<Self> {
         crate::combine::affine_window_combine_one(g1, g2, x2)
     }
@@ -263,8 +271,14 @@ impl<P : SWModelParameters> CommitmentCurve for SWJAffine<P> where P::BaseField
     }
 }
 
-fn to_group<G : CommitmentCurve>(
-    m: &G::Map,
+// Hash-to-curve of the opening argument: the curve point U of the inner product argument
+// is obtained as the image of the Fq sponge challenge under the group map. The default
+// map of a curve is CommitmentCurve::Map (BWParameters, the Shallue-van de Woestijne
+// construction as optimized by Fouque-Tibouchi and Wahby-Boneh). Any GroupMap can be
+// supplied instead, but the prover and the verifier have to use the same map, otherwise
+// the combined inner product is bound to different points U and the opening is rejected.
+fn to_group<G : CommitmentCurve, M : GroupMap<Fq<G>>>(
+    m: &M,
     t: <G as AffineCurve>::BaseField) -> G {
     let (x, y) = m.to_group(t);
     G::of_coordinates(x, y)
@@ -312,114 +326,58 @@ pub fn combined_inner_product<G: CommitmentCurve>(
     res
 }
 
-impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
-    pub fn commit(
-        &self,
-        plnm: &DensePolynomial<Fr<G>>,
-        max: Option<usize>,
-        rng: &mut dyn RngCore,
-    ) -> (PolyComm<G>, PolyComm<Fr<G>>)
-    {
-        self.mask(self.commit_non_hiding(plnm, max), rng)
-    }
-
-    fn mask(
-        &self,
-        c : PolyComm<G>,
-        rng: &mut dyn RngCore,
-    ) -> (PolyComm<G>, PolyComm<Fr<G>>) {
-        c.map(|g : G| {
-            if g.is_zero() {
-                // TODO: This leaks information when g is the identity!
-                // We should change this so that we still mask in this case
-                (g, Fr::<G>::zero())
-            } else {
-                let w = Fr::<G>::rand(rng);
-                let mut g_masked = self.h.mul(w);
-                g_masked.add_assign_mixed(&g);
-                (g_masked.into_affine(), w)
-            }
-        }).unzip()
-    }
-
-    // This function commits a polynomial against URS instance
-    //     plnm: polynomial to commit to with max size of sections
-    //     max: maximal degree of the polynomial, if none, no degree bound
-    //     RETURN: tuple of: unbounded commitment vector, optional bounded commitment
-    pub fn commit_non_hiding(
-        &self,
-        plnm: &DensePolynomial<Fr<G>>,
-        max: Option<usize>,
-    ) -> PolyComm<G>
-    {
-        let n = self.g.len();
-        let p = plnm.coeffs.len();
-
-        // committing all the segments without shifting
-        let unshifted = if plnm.is_zero() {Vec::new()}
-        else
-        {
-            (0..p/n + if p%n != 0 {1} else {0}).map
-            (
-                |i|
-                {
-                    VariableBaseMSM::multi_scalar_mul
-                    (
-                        &self.g, &plnm.coeffs[i*n..p].iter().map(|s| s.into_repr()).collect::<Vec<_>>()
-                    ).into_affine()
-                }
-            ).collect()
-        };
-
-        // committing only last segment shifted to the right edge of SRS
-        let shifted = match max
-        {
-            None => None,
-            Some(max) =>
-            {
-                let start = max - (max % n);
-                if plnm.is_zero() || start >= p {Some(G::zero())}
-                else if max % n == 0 {None}
-                else
-                {
-                    Some(VariableBaseMSM::multi_scalar_mul
-                    (
-                        &self.g[n - (max%n)..],
-                        &plnm.coeffs[start..p].iter().map(|s| s.into_repr()).collect::<Vec<_>>()
-                    ).into_affine())
-                }
-            }
-        };
+// This function blinds the non-hiding commitment against the blinding group element
+//     h: blinding group element
+//     c: non-hiding commitment
+//     RETURN: tuple of: blinded commitment, commitment randomness
+pub(crate) fn mask<G: AffineCurve>(
+    h: G,
+    c : PolyComm<G>,
+    rng: &mut dyn RngCore,
+) -> (PolyComm<G>, PolyComm<Fr<G>>) {
+    c.map(|g : G| {
+        if g.is_zero() {
+            // TODO: This leaks information when g is the identity!
+            // We should change this so that we still mask in this case
+            (g, Fr::<G>::zero())
+        } else {
+            let w = Fr::<G>::rand(rng);
+            let mut g_masked = h.mul(w);
+            g_masked.add_assign_mixed(&g);
+            (g_masked.into_affine(), w)
+        }
+    }).unzip()
+}
 
-        PolyComm::<G>{unshifted, shifted}
-    }
+// State of the opening argument folded over the rounds, shared by the openings against the SRS
+// held in memory and the chunk-streamed one, which differ in the folding of the g group elements only
+pub(crate) struct Opening<G: AffineCurve> {
+    pub a: Vec<Fr<G>>,                  // folded coefficients
+    b: Vec<Fr<G>>,                      // folded scaled evaluation powers
+    u: G,                               // inner product base
+    blinding_factor: Fr<G>,             // combined commitment randomness
+    lr: Vec<(G, G)>,                    // rounds of L & R commitments
+    blinders: Vec<(Fr<G>, Fr<G>)>,      // rounds of L & R commitment randomness
+    chals: Vec<(Fr<G>, Fr<G>)>,         // rounds of challenges and their inverses
+}
 
-    // This function opens polynomial commitments in batch
-    //     plnms: batch of polynomials to open commitments for with, optionally, max degrees
-    //     elm: evaluation point vector to open the commitments at
-    //     polyscale: polynomial scaling factor for opening commitments in batch
-    //     evalscale: eval scaling factor for opening commitments in batch
-    //     oracle_params: parameters for the random oracle argument
-    //     RETURN: commitment opening proof
-    pub fn open<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>>(
-        &self,
-        group_map: &G::Map,
-        plnms: Vec<(&DensePolynomial<Fr<G>>, Option<usize>, PolyComm<Fr<G>>)>, // vector of polynomial with optional degree bound and commitment randomness
-        elm: &Vec<Fr<G>>,                                     // vector of evaluation points
-        polyscale: Fr<G>,                                     // scaling factor for polynoms
-        evalscale: Fr<G>, // scaling factor for evaluation point powers
-        mut sponge: EFqSponge, // sponge
-        rng: &mut dyn RngCore,
-    ) -> OpeningProof<G> {
-        let rounds = ceil_log2(self.g.len());
+impl<G: CommitmentCurve> Opening<G> where G::ScalarField : CommitmentField {
+    // This function combines the batch of polynomials to open into the coefficient and the eval power
+    // vectors padded to the power of two, absorbing their inner product into the sponge
+    //     n: SRS size
+    //     RETURN: opening argument state before the first round
+    pub fn new<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        n: usize,
+        group_map: &M,
+        plnms: &[(&DensePolynomial<Fr<G>>, Option<usize>, PolyComm<Fr<G>>)],
+        elm: &[Fr<G>],
+        polyscale: Fr<G>,
+        evalscale: Fr<G>,
+        sponge: &mut EFqSponge,
+    ) -> Self {
+        let rounds = ceil_log2(n);
         let padded_length = 1 << rounds;
 
-        // TODO: Trim this to the degree of the largest polynomial
-
-        let padding = padded_length - self.g.len();
-        let mut g = self.g.clone();
-        g.extend(vec![G::zero(); padding]);
-
         // scale the polynoms in accumulator shifted, if bounded, to the end of SRS
         let (p, blinding_factor) = {
             let mut p = DensePolynomial::<Fr<G>>::zero();
@@ -436,16 +394,16 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
                     assert!(p_i.coeffs.len() <= m + 1);
                     while offset < p_i.coeffs.len() {
                         let segment = DensePolynomial::<Fr<G>>::from_coefficients_slice
-                            (&p_i.coeffs[offset..if offset+self.g.len() > p_i.coeffs.len() {p_i.coeffs.len()} else {offset+self.g.len()}]);
+                            (&p_i.coeffs[offset..if offset+n > p_i.coeffs.len() {p_i.coeffs.len()} else {offset+n}]);
                         // always mixing in the unshifted segments
                         p += &segment.scale(scale);
                         omega += &(omegas.unshifted[j] * scale);
                         j += 1;
                         scale *= &polyscale;
-                        offset += self.g.len();
+                        offset += n;
                         if offset > *m {
                             // mixing in the shifted segment since degree is bounded
-                            p += &(segment.shiftr(self.g.len() - m%self.g.len()).scale(scale));
+                            p += &(segment.shiftr(n - m%n).scale(scale));
                             omega += &(omegas.shifted.unwrap() * scale);
                             scale *= &polyscale;
                         }
@@ -455,13 +413,13 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
                     assert!(omegas.shifted.is_none());
                     while offset < p_i.coeffs.len() {
                         let segment = DensePolynomial::<Fr<G>>::from_coefficients_slice
-                            (&p_i.coeffs[offset..if offset+self.g.len() > p_i.coeffs.len() {p_i.coeffs.len()} else {offset+self.g.len()}]);
+                            (&p_i.coeffs[offset..if offset+n > p_i.coeffs.len() {p_i.coeffs.len()} else {offset+n}]);
                         // always mixing in the unshifted segments
                         p += &segment.scale(scale);
                         omega += &(omegas.unshifted[j] * scale);
                         j += 1;
                         scale *= &polyscale;
-                        offset += self.g.len();
+                        offset += n;
                     }
                 }
                 assert_eq!(j, omegas.unshifted.len());
@@ -469,10 +427,8 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
             (p, omega)
         };
 
-        let rounds = ceil_log2(self.g.len());
-
         // b_j = sum_i r^i elm_i^j
-        let b_init = {
+        let b = {
             // randomise/scale the eval powers
             let mut scale = Fr::<G>::one();
             let mut res: Vec<Fr<G>> = (0..padded_length).map(|_| Fr::<G>::zero()).collect();
@@ -486,120 +442,269 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
         };
 
         let combined_inner_product = 
-            p.coeffs.iter().zip(b_init.iter())
+            p.coeffs.iter().zip(b.iter())
             .map(|(a, b)| *a * b)
             .fold(Fr::<G>::zero(), |acc, x| acc + x);
 
         sponge.absorb_fr(&[shift_scalar(combined_inner_product)]);
 
         let t = sponge.challenge_fq();
-        let u: G = to_group(group_map, t);
+        let u: G = to_group::<G, M>(group_map, t);
 
         let mut a = p.coeffs;
         assert!(padded_length >= a.len());
         a.extend(vec![Fr::<G>::zero(); padded_length - a.len()]);
 
-        let mut b = b_init.clone();
+        Opening {a, b, u, blinding_factor, lr: vec![], blinders: vec![], chals: vec![]}
+    }
+
+    // This function runs the round of the opening argument, folding the coefficients and the eval powers
+    //     h: blinding group element
+    //     endo_r: scalar field endomorphism coefficient
+    //     l: commitment of the upper coefficient half against the lower g half
+    //     r: commitment of the lower coefficient half against the upper g half
+    //     RETURN: round challenge to fold the g group elements with
+    pub fn round<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>>(
+        &mut self,
+        h: G,
+        endo_r: &Fr<G>,
+        l: G::Projective,
+        r: G::Projective,
+        sponge: &mut EFqSponge,
+        rng: &mut dyn RngCore,
+    ) -> Fr<G> {
+        let n = self.a.len() / 2;
+        let (a_lo, a_hi) = (&self.a[0..n], &self.a[n..]);
+        let (b_lo, b_hi) = (&self.b[0..n], &self.b[n..]);
 
-        let mut lr = vec![];
+        let rand_l = Fr::<G>::rand(rng);
+        let rand_r = Fr::<G>::rand(rng);
 
-        let mut blinders = vec![];
+        let l = (l + &h.mul(rand_l) + &self.u.mul(inner_prod(a_hi, b_lo))).into_affine();
+        let r = (r + &h.mul(rand_r) + &self.u.mul(inner_prod(a_lo, b_hi))).into_affine();
 
-        let mut chals = vec![];
-        let mut chal_invs = vec![];
+        self.lr.push((l, r));
+        self.blinders.push((rand_l, rand_r));
 
-        for _ in 0..rounds {
-            let n = g.len() / 2;
-            let (g_lo, g_hi) = (g[0..n].to_vec(), g[n..].to_vec());
-            let (a_lo, a_hi) = (&a[0..n], &a[n..]);
-            let (b_lo, b_hi) = (&b[0..n], &b[n..]);
+        sponge.absorb_g(&[l]);
+        sponge.absorb_g(&[r]);
 
-            let rand_l = Fr::<G>::rand(rng);
-            let rand_r = Fr::<G>::rand(rng);
+        let u = squeeze_challenge(endo_r, sponge);
+        let u_inv = u.inverse().unwrap();
 
-            let l = VariableBaseMSM::multi_scalar_mul(
-                &[&g[0..n], &[self.h, u]].concat(),
-                &[&a[n..], &[rand_l, inner_prod(a_hi, b_lo)]].concat()
-                    .iter().map(|x| x.into_repr()).collect::<Vec<_>>()
-            ).into_affine();
+        self.chals.push((u, u_inv));
 
-            let r = VariableBaseMSM::multi_scalar_mul(
-                &[&g[n..], &[self.h, u]].concat(),
-                &[&a[0..n], &[rand_r, inner_prod(a_lo, b_hi)]].concat()
-                    .iter().map(|x| x.into_repr()).collect::<Vec<_>>()
-            ).into_affine();
-
-            lr.push((l, r));
-            blinders.push((rand_l, rand_r));
-
-            sponge.absorb_g(&[l]);
-            sponge.absorb_g(&[r]);
-
-            let u = squeeze_challenge(&self.endo_r, &mut sponge);
-            let u_inv = u.inverse().unwrap();
-
-            chals.push(u);
-            chal_invs.push(u_inv);
-
-            a = a_hi
-                .par_iter()
-                .zip(a_lo)
-                .map(|(&hi, &lo)| {
-                    // lo + u_inv * hi
-                    let mut res = hi;
-                    res *= u_inv;
-                    res += &lo;
-                    res
-                })
-                .collect();
+        let a = a_hi
+            .par_iter()
+            .zip(a_lo)
+            .map(|(&hi, &lo)| {
+                // lo + u_inv * hi
+                let mut res = hi;
+                res *= u_inv;
+                res += &lo;
+                res
+            })
+            .collect();
 
-            b = b_lo
-                .par_iter()
-                .zip(b_hi)
-                .map(|(&lo, &hi)| {
-                    // lo + u * hi
-                    let mut res = hi;
-                    res *= u;
-                    res += &lo;
-                    res
-                })
-                .collect();
+        let b = b_lo
+            .par_iter()
+            .zip(b_hi)
+            .map(|(&lo, &hi)| {
+                // lo + u * hi
+                let mut res = hi;
+                res *= u;
+                res += &lo;
+                res
+            })
+            .collect();
 
-            g = G::combine_one(&g_lo, &g_hi, u);
-        }
+        self.a = a;
+        self.b = b;
+        u
+    }
 
-        assert!(g.len() == 1);
-        let a0 = a[0];
-        let b0 = b[0];
-        let g0 = g[0];
+    // This function completes the opening proof after the last round
+    //     h: blinding group element
+    //     endo_r: scalar field endomorphism coefficient
+    //     g0: folded g group element
+    //     RETURN: commitment opening proof
+    pub fn finish<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>>(
+        self,
+        h: G,
+        endo_r: &Fr<G>,
+        g0: G,
+        mut sponge: EFqSponge,
+        rng: &mut dyn RngCore,
+    ) -> OpeningProof<G> {
+        assert!(self.a.len() == 1);
+        let a0 = self.a[0];
+        let b0 = self.b[0];
 
-        let r_prime = blinders
+        let r_prime = self.blinders
             .iter()
-            .zip(chals.iter().zip(chal_invs.iter()))
+            .zip(self.chals.iter())
             .map(|((l, r), (u, u_inv))| ((*l) * u_inv) + &(*r * u))
-            .fold(blinding_factor, |acc, x| acc + &x);
+            .fold(self.blinding_factor, |acc, x| acc + &x);
 
         let d = Fr::<G>::rand(rng);
         let r_delta = Fr::<G>::rand(rng);
 
-        let delta = ((g0.into_projective() + &(u.mul(b0))).into_affine().mul(d)
-            + &self.h.mul(r_delta))
+        let delta = ((g0.into_projective() + &(self.u.mul(b0))).into_affine().mul(d)
+            + &h.mul(r_delta))
             .into_affine();
 
         sponge.absorb_g(&[delta]);
-        let c = ScalarChallenge(sponge.challenge()).to_field(&self.endo_r);
+        let c = ScalarChallenge(sponge.challenge()).to_field(endo_r);
 
         let z1 = a0 * &c + &d;
         let z2 = c * &r_prime + &r_delta;
 
         OpeningProof {
             delta,
-            lr,
+            lr: self.lr,
             z1,
             z2,
             sg: g0,
         }
     }
+}
+
+impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
+    pub fn commit(
+        &self,
+        plnm: &DensePolynomial<Fr<G>>,
+        max: Option<usize>,
+        rng: &mut dyn RngCore,
+    ) -> (PolyComm<G>, PolyComm<Fr<G>>)
+    {
+        self.mask(self.commit_non_hiding(plnm, max), rng)
+    }
+
+    // This function blinds the non-hiding commitment with the commitment randomness
+    //     c: non-hiding commitment
+    //     RETURN: tuple of: blinded commitment, commitment randomness
+    pub fn mask(
+        &self,
+        c : PolyComm<G>,
+        rng: &mut dyn RngCore,
+    ) -> (PolyComm<G>, PolyComm<Fr<G>>) {
+        mask(self.h, c, rng)
+    }
+
+    // This function blinds the non-hiding commitment with the given commitment randomness, reproducing
+    // the commitment of mask from its randomness or re-randomizing an already blinded commitment
+    //     c: commitment
+    //     omega: commitment randomness, of the same segments as the commitment
+    //     RETURN: blinded commitment
+    pub fn mask_with(
+        &self,
+        c : &PolyComm<G>,
+        omega : &PolyComm<Fr<G>>,
+    ) -> PolyComm<G> {
+        let blind = |g : &G, w : &Fr<G>| {
+            let mut g_masked = self.h.mul(*w);
+            g_masked.add_assign_mixed(g);
+            g_masked.into_affine()
+        };
+        PolyComm {
+            unshifted: c.unshifted.iter().zip(omega.unshifted.iter()).map(|(g, w)| blind(g, w)).collect(),
+            shifted: c.shifted.as_ref().zip(omega.shifted.as_ref()).map(|(g, w)| blind(g, w)),
+        }
+    }
+
+    // This function commits a polynomial against URS instance
+    //     plnm: polynomial to commit to with max size of sections
+    //     max: maximal degree of the polynomial, if none, no degree bound
+    //     RETURN: tuple of: unbounded commitment vector, optional bounded commitment
+    pub fn commit_non_hiding(
+        &self,
+        plnm: &DensePolynomial<Fr<G>>,
+        max: Option<usize>,
+    ) -> PolyComm<G>
+    {
+        let n = self.g.len();
+        let p = plnm.coeffs.len();
+
+        // committing all the segments without shifting
+        let unshifted = if plnm.is_zero() {Vec::new()}
+        else
+        {
+            (0..p/n + if p%n != 0 {1} else {0}).map
+            (
+                |i|
+                {
+                    VariableBaseMSM::multi_scalar_mul
+                    (
+                        &self.g, &plnm.coeffs[i*n..p].iter().map(|s| s.into_repr()).collect::<Vec<_>>()
+                    ).into_affine()
+                }
+            ).collect()
+        };
+
+        // committing only last segment shifted to the right edge of SRS, the bound of a multiple of the
+        // SRS size needing no shift, so that the shape of the commitment depends on the bound only
+        let shifted = match max
+        {
+            None => None,
+            Some(max) =>
+            {
+                let start = max - (max % n);
+                if max % n == 0 {None}
+                else if plnm.is_zero() || start >= p {Some(G::zero())}
+                else
+                {
+                    Some(VariableBaseMSM::multi_scalar_mul
+                    (
+                        &self.g[n - (max%n)..],
+                        &plnm.coeffs[start..p].iter().map(|s| s.into_repr()).collect::<Vec<_>>()
+                    ).into_affine())
+                }
+            }
+        };
+
+        PolyComm::<G>{unshifted, shifted}
+    }
+
+    // This function opens polynomial commitments in batch
+    //     plnms: batch of polynomials to open commitments for with, optionally, max degrees
+    //     elm: evaluation point vector to open the commitments at
+    //     polyscale: polynomial scaling factor for opening commitments in batch
+    //     evalscale: eval scaling factor for opening commitments in batch
+    //     oracle_params: parameters for the random oracle argument
+    //     group_map: hash-to-curve map for the opening argument, has to match the verifier's one
+    //     RETURN: commitment opening proof
+    pub fn open<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        &self,
+        group_map: &M,
+        plnms: Vec<(&DensePolynomial<Fr<G>>, Option<usize>, PolyComm<Fr<G>>)>, // vector of polynomial with optional degree bound and commitment randomness
+        elm: &Vec<Fr<G>>,                                     // vector of evaluation points
+        polyscale: Fr<G>,                                     // scaling factor for polynoms
+        evalscale: Fr<G>, // scaling factor for evaluation point powers
+        mut sponge: EFqSponge, // sponge
+        rng: &mut dyn RngCore,
+    ) -> OpeningProof<G> {
+        let mut opening = Opening::new(self.g.len(), group_map, &plnms, elm, polyscale, evalscale, &mut sponge);
+
+        // TODO: Trim this to the degree of the largest polynomial
+
+        let mut g = self.g.clone();
+        g.extend(vec![G::zero(); opening.a.len() - self.g.len()]);
+
+        while g.len() > 1 {
+            let n = g.len() / 2;
+            let (g_lo, g_hi) = (g[0..n].to_vec(), g[n..].to_vec());
+
+            let l = VariableBaseMSM::multi_scalar_mul(
+                &g_lo, &opening.a[n..].iter().map(|x| x.into_repr()).collect::<Vec<_>>());
+            let r = VariableBaseMSM::multi_scalar_mul(
+                &g_hi, &opening.a[0..n].iter().map(|x| x.into_repr()).collect::<Vec<_>>());
+
+            let u = opening.round(self.h, &self.endo_r, l, r, &mut sponge, rng);
+            g = G::combine_one(&g_lo, &g_hi, u);
+        }
+
+        opening.finish(self.h, &self.endo_r, g[0], sponge, rng)
+    }
 
     // This function verifies batch of batched polynomial commitment opening proofs
     //     batch: batch of batched polynomial commitment opening proofs
@@ -609,11 +714,12 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
     //          batch/vector of polycommitments (opened in this batch), evaluation vectors and, optionally, max degrees
     //          opening proof for this batched opening
     //     oracle_params: parameters for the random oracle argument
+    //     group_map: hash-to-curve map for the opening argument, has to match the prover's one
     //     randomness source context
     //     RETURN: verification status
-    pub fn verify<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>>(
+    pub fn verify<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
         &self,
-        group_map: &G::Map,
+        group_map: &M,
         batch: &mut Vec<(
             EFqSponge,
             Vec<Fr<G>>, // vector of evaluation points
@@ -628,6 +734,46 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
         )>,
         rng: &mut dyn RngCore,
     ) -> bool {
+        self.verify_opening(group_map, batch, true, rng).is_some()
+    }
+
+    // This function verifies batch of batched polynomial commitment opening proofs succinctly, taking
+    // the sg components of the proofs as given instead of checking them against the SRS, so that the
+    // multi-scalar multiplication is logarithmic in the SRS size. The sg components along with their
+    // challenges are to be checked by an accumulated opening, see accumulator
+    //     batch: batch of batched polynomial commitment opening proofs as with verify
+    //     group_map: hash-to-curve map for the opening argument, has to match the prover's one
+    //     RETURN: opening challenges of the proofs of the batch, None if the verification fails
+    pub fn verify_succinct<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        &self,
+        group_map: &M,
+        batch: &mut Vec<(
+            EFqSponge,
+            Vec<Fr<G>>,
+            Fr<G>,
+            Fr<G>,
+            Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>,
+            &OpeningProof<G>,
+        )>,
+        rng: &mut dyn RngCore,
+    ) -> Option<Vec<Vec<Fr<G>>>> {
+        self.verify_opening(group_map, batch, false, rng)
+    }
+
+    fn verify_opening<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        &self,
+        group_map: &M,
+        batch: &mut Vec<(
+            EFqSponge,
+            Vec<Fr<G>>,
+            Fr<G>,
+            Fr<G>,
+            Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>,
+            &OpeningProof<G>,
+        )>,
+        check_sg: bool,
+        rng: &mut dyn RngCore,
+    ) -> Option<Vec<Vec<Fr<G>>>> {
         // Verifier checks for all i,
         // c_i Q_i + delta_i = z1_i (G_i + b_i U_i) + z2_i H
         //
@@ -646,7 +792,7 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
         // - (r^i z1_i b_i) U_i
 
         // We also check that the sg component of the proof is equal to the polynomial commitment
-        // to the "s" array
+        // to the "s" array, unless verifying succinctly
 
         let nonzero_length = self.g.len();
 
@@ -657,11 +803,13 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
         // TODO: This will need adjusting
         let padding = padded_length - nonzero_length;
         let mut points = vec![self.h];
-        points.extend(self.g.clone());
-        points.extend(vec![G::zero(); padding]);
+        if check_sg {
+            points.extend(self.g.clone());
+            points.extend(vec![G::zero(); padding]);
+        }
 
-        let mut scalars = vec![Fr::<G>::zero(); padded_length + 1];
-        assert_eq!(scalars.len(), points.len());
+        let mut scalars = vec![Fr::<G>::zero(); points.len()];
+        let mut chals = Vec::with_capacity(batch.len());
 
         // sample randomiser to scale the proofs with
         let rand_base = Fr::<G>::rand(rng);
@@ -687,7 +835,7 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
             sponge.absorb_fr(&[shift_scalar(combined_inner_product0)]);
 
             let t = sponge.challenge_fq();
-            let u: G = to_group(group_map, t);
+            let u: G = to_group::<G, M>(group_map, t);
 
             let Challenges { chal, chal_inv } = opening.challenges::<EFqSponge>(&self.endo_r, sponge);
 
@@ -708,8 +856,6 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
                 res
             };
 
-            let s = b_poly_coefficients(&chal);
-
             let neg_rand_base_i = -rand_base_i;
 
             // TERM
@@ -717,7 +863,7 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
             //
             // we also add -sg_rand_base_i * G to check correctness of sg.
             points.push(opening.sg);
-            scalars.push(neg_rand_base_i * &opening.z1 - &sg_rand_base_i);
+            scalars.push(neg_rand_base_i * &opening.z1 - &(if check_sg {sg_rand_base_i} else {Fr::<G>::zero()}));
 
             // Here we add
             // sg_rand_base_i * ( < s, self.g > )
@@ -725,7 +871,8 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
             // < sg_rand_base_i s, self.g >
             //
             // to check correctness of the sg component.
-            {
+            if check_sg {
+                let s = b_poly_coefficients(&chal);
                 let terms: Vec<_> = s.par_iter().map(|s| sg_rand_base_i * s).collect();
 
                 for (i, term) in terms.iter().enumerate() {
@@ -796,10 +943,54 @@ impl<G: CommitmentCurve> SRS<G> where G::ScalarField : CommitmentField {
 
             rand_base_i *= &rand_base;
             sg_rand_base_i *= &sg_rand_base;
+            chals.push(chal);
         }
         // verify the equation
         let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
-        VariableBaseMSM::multi_scalar_mul(&points, &scalars) == G::Projective::zero()
+        if VariableBaseMSM::multi_scalar_mul(&points, &scalars) == G::Projective::zero() {Some(chals)} else {None}
+    }
+
+    // This function opens the polynomial commitments of several proofs sharing the evaluation points
+    // in a single opening, the polynomials of all the sets being combined with the powers of polyscale
+    //     sets: per proof batches of polynomials to open commitments for with, optionally, max degrees
+    //     elm: evaluation point vector shared by the sets
+    //     polyscale: combined polynomial scaling factor across the sets
+    //     evalscale: eval scaling factor for opening commitments in batch
+    //     group_map: hash-to-curve map for the opening argument, has to match the verifier's one
+    //     RETURN: commitment opening proof covering all the sets
+    pub fn batch_open<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        &self,
+        group_map: &M,
+        sets: Vec<Vec<(&DensePolynomial<Fr<G>>, Option<usize>, PolyComm<Fr<G>>)>>,
+        elm: &Vec<Fr<G>>,
+        polyscale: Fr<G>,
+        evalscale: Fr<G>,
+        sponge: EFqSponge,
+        rng: &mut dyn RngCore,
+    ) -> OpeningProof<G> {
+        self.open(group_map, sets.into_iter().flatten().collect(), elm, polyscale, evalscale, sponge, rng)
+    }
+
+    // This function verifies the opening produced by batch_open
+    //     sets: per proof batches of polycommitments, evaluation vectors and, optionally, max degrees
+    //     elm: evaluation point vector shared by the sets
+    //     polyscale: combined polynomial scaling factor across the sets
+    //     evalscale: eval scaling factor for opening commitments in batch
+    //     proof: commitment opening proof covering all the sets
+    //     group_map: hash-to-curve map for the opening argument, has to match the prover's one
+    //     RETURN: verification status
+    pub fn batch_verify<EFqSponge: FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>(
+        &self,
+        group_map: &M,
+        sets: Vec<Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>>,
+        elm: &Vec<Fr<G>>,
+        polyscale: Fr<G>,
+        evalscale: Fr<G>,
+        sponge: EFqSponge,
+        proof: &OpeningProof<G>,
+        rng: &mut dyn RngCore,
+    ) -> bool {
+        self.verify(group_map, &mut vec![(sponge, elm.clone(), polyscale, evalscale, sets.into_iter().flatten().collect(), proof)], rng)
     }
 }
 
@@ -3,6 +3,10 @@ mod qnr_field;
 pub use qnr_field::*;
 pub mod srs;
 pub mod commitment;
+pub mod stream;
+pub mod accumulator;
+#[cfg(feature = "test-utils")]
+pub mod reference;
 use algebra::{tweedle, bn_382};
 
 pub trait CommitmentField : QnrField + dlog_solver::DetSquareRootField {
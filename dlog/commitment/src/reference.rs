@@ -0,0 +1,33 @@
+/*****************************************************************************************************************
+
+This source file implements the reference polynomial commitment for testing.
+
+The commitment is computed by the naive multi-scalar multiplication, each SRS point being multiplied by its
+polynomial coefficient one by one and the products summed up, so that the optimized commitment computation
+can be checked against it. The reference is available with the test-utils feature only.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, ProjectiveCurve, Zero};
+use ff_fft::DensePolynomial;
+use crate::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+
+// This function computes the expected commitment of the polynomial without degree bound
+//     poly: polynomial to commit to, split into the SRS size segments
+//     srs: SRS instance
+//     RETURN: unshifted commitment of the polynomial segments
+pub fn expected_commitment<G: CommitmentCurve>(poly: &DensePolynomial<Fr<G>>, srs: &SRS<G>) -> PolyComm<G>
+{
+    let unshifted = poly.coeffs.chunks(srs.g.len()).map
+    (
+        |segment|
+        {
+            let mut acc = G::Projective::zero();
+            for (g, c) in srs.g.iter().zip(segment.iter()) {acc += &g.mul(*c)}
+            acc.into_affine()
+        }
+    ).collect();
+    PolyComm::<G>{unshifted, shifted: None}
+}
@@ -7,7 +7,8 @@ This source file implements the Marlin structured reference string primitive
 pub use crate::{QnrField, CommitmentField};
 use blake2::{Blake2b, Digest};
 use std::io::{Read, Result as IoResult, Write};
-use algebra::{FromBytes, PrimeField, ToBytes, BigInteger};
+use algebra::{FromBytes, PrimeField, ToBytes, BigInteger, Field, One, AffineCurve, ProjectiveCurve};
+use ff_fft::{EvaluationDomain, Radix2EvaluationDomain as D};
 use crate::commitment::CommitmentCurve;
 use groupmap::GroupMap;
 use array_init::array_init;
@@ -91,6 +92,41 @@ impl<G: CommitmentCurve> SRS<G> where G::BaseField : PrimeField, G::ScalarField
         }
     }
 
+    // This function computes the commitments to the Lagrange base polynomials of the domain
+    // as the inverse DFT of the SRS group elements
+    //     domain: evaluation domain of the size not exceeding the SRS size
+    //     RETURN: commitments L_i = sum_j w^(-ij) * g_j / n
+    pub fn lagrange_basis(&self, domain: D<G::ScalarField>) -> Vec<G> {
+        let n = domain.size();
+        assert!(n <= self.g.len());
+        let mut a = self.g[0..n].iter().map(|g| g.into_projective()).collect::<Vec<_>>();
+
+        // bit reversal permutation
+        let log_n = domain.log_size_of_group;
+        for i in 0..n {
+            let j = (i as u64).reverse_bits().checked_shr(64 - log_n).unwrap_or(0) as usize;
+            if i < j {a.swap(i, j)}
+        }
+
+        // radix-2 butterflies with the inverse domain generator
+        let mut m = 1;
+        while m < n {
+            let w_m = domain.group_gen_inv.pow(&[(n / (2 * m)) as u64]);
+            for k in (0..n).step_by(2 * m) {
+                let mut w = G::ScalarField::one();
+                for j in 0..m {
+                    let t = a[k + j + m].mul(w);
+                    a[k + j + m] = a[k + j] - &t;
+                    a[k + j] += &t;
+                    w *= &w_m;
+                }
+            }
+            m *= 2;
+        }
+
+        a.into_iter().map(|p| p.mul(domain.size_inv).into_affine()).collect()
+    }
+
     pub fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
         u64::write(&(self.g.len() as u64), &mut writer)?;
         for x in &self.g {
@@ -0,0 +1,246 @@
+/*****************************************************************************************************************
+
+This source file implements the chunk-streamed structured reference string.
+
+The SRS is read from its serialized form, as written by SRS::write, in chunks of the group elements, so that
+the commitments of the large circuits do not hold the full SRS in memory. With the mmap feature, the serialized
+SRS file is memory mapped, the operating system paging the group elements in as the chunks are read.
+
+The commitments and the opening proofs against the streamed SRS are equal to the ones against the loaded SRS.
+The opening folds the g group elements chunk by chunk in each round, the folded halves being written to
+the scratch storage supplied by the caller, so that at most two chunks of the group elements are held in
+memory. The coefficient and eval power vectors of the opening argument are held in memory as with SRS::open.
+
+*****************************************************************************************************************/
+
+use std::io::{Read, Write, Seek, SeekFrom, Error, ErrorKind, Result as IoResult};
+use std::ops::Range;
+use algebra::{FromBytes, ToBytes, PrimeField, AffineCurve, ProjectiveCurve, VariableBaseMSM, Zero};
+use ff_fft::DensePolynomial;
+use groupmap::GroupMap;
+use oracle::FqSponge;
+use rand_core::RngCore;
+use crate::commitment::{CommitmentCurve, PolyComm, OpeningProof, Opening, mask};
+use crate::srs::{SRS, endos};
+pub use crate::CommitmentField;
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+pub struct SRSStream<G: CommitmentCurve, R: Read + Seek>
+{
+    reader: R,          // serialized SRS
+    size: usize,        // number of the g group elements
+    element: usize,     // serialized group element size
+    pub h: G,           // blinding
+
+    // Coefficients for the curve endomorphism
+    pub endo_r: G::ScalarField,
+    pub endo_q: G::BaseField,
+}
+
+impl<G: CommitmentCurve, R: Read + Seek> SRSStream<G, R> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function opens the serialized SRS reading the blinding group element only
+    //     reader: SRS serialized by SRS::write
+    //     RETURN: streamed SRS
+    pub fn open(mut reader: R) -> IoResult<Self>
+    {
+        reader.seek(SeekFrom::Start(0))?;
+        let size = u64::read(&mut reader)? as usize;
+        let mut bytes = Vec::new();
+        G::write(&G::prime_subgroup_generator(), &mut bytes)?;
+        let element = bytes.len();
+
+        reader.seek(SeekFrom::Start((8 + size * element) as u64))?;
+        let h = G::read(&mut reader)?;
+        let (endo_q, endo_r) = endos::<G>();
+        Ok(SRSStream {reader, size, element, h, endo_r, endo_q})
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.size
+    }
+
+    // This function reads the chunk of the g group elements
+    //     range: positions of the group elements
+    //     RETURN: group elements of the chunk
+    pub fn chunk(&mut self, range: Range<usize>) -> IoResult<Vec<G>>
+    {
+        if range.end > self.size {return Err(Error::new(ErrorKind::UnexpectedEof, "SRS chunk exceeds the SRS size"))}
+        self.reader.seek(SeekFrom::Start((8 + range.start * self.element) as u64))?;
+        range.map(|_| G::read(&mut self.reader)).collect()
+    }
+
+    // This function loads the full SRS into memory
+    pub fn load(&mut self) -> IoResult<SRS<G>>
+    {
+        Ok(SRS {g: self.chunk(0..self.size)?, h: self.h, endo_r: self.endo_r, endo_q: self.endo_q})
+    }
+
+    // This function commits a polynomial against the streamed SRS, the commitment being
+    // equal to the one of SRS::commit_non_hiding against the loaded SRS
+    //     plnm: polynomial to commit to with max size of sections
+    //     max: maximal degree of the polynomial, if none, no degree bound
+    //     chunk: number of the group elements held in memory at a time
+    //     RETURN: tuple of: unbounded commitment vector, optional bounded commitment
+    pub fn commit_non_hiding(
+        &mut self,
+        plnm: &DensePolynomial<Fr<G>>,
+        max: Option<usize>,
+        chunk: usize,
+    ) -> IoResult<PolyComm<G>>
+    {
+        assert!(chunk > 0);
+        let n = self.size;
+        let p = plnm.coeffs.len();
+
+        // commitment of the coefficients against the group elements from the offset on
+        let mut msm = |offset: usize, coeffs: &[Fr<G>]| -> IoResult<G>
+        {
+            let len = coeffs.len().min(n - offset);
+            let mut acc = G::Projective::zero();
+            for start in (0..len).step_by(chunk)
+            {
+                let end = (start + chunk).min(len);
+                let g = self.chunk(offset + start..offset + end)?;
+                acc += &VariableBaseMSM::multi_scalar_mul(&g, &coeffs[start..end].iter().map(|s| s.into_repr()).collect::<Vec<_>>());
+            }
+            Ok(acc.into_affine())
+        };
+
+        // committing all the segments without shifting
+        let unshifted = if plnm.is_zero() {Vec::new()}
+        else
+        {
+            (0..p/n + if p%n != 0 {1} else {0}).map(|i| msm(0, &plnm.coeffs[i*n..p])).collect::<IoResult<Vec<_>>>()?
+        };
+
+        // committing only last segment shifted to the right edge of SRS as with SRS::commit_non_hiding
+        let shifted = match max
+        {
+            None => None,
+            Some(max) =>
+            {
+                let start = max - (max % n);
+                if max % n == 0 {None}
+                else if plnm.is_zero() || start >= p {Some(G::zero())}
+                else {Some(msm(n - (max%n), &plnm.coeffs[start..p])?)}
+            }
+        };
+
+        Ok(PolyComm::<G>{unshifted, shifted})
+    }
+
+    // This function commits a polynomial against the streamed SRS, blinding the commitment
+    // as SRS::commit does
+    //     plnm: polynomial to commit to with max size of sections
+    //     max: maximal degree of the polynomial, if none, no degree bound
+    //     chunk: number of the group elements held in memory at a time
+    //     RETURN: tuple of: blinded commitment, commitment randomness
+    pub fn commit(
+        &mut self,
+        plnm: &DensePolynomial<Fr<G>>,
+        max: Option<usize>,
+        chunk: usize,
+        rng: &mut dyn RngCore,
+    ) -> IoResult<(PolyComm<G>, PolyComm<Fr<G>>)>
+    {
+        Ok(mask(self.h, self.commit_non_hiding(plnm, max, chunk)?, rng))
+    }
+
+    // This function reads the chunk of the g group elements folded in the given number of the rounds,
+    // the SRS being padded with the zero group elements to the power of two
+    fn folded<W: Read + Write + Seek>(&mut self, scratch: &mut W, round: usize, range: Range<usize>) -> IoResult<Vec<G>>
+    {
+        if round == 0
+        {
+            let end = range.end.min(self.size);
+            let mut g = if range.start < end {self.chunk(range.start..end)?} else {Vec::new()};
+            g.resize(range.len(), G::zero());
+            Ok(g)
+        }
+        else
+        {
+            scratch.seek(SeekFrom::Start((range.start * self.element) as u64))?;
+            range.map(|_| G::read(&mut *scratch)).collect()
+        }
+    }
+
+    // This function opens polynomial commitments in batch against the streamed SRS, the opening proof
+    // being equal to the one of SRS::open against the loaded SRS with the same randomness
+    //     plnms: batch of polynomials to open commitments for with, optionally, max degrees
+    //     elm: evaluation point vector to open the commitments at
+    //     polyscale: polynomial scaling factor for opening commitments in batch
+    //     evalscale: eval scaling factor for opening commitments in batch
+    //     group_map: hash-to-curve map for the opening argument, has to match the verifier's one
+    //     chunk: number of the group elements held in memory at a time
+    //     scratch: storage of the folded group elements, of at least half of the SRS size
+    //     RETURN: commitment opening proof
+    pub fn open<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>, W: Read + Write + Seek>(
+        &mut self,
+        group_map: &M,
+        plnms: Vec<(&DensePolynomial<Fr<G>>, Option<usize>, PolyComm<Fr<G>>)>,
+        elm: &Vec<Fr<G>>,
+        polyscale: Fr<G>,
+        evalscale: Fr<G>,
+        mut sponge: EFqSponge,
+        rng: &mut dyn RngCore,
+        chunk: usize,
+        scratch: &mut W,
+    ) -> IoResult<OpeningProof<G>>
+    {
+        assert!(chunk > 0);
+        let mut opening = Opening::new(self.size, group_map, &plnms, elm, polyscale, evalscale, &mut sponge);
+
+        let mut round = 0;
+        let mut len = opening.a.len();
+        while len > 1
+        {
+            let n = len / 2;
+
+            // L & R of the group element halves read chunk by chunk
+            let mut l = G::Projective::zero();
+            let mut r = G::Projective::zero();
+            for start in (0..n).step_by(chunk)
+            {
+                let end = (start + chunk).min(n);
+                let g_lo = self.folded(scratch, round, start..end)?;
+                let g_hi = self.folded(scratch, round, n + start..n + end)?;
+                l += &VariableBaseMSM::multi_scalar_mul(&g_lo, &opening.a[n + start..n + end].iter().map(|x| x.into_repr()).collect::<Vec<_>>());
+                r += &VariableBaseMSM::multi_scalar_mul(&g_hi, &opening.a[start..end].iter().map(|x| x.into_repr()).collect::<Vec<_>>());
+            }
+            let u = opening.round(self.h, &self.endo_r, l, r, &mut sponge, rng);
+
+            // folding in place, each chunk being written over the lower half chunk it is folded from
+            for start in (0..n).step_by(chunk)
+            {
+                let end = (start + chunk).min(n);
+                let g_lo = self.folded(scratch, round, start..end)?;
+                let g_hi = self.folded(scratch, round, n + start..n + end)?;
+                scratch.seek(SeekFrom::Start((start * self.element) as u64))?;
+                for g in G::combine_one(&g_lo, &g_hi, u).iter() {g.write(&mut *scratch)?}
+            }
+            round += 1;
+            len = n;
+        }
+
+        let g0 = self.folded(scratch, round, 0..1)?[0];
+        Ok(opening.finish(self.h, &self.endo_r, g0, sponge, rng))
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<G: CommitmentCurve> SRSStream<G, std::io::Cursor<memmap::Mmap>> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function memory maps the serialized SRS file
+    //     path: file of the SRS serialized by SRS::write
+    //     RETURN: streamed SRS
+    pub fn load_mmap<P: AsRef<std::path::Path>>(path: P) -> IoResult<Self>
+    {
+        let file = std::fs::File::open(path)?;
+        // the file is not to be modified while mapped
+        let map = unsafe {memmap::Mmap::map(&file)?};
+        Self::open(std::io::Cursor::new(map))
+    }
+}
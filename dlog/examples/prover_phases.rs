@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file prints the phase breakdown of the Plonk proof creation for the 2^14 circuit
+of the following computation:
+
+    x * x = y
+
+with x being the public input, the rest of the gates being the zero ones
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{Field, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::{Index, SRSSpec}, hooks::PhaseTimer};
+use groupmap::GroupMap;
+use colored::Colorize;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 1 << 14; // Plonk domain size
+const PUBLIC: usize = 1;
+
+fn main()
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(GateWires::wires((0,   1), (N,   N), (2*N,   2*N)), p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, N+1), (N+1, 0), (2*N+1, 2*N+1)), z, z, n, p, z),
+    ];
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let x = Fp::from(5u64);
+    let mut witness = vec![Fp::zero(); 3*N];
+    witness[0] = x;
+    witness[1] = x;
+    witness[N+1] = x;
+    witness[2*N+1] = x.square();
+
+    let mut timer = PhaseTimer::default();
+    ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_hooks(&mut timer)).unwrap();
+
+    let total = timer.phases.iter().map(|(_, d)| d.as_secs_f64()).sum::<f64>();
+    for (phase, duration) in timer.phases.iter()
+    {
+        println!("{}{:?} ({:.1}%)", format!("{}: ", phase.label()).yellow(), duration, 100.0 * duration.as_secs_f64() / total);
+    }
+}
@@ -353,7 +353,7 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
             g3_comm,
 
             // polynomial commitment batched opening proofs
-            proof: index.srs.get_ref().open::<EFqSponge>
+            proof: index.srs.get_ref().open::<EFqSponge, _>
             (
                 group_map,
                 polynoms,
@@ -340,7 +340,7 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
         ).collect::<Result<Vec<_>, _>>()
         // second, verify the commitment opening proofs
         {
-            Ok(mut batch) => proofs[0].0.srs.get_ref().verify::<EFqSponge>(group_map, &mut batch, rng),
+            Ok(mut batch) => proofs[0].0.srs.get_ref().verify::<EFqSponge, _>(group_map, &mut batch, rng),
             Err(_) => false
         }
     }
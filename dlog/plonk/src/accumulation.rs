@@ -0,0 +1,102 @@
+/*****************************************************************************************************************
+
+This source file implements the aggregation of the opening proofs of several zk-proofs into a single opening.
+
+The zk-proofs are verified succinctly, the sg components of their opening proofs being taken as given, and the
+sg components along with their opening challenges are accumulated into the single opening proof of the combined
+challenge polynomial, see commitment_dlog::accumulator. The verifier of the aggregated proofs then takes the
+single multi-scalar multiplication of the SRS size for all of the zk-proofs, instead of the per proof SRS size
+scalar computations of the batched verification. The zk-proofs have to be committed against the same SRS and
+opened in a single opening proof.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof}};
+use oracle::{FqSponge, rndoracle::ProofError};
+use groupmap::GroupMap;
+use rand_core::RngCore;
+use crate::{prover::ProverProof, index::VerifierIndex as Index, plonk_sponge::FrSponge};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function verifies the zk-proofs succinctly and accumulates their opening proofs
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: Plonk proofs along with their indexes and Lagrange base commitments of the public input
+    //     RETURN: shared SRS of the proofs and the opening challenges and sg components of the proofs
+    fn accumulators
+        <'a,
+         EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &'a Vec<(&'a Index<'a, G>, &'a Vec<PolyComm<G>>, &'a ProverProof<G>)>,
+        rng: &mut dyn RngCore,
+    ) -> Result<(&'a SRS<G>, Vec<(Vec<Fr<G>>, G)>), ProofError>
+    {
+        let srs = proofs.get(0).ok_or(ProofError::ProofStructure)?.0.srs.get_ref();
+        if proofs.iter().any(|(index, _, proof)| proof.index_proof.is_some() || index.srs.get_ref().g != srs.g)
+        {
+            return Err(ProofError::ProofStructure)
+        }
+
+        let parts = proofs.iter().map
+        (
+            |(index, lgr_comm, proof)| proof.opening_parts::<EFqSponge, EFrSponge>(index, lgr_comm)
+        ).collect::<Result<Vec<_>, _>>()?;
+        let chals = srs.verify_succinct::<EFqSponge, _>(group_map, &mut parts.iter().map(|p| p.batch()).collect(), rng)
+            .ok_or(ProofError::OpenProof)?;
+        Ok((srs, chals.into_iter().zip(proofs.iter()).map(|(chal, (_, _, proof))| (chal, proof.proof.sg)).collect()))
+    }
+
+    // This function aggregates the opening proofs of the zk-proofs into a single opening proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     proofs: Plonk proofs along with their indexes and Lagrange base commitments of the public input
+    //     RETURN: aggregated opening proof, the opening proof error unless the SRS size is a power of two
+    pub fn aggregate
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+        rng: &mut dyn RngCore,
+    ) -> Result<OpeningProof<G>, ProofError>
+    {
+        let (srs, accumulators) = Self::accumulators::<EFqSponge, EFrSponge, _>(group_map, proofs, &mut *rng)?;
+        let sponge = EFqSponge::new(proofs[0].0.fq_sponge_params.clone());
+        OpeningProof::aggregate(srs, group_map, &accumulators, sponge, rng).ok_or(ProofError::OpenProof)
+    }
+
+    // This function verifies the zk-proofs against their aggregated opening proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: Plonk proofs along with their indexes and Lagrange base commitments of the public input
+    //     aggregated: aggregated opening proof of the proofs
+    //     RETURN: verification status
+    pub fn verify_aggregated
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+        aggregated: &OpeningProof<G>,
+    ) -> Result<bool, ProofError>
+    {
+        let rng = &mut rand::thread_rng();
+        let (srs, accumulators) = Self::accumulators::<EFqSponge, EFrSponge, _>(group_map, proofs, &mut *rng)?;
+        let sponge = EFqSponge::new(proofs[0].0.fq_sponge_params.clone());
+        match srs.verify_aggregated(group_map, &accumulators, aggregated, sponge, rng)
+        {
+            false => Err(ProofError::OpenProof),
+            true => Ok(true)
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/*****************************************************************************************************************
+
+This source file implements the aggregated verification key of a fixed family of Plonk circuits.
+
+The aggregated key holds the verifier indexes of the circuits over the single SRS the family shares, along with
+the Lagrange base commitments of the public inputs, which are computed once per domain size and shared by the
+circuits of the same domain. The first public input of each circuit is its selector, the position of the circuit
+in the family, which the circuit has to constrain to the constant of its position, so that the proof of one
+circuit can not be verified against the sub-key of another one. The verifier reads the selector from the proof
+public input and verifies the proof against the corresponding sub-key.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField, Zero, One};
+use ff_fft::{EvaluationDomain, Evaluations, Radix2EvaluationDomain as D};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentField, CommitmentCurve, PolyComm}};
+use oracle::{FqSponge, rndoracle::ProofError};
+use groupmap::GroupMap;
+use crate::{prover::ProverProof, index::{Index, VerifierIndex}, plonk_sponge::FrSponge};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+pub struct AggregatedVerifierKey<'a, G: CommitmentCurve>
+{
+    pub srs: &'a SRS<G>,                            // SRS shared by the circuits
+    pub keys: Vec<VerifierIndex<'a, G>>,            // verifier indexes of the circuits in the selector order
+    pub public: Vec<usize>,                         // numbers of the public inputs of the circuits
+    pub lgr_comms: Vec<(usize, Vec<PolyComm<G>>)>,  // public input Lagrange base commitments by the domain size
+}
+
+impl<'a, G: CommitmentCurve> AggregatedVerifierKey<'a, G> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function aggregates the verifier indexes of the circuits
+    //     srs: SRS shared by the circuits
+    //     indices: circuit indexes in the selector order, each of at least the selector public input
+    //         and created over the shared SRS
+    //     RETURN: aggregated verification key, the public input schema error for an empty family or
+    //         a circuit without the selector, the SRS mismatch error for an index over another SRS
+    pub fn from_indices(srs: &'a SRS<G>, indices: &[&'a Index<'a, G>]) -> Result<Self, ProofError>
+    {
+        if indices.is_empty() || indices.iter().any(|index| index.cs.public == 0) {return Err(ProofError::PublicInputSchema)}
+        if indices.iter().any(|index| !std::ptr::eq(index.srs.get_ref(), srs)) {return Err(ProofError::SrsMismatch)}
+
+        // the Lagrange base commitments are shared by the circuits of the same domain
+        let mut lgr_comms: Vec<(usize, Vec<PolyComm<G>>)> = Vec::new();
+        for index in indices.iter()
+        {
+            let domain = index.cs.domain.d1;
+            let public = indices.iter().filter(|other| other.cs.domain.d1.size == domain.size).map(|other| other.cs.public).max().unwrap_or(0);
+            if lgr_comms.iter().any(|(n, _)| *n == domain.size()) {continue}
+            lgr_comms.push((domain.size(), Self::lagrange_comms(srs, domain, public)));
+        }
+
+        Ok(AggregatedVerifierKey
+        {
+            srs,
+            keys: indices.iter().map(|index| index.verifier_index()).collect(),
+            public: indices.iter().map(|index| index.cs.public).collect(),
+            lgr_comms,
+        })
+    }
+
+    // This function commits to the first Lagrange base polynomials of the domain, over the Lagrange
+    // base of the SRS if the domain fits a single SRS chunk and segment by segment otherwise
+    fn lagrange_comms(srs: &SRS<G>, domain: D<Fr<G>>, public: usize) -> Vec<PolyComm<G>>
+    {
+        if domain.size() <= srs.g.len()
+        {
+            srs.lagrange_basis(domain)[0..public].iter().map(|l| PolyComm {unshifted: vec![*l], shifted: None}).collect()
+        }
+        else
+        {
+            (0..public).map
+            (
+                |i|
+                {
+                    let mut v = vec![Fr::<G>::zero(); i+1];
+                    v[i] = Fr::<G>::one();
+                    srs.commit_non_hiding(&Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(v, domain).interpolate(), None)
+                }
+            ).collect()
+        }
+    }
+
+    // This function returns the position of the circuit the proof targets as per its selector
+    pub fn selector(&self, proof: &ProverProof<G>) -> Result<usize, ProofError>
+    {
+        let selector = proof.public.get(0).ok_or(ProofError::ProofStructure)?.into_repr();
+        let selector = selector.as_ref();
+        if selector[1..].iter().any(|l| *l != 0) || selector[0] as usize >= self.keys.len() {return Err(ProofError::ProofStructure)}
+        Ok(selector[0] as usize)
+    }
+
+    // This function verifies the zk-proof against the sub-key of the circuit its selector chooses
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proof: Plonk proof of one of the aggregated circuits
+    //     RETURN: verification status
+    pub fn verify
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        proof: &ProverProof<G>,
+    ) -> Result<bool, ProofError>
+    {
+        let i = self.selector(proof)?;
+        if proof.public.len() != self.public[i] {return Err(ProofError::ProofStructure)}
+        let n = self.keys[i].domain.size();
+        let lgr_comms = &self.lgr_comms.iter().find(|(size, _)| *size == n).ok_or(ProofError::ProofStructure)?.1;
+        ProverProof::verify::<EFqSponge, EFrSponge, _>(group_map, &vec![(&self.keys[i], lgr_comms, proof)])
+    }
+}
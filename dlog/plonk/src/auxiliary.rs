@@ -0,0 +1,66 @@
+/*****************************************************************************************************************
+
+This source file implements auxiliary witness polynomials of custom constraints.
+
+An auxiliary polynomial is computed from the witness as its evaluations over the domain, blinded in its last
+two evaluations the same way as the permutation polynomial, committed and opened along with the wire
+polynomials at zeta and zeta*w. Its constraint is expressed over the polynomial values at a point x, the
+prover evaluates it over domain d8 into the quotient polynomial and the verifier evaluates it at zeta.
+The constraint has to vanish over the domain, the value of the zero-knowledge polynomial at x is
+supplied for disabling it over the blinded rows. Its degree has to fit the quotient polynomial bound.
+
+*****************************************************************************************************************/
+
+use std::sync::Arc;
+
+// polynomial values at a point x the auxiliary constraint is expressed over
+#[derive(Clone, Copy, Debug)]
+pub struct AuxiliaryPoint<F>
+{
+    pub l: F,       // left wire polynomial value at x
+    pub r: F,       // right wire polynomial value at x
+    pub o: F,       // output wire polynomial value at x
+    pub aux: F,     // auxiliary polynomial value at x
+    pub aux_w: F,   // auxiliary polynomial value at w*x
+    pub zkp: F,     // zero-knowledge polynomial value at x
+}
+
+#[derive(Clone)]
+pub struct Auxiliary<F>
+{
+    // evaluations of the auxiliary polynomial over the domain computed from the witness
+    pub witness: Arc<dyn Fn(&Vec<F>) -> Vec<F> + Send + Sync>,
+
+    // constraint of the auxiliary polynomial
+    pub constraint: Arc<dyn Fn(&AuxiliaryPoint<F>) -> F + Send + Sync>,
+
+    // declared degree of the constraint in the polynomials of the point, if any
+    pub degree: Option<usize>,
+}
+
+impl<F> Auxiliary<F>
+{
+    // This function creates the auxiliary witness polynomial
+    //     witness: computes the evaluations of the polynomial over the domain from the computation witness
+    //     constraint: computes the constraint value at a point
+    pub fn create
+    (
+        witness: impl Fn(&Vec<F>) -> Vec<F> + Send + Sync + 'static,
+        constraint: impl Fn(&AuxiliaryPoint<F>) -> F + Send + Sync + 'static,
+    ) -> Self
+    {
+        Auxiliary
+        {
+            witness: Arc::new(witness),
+            constraint: Arc::new(constraint),
+            degree: None,
+        }
+    }
+
+    // This function declares the degree of the auxiliary constraint for its validation against the quotient bound
+    pub fn with_degree(mut self, degree: usize) -> Self
+    {
+        self.degree = Some(degree);
+        self
+    }
+}
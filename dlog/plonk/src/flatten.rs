@@ -0,0 +1,87 @@
+/*****************************************************************************************************************
+
+This source file implements the flattening of the Plonk proof into the base field elements for in-circuit
+verification.
+
+The proof is flattened in the following order:
+
+    commitments: l, r, o, z, t unshifted chunks and t shifted part, auxiliary polynomial chunks,
+        lookup multiplicities and running sum chunks
+    evaluations: at zeta, zeta*w and the rest of the rotated points, l, r, o, z, t, f, sigma1, sigma2 chunks,
+        auxiliary polynomial chunks, lookup multiplicities, running sum, selector and table column chunks
+    opening proof: L and R of the rounds, delta, z1, z2, sg
+    index opening proof when present, in the format of the opening proof
+    public inputs
+    previous challenges: challenges and commitment chunks of each
+
+A curve point is flattened into its x and y coordinates, the point at infinity being (0, 0).
+A scalar field element is flattened into its canonical representation split into 128 bit little-endian limbs.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField, Zero};
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof};
+use crate::prover::ProverProof;
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function flattens the proof into the vector of base field elements
+    //     RETURN: flattened proof
+    pub fn to_field_vec(&self) -> Vec<Fq<G>>
+    {
+        let mut v = Vec::new();
+
+        let point = |v: &mut Vec<Fq<G>>, g: &G|
+        {
+            let (x, y) = g.to_coordinates().unwrap_or((Fq::<G>::zero(), Fq::<G>::zero()));
+            v.push(x);
+            v.push(y);
+        };
+        let scalar = |v: &mut Vec<Fq<G>>, x: &Fr<G>|
+        {
+            let repr = x.into_repr();
+            repr.as_ref().chunks(2).for_each(|c| v.push(Fq::<G>::from(c[0] as u128 + ((*c.get(1).unwrap_or(&0) as u128) << 64))));
+        };
+        let comm = |v: &mut Vec<Fq<G>>, c: &PolyComm<G>|
+        {
+            c.unshifted.iter().for_each(|g| point(v, g));
+            c.shifted.iter().for_each(|g| point(v, g));
+        };
+        let opening = |v: &mut Vec<Fq<G>>, p: &OpeningProof<G>|
+        {
+            p.lr.iter().for_each(|(l, r)| {point(v, l); point(v, r)});
+            point(v, &p.delta);
+            scalar(v, &p.z1);
+            scalar(v, &p.z2);
+            point(v, &p.sg);
+        };
+
+        // commitments
+        [&self.commitments.l_comm, &self.commitments.r_comm, &self.commitments.o_comm, &self.commitments.z_comm, &self.commitments.t_comm].
+            iter().cloned().chain(self.commitments.aux_comm.iter()).chain(self.commitments.lookup_comm.iter()).for_each(|c| comm(&mut v, c));
+
+        // evaluations
+        for ((e, aux), lookup) in self.evals.iter().zip(self.aux_evals.iter()).zip(self.lookup_evals.iter())
+        {
+            [&e.l, &e.r, &e.o, &e.z, &e.t, &e.f, &e.sigma1, &e.sigma2].iter().cloned().chain(aux.iter()).chain(lookup.iter()).
+                for_each(|e| e.iter().for_each(|x| scalar(&mut v, x)));
+        }
+
+        // opening proofs
+        opening(&mut v, &self.proof);
+        self.index_proof.iter().for_each(|p| opening(&mut v, p));
+
+        // public inputs and previous challenges
+        self.public.iter().for_each(|x| scalar(&mut v, x));
+        for (chals, c) in self.prev_challenges.iter()
+        {
+            chals.iter().for_each(|x| scalar(&mut v, x));
+            comm(&mut v, c);
+        }
+
+        v
+    }
+}
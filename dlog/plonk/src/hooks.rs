@@ -0,0 +1,69 @@
+/********************************************************************************************
+
+This source file implements the instrumentation hooks of the prover phases.
+
+The prover reports the boundaries of its phases to the hooks, the phases following each other
+in the declaration order. Without the hooks the prover only checks their absence at the phase
+boundaries.
+
+*********************************************************************************************/
+
+use std::time::{Duration, Instant};
+
+// phases of the proof creation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProverPhase
+{
+    Wires,          // wire polynomials interpolation and commitment
+    Permutation,    // permutation and lookup running sum polynomials computation and commitment
+    Quotient,       // quotient polynomial computation and commitment
+    Evaluations,    // polynomial evaluations and the linearization
+    Opening,        // opening proof
+}
+
+impl ProverPhase
+{
+    // This function returns the label of the phase
+    pub fn label(&self) -> &'static str
+    {
+        match self
+        {
+            ProverPhase::Wires => "wires",
+            ProverPhase::Permutation => "permutation",
+            ProverPhase::Quotient => "quotient",
+            ProverPhase::Evaluations => "evaluations",
+            ProverPhase::Opening => "opening",
+        }
+    }
+}
+
+pub trait ProverHooks
+{
+    // This function is called at the start of the phase
+    fn begin(&mut self, _phase: ProverPhase) {}
+
+    // This function is called at the end of the phase, not being called for the phase the proof
+    // creation fails in
+    fn end(&mut self, _phase: ProverPhase) {}
+}
+
+// hooks recording the durations of the phases
+#[derive(Clone, Debug, Default)]
+pub struct PhaseTimer
+{
+    pub phases: Vec<(ProverPhase, Duration)>,   // durations of the completed phases
+    start: Option<Instant>,
+}
+
+impl ProverHooks for PhaseTimer
+{
+    fn begin(&mut self, _phase: ProverPhase)
+    {
+        self.start = Some(Instant::now());
+    }
+
+    fn end(&mut self, phase: ProverPhase)
+    {
+        if let Some(start) = self.start.take() {self.phases.push((phase, start.elapsed()))}
+    }
+}
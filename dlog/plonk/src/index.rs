@@ -5,12 +5,15 @@ This source file implements Plonk Protocol Index primitive.
 *****************************************************************************************************************/
 
 use ff_fft::{DensePolynomial, Radix2EvaluationDomain as D};
-use commitment_dlog::{srs::SRS, CommitmentField, commitment::{CommitmentCurve, PolyComm}};
+use commitment_dlog::{srs::SRS, stream::SRSStream, CommitmentField, commitment::{CommitmentCurve, PolyComm, ceil_log2}};
+use std::io::{Read, Seek, Result as IoResult};
 use oracle::poseidon::{ArithmeticSpongeParams, SpongeConstants, PlonkSpongeConstants};
-use plonk_circuits::constraints::{zk_w, ConstraintSystem};
+use plonk_circuits::{constraints::{zk_w, ConstraintSystem, UnsatisfiedConstraint}, gate::CircuitGate, wires::COLUMNS, domains::EvaluationDomains};
 use array_init::array_init;
-use algebra::AffineCurve;
+use algebra::{AffineCurve, ProjectiveCurve, FftField, Field, Zero, One};
 use algebra::PrimeField;
+use crate::auxiliary::Auxiliary;
+use oracle::{FqSponge, rndoracle::ProofError};
 
 type Fr<G> = <G as AffineCurve>::ScalarField;
 type Fq<G> = <G as AffineCurve>::BaseField;
@@ -47,6 +50,169 @@ impl<'a, G: CommitmentCurve> SRSValue<'a, G> where G::BaseField : PrimeField, G:
     }
 }
 
+// grouping of the committed polynomials into the batched opening proofs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OpeningGroups
+{
+    Single,     // all the polynomials are opened in a single batched opening proof
+    Split,      // witness and index polynomials are opened in separate batched opening proofs
+}
+
+// absorption of the public input into the transcript
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PublicAbsorb
+{
+    Batched,    // the public input polynomial commitment is absorbed in a single sponge call
+    PerElement, // the public input elements are absorbed one per sponge call
+}
+
+// derivation of the opening scaler challenges v, u from the Fr sponge relative to the evaluations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OpeningOrder
+{
+    AfterEvaluations,   // v, u are queried after the evaluations are absorbed
+    DigestedEvaluations,// v, u are queried after the digest of the evaluations, absorbed into a fresh
+                        // Fr sponge, is absorbed in a single sponge call
+}
+
+// zero-knowledge blinding of the prover's polynomials
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZkConfig
+{
+    pub wire_degree: usize,     // degree of the random polynomial blinding the l, r, o wire polynomials
+    pub wire_mask: [bool; 3],   // blinding of the l, r, o wire commitments
+    pub z: bool,                // random z values over the zero-knowledge rows along with the z commitment blinding
+    pub t: bool,                // blinding of the t commitment
+}
+
+impl ZkConfig
+{
+    // This function returns the configuration of the proofs without the zero-knowledge, for the benchmarks
+    // and the verifiable computation deployments; the opening proof remains blinded
+    pub fn none() -> Self
+    {
+        ZkConfig {wire_degree: 0, wire_mask: [false; 3], z: false, t: false}
+    }
+}
+
+impl Default for ZkConfig
+{
+    fn default() -> Self
+    {
+        ZkConfig {wire_degree: 0, wire_mask: [true; 3], z: true, t: true}
+    }
+}
+
+// proof size and SRS requirements of the index
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProofProfile
+{
+    pub group_elements: usize,  // number of the commitment and opening proof group elements of the proof
+    pub field_elements: usize,  // number of the field elements of the two proof evaluations
+    pub srs_degree: usize,      // SRS size committing the quotient polynomial in a single segment
+    pub srs_sufficient: bool,   // whether the index SRS is of the srs_degree size, the quotient polynomial
+                                // being committed in several segments otherwise
+}
+
+// quotient polynomial commitment layout of the proof, the quotient evaluation being recombined from the
+// segment evaluations t_k as sum_k t_k * zeta^(k*max_poly_size)
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuotientLayout
+{
+    pub bound: usize,           // quotient polynomial size bound max_quot_size
+    pub max_poly_size: usize,   // segment size
+    pub chunks: Vec<usize>,     // sizes of the committed unshifted segments, the trailing zero ones not being committed
+    pub absorbed: usize,        // number of the segments absorbed into the transcript, padded with the dummy point
+    pub shifted: Option<usize>, // size of the last segment shifted to the right edge of the SRS, unless the bound
+                                // is a multiple of the segment size
+}
+
+// index polynomial commitments computed at the index construction, reused by the verifier indexes
+#[derive(Clone)]
+pub struct IndexCommitments<G: AffineCurve>
+{
+    pub sigma_comm: [PolyComm<G>; COLUMNS], // permutation commitment array
+    pub ql_comm:    PolyComm<G>,        // left input wire commitment
+    pub qr_comm:    PolyComm<G>,        // right input wire commitment
+    pub qo_comm:    PolyComm<G>,        // output wire commitment
+    pub qm_comm:    PolyComm<G>,        // multiplication commitment
+    pub qc_comm:    PolyComm<G>,        // constant wire commitment
+    pub rcm_comm:   [PolyComm<G>; 3],   // round constant polynomial commitment array
+    pub psm_comm:   PolyComm<G>,        // poseidon constraint selector polynomial commitment
+    pub add_comm:   PolyComm<G>,        // EC addition selector polynomial commitment
+    pub mul1_comm:  PolyComm<G>,        // EC variable base scalar multiplication selector polynomial commitment
+    pub mul2_comm:  PolyComm<G>,        // EC variable base scalar multiplication selector polynomial commitment
+    pub emul1_comm: PolyComm<G>,        // endoscalar multiplication selector polynomial commitment
+    pub emul2_comm: PolyComm<G>,        // endoscalar multiplication selector polynomial commitment
+    pub emul3_comm: PolyComm<G>,        // endoscalar multiplication selector polynomial commitment
+    pub lookup_comm: Option<Vec<PolyComm<G>>>, // lookup argument selector and table column polynomial commitments
+}
+
+impl<G: CommitmentCurve> IndexCommitments<G> where G::ScalarField : CommitmentField
+{
+    // This function commits to the index polynomials of the constraint system
+    pub fn create(cs: &ConstraintSystem<Fr<G>>, srs: &SRS<G>) -> Self
+    {
+        IndexCommitments
+        {
+            sigma_comm: array_init(|i| srs.commit_non_hiding(&cs.sigmam[i], None)),
+            ql_comm: srs.commit_non_hiding(&cs.qlm, None),
+            qr_comm: srs.commit_non_hiding(&cs.qrm, None),
+            qo_comm: srs.commit_non_hiding(&cs.qom, None),
+            qm_comm: srs.commit_non_hiding(&cs.qmm, None),
+            qc_comm: srs.commit_non_hiding(&cs.qc, None),
+            rcm_comm: array_init(|i| srs.commit_non_hiding(&cs.rcm[i], None)),
+            psm_comm: srs.commit_non_hiding(&cs.psm, None),
+            add_comm: srs.commit_non_hiding(&cs.addm, None),
+            mul1_comm: srs.commit_non_hiding(&cs.mul1m, None),
+            mul2_comm: srs.commit_non_hiding(&cs.mul2m, None),
+            emul1_comm: srs.commit_non_hiding(&cs.emul1m, None),
+            emul2_comm: srs.commit_non_hiding(&cs.emul2m, None),
+            emul3_comm: srs.commit_non_hiding(&cs.emul3m, None),
+            lookup_comm: cs.lookup.as_ref().map(|l| std::iter::once(&l.lkpm).chain(l.tablem.iter()).map(|p| srs.commit_non_hiding(p, None)).collect()),
+        }
+    }
+
+    // This function commits to the index polynomials of the constraint system against the chunk-streamed SRS,
+    // the commitments being equal to the ones of create against the loaded SRS, so that the keys of the large
+    // circuits are generated without the SRS in memory and restored by Index::create_with_commitments.
+    // The prover's commitments are not streamed: the opening proof folds the full SRS in its first round,
+    // so that the prover holds the SRS in memory anyway
+    //     cs: constraint system
+    //     srs: streamed SRS
+    //     chunk: number of the group elements held in memory at a time
+    //     RETURN: index polynomial commitments
+    pub fn create_streamed<R: Read + Seek>(cs: &ConstraintSystem<Fr<G>>, srs: &mut SRSStream<G, R>, chunk: usize) -> IoResult<Self>
+        where G::BaseField : PrimeField
+    {
+        let mut commit = |p: &DensePolynomial<Fr<G>>| srs.commit_non_hiding(p, None, chunk);
+        let mut sigma = cs.sigmam.iter().map(&mut commit).collect::<IoResult<Vec<_>>>()?.into_iter();
+        let rcm = [commit(&cs.rcm[0])?, commit(&cs.rcm[1])?, commit(&cs.rcm[2])?];
+        Ok(IndexCommitments
+        {
+            sigma_comm: array_init(|_| sigma.next().unwrap()),
+            ql_comm: commit(&cs.qlm)?,
+            qr_comm: commit(&cs.qrm)?,
+            qo_comm: commit(&cs.qom)?,
+            qm_comm: commit(&cs.qmm)?,
+            qc_comm: commit(&cs.qc)?,
+            rcm_comm: rcm,
+            psm_comm: commit(&cs.psm)?,
+            add_comm: commit(&cs.addm)?,
+            mul1_comm: commit(&cs.mul1m)?,
+            mul2_comm: commit(&cs.mul2m)?,
+            emul1_comm: commit(&cs.emul1m)?,
+            emul2_comm: commit(&cs.emul2m)?,
+            emul3_comm: commit(&cs.emul3m)?,
+            lookup_comm: match cs.lookup.as_ref()
+            {
+                None => None,
+                Some(l) => Some(std::iter::once(&l.lkpm).chain(l.tablem.iter()).map(&mut commit).collect::<IoResult<Vec<_>>>()?),
+            },
+        })
+    }
+}
+
 pub struct Index<'a, G: CommitmentCurve> where G::ScalarField : CommitmentField
 {
     // constraints system polynoms
@@ -63,6 +229,59 @@ pub struct Index<'a, G: CommitmentCurve> where G::ScalarField : CommitmentField
 
     // random oracle argument parameters
     pub fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+
+    // grouping of the polynomials into the opening proofs
+    pub opening_groups: OpeningGroups,
+
+    // absorption of the public input into the transcript
+    pub public_absorb_mode: PublicAbsorb,
+
+    // derivation order of the opening scaler challenges
+    pub opening_order: OpeningOrder,
+
+    // auxiliary witness polynomials of custom constraints
+    pub auxiliary: Vec<Auxiliary<Fr<G>>>,
+
+    // zero-knowledge blinding of the prover's polynomials, set by set_zk_config: the blinding of the
+    // l, r, o wire commitments, to be disabled for the public wires only, of the z polynomial over the
+    // zero-knowledge rows and of the z and t commitments, and the wire blinding degree. The degree b
+    // random polynomial the vanishing polynomial multiple of which blinds the wire polynomials hides
+    // b+1 evaluations, 1 being the minimum for the two evaluation points zeta and zeta*w, 0 disabling
+    // the blinding. The blinded wires raise the quotient of the degree d constraint to d*(n-1+b)-n, so
+    // that with the default max_quot_size of 5(n-1) the degree 6 Poseidon and EC gates fit the unblinded
+    // wires only, the degree 1 blinding lowering the quotient_degree_bound to 5; the prover rejects the
+    // combination by check_blinding_degree
+    pub(crate) zk: ZkConfig,
+
+    // overlapping of the l, r, o wire commitment MSMs, effective with the parallel feature
+    pub pipelined_commit: bool,
+
+    // validation of the witness against the gate constraints before the proof creation
+    pub witness_check: bool,
+
+    // validation of the witness against the permutation before the permutation polynomial computation
+    pub permutation_check: bool,
+
+    // index polynomial commitments, to be recomputed if the constraint system is modified
+    pub commitments: IndexCommitments<G>,
+
+    // rotations of the evaluation points zeta*w^rot the polynomials are opened at, the 0 and 1 ones of
+    // the constraints coming first; the wire blinding of the degree d hides d+1 of the evaluations
+    pub rotations: Vec<u64>,
+
+    // domain separator absorbed into the transcript before anything else, so that the proofs of the
+    // protocols of the distinct separators sharing the curve can not be replayed across them
+    pub domain_separator: Option<Vec<u8>>,
+
+    // fixed number of the group elements each commitment is absorbed into the transcript as, padded with
+    // the dummy point, so that the transcript of the in-circuit verifier does not depend on the circuit size;
+    // None absorbs the commitment segments as they are, the quotient ones being padded to the quotient bound
+    pub absorb_size: Option<usize>,
+
+    // scale of the permutation polynomial skipping its normalization, the boundary conditions failing
+    // unless it is one; the test harnesses produce the proofs the verifier has to reject with it
+    #[cfg(feature = "debug")]
+    pub z_scale: Option<Fr<G>>,
 }
 
 pub struct VerifierIndex<'a, G: CommitmentCurve>
@@ -73,7 +292,7 @@ pub struct VerifierIndex<'a, G: CommitmentCurve>
     pub srs: SRSValue<'a, G>,           // polynomial commitment keys
 
     // index polynomial commitments
-    pub sigma_comm: [PolyComm<G>; 3],   // permutation commitment array
+    pub sigma_comm: [PolyComm<G>; COLUMNS], // permutation commitment array
     pub ql_comm:    PolyComm<G>,        // left input wire commitment
     pub qr_comm:    PolyComm<G>,        // right input wire commitment
     pub qo_comm:    PolyComm<G>,        // output wire commitment
@@ -92,6 +311,9 @@ pub struct VerifierIndex<'a, G: CommitmentCurve>
     pub emul2_comm: PolyComm<G>,        // endoscalar multiplication selector polynomial commitment
     pub emul3_comm: PolyComm<G>,        // endoscalar multiplication selector polynomial commitment
 
+    // lookup argument selector and table column polynomial commitments
+    pub lookup_comm: Option<Vec<PolyComm<G>>>,
+
     pub r:          Fr<G>,              // coordinate shift for right wires
     pub o:          Fr<G>,              // coordinate shift for output wires
     pub zkpm:       DensePolynomial<Fr<G>>, // zero-knowledge polynomial
@@ -101,37 +323,182 @@ pub struct VerifierIndex<'a, G: CommitmentCurve>
     // random oracle argument parameters
     pub fr_sponge_params: ArithmeticSpongeParams<Fr<G>>,
     pub fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+
+    // grouping of the polynomials into the opening proofs
+    pub opening_groups: OpeningGroups,
+
+    // absorption of the public input into the transcript
+    pub public_absorb_mode: PublicAbsorb,
+
+    // derivation order of the opening scaler challenges
+    pub opening_order: OpeningOrder,
+
+    // auxiliary witness polynomials of custom constraints
+    pub auxiliary: Vec<Auxiliary<Fr<G>>>,
+
+    // degree of the l, r, o wire polynomial blinding
+    pub blinding_degree: usize,
+
+    // rotations of the evaluation points
+    pub rotations: Vec<u64>,
+
+    // transcript domain separator
+    pub domain_separator: Option<Vec<u8>>,
+
+    // fixed number of the group elements of the commitment absorption
+    pub absorb_size: Option<usize>,
+}
+
+impl<'a, G: CommitmentCurve> VerifierIndex<'a, G>
+{
+    // This function returns the coordinate shifts of the wire columns, the left wire being unshifted
+    pub fn shifts(&self) -> [Fr<G>; COLUMNS]
+    {
+        [Fr::<G>::one(), self.r, self.o]
+    }
+}
+
+// This function absorbs the commitment segments into the transcript, padded with the dummy point up to
+// the fixed number of the group elements, if any
+//     sponge: transcript sponge
+//     segments: commitment segments
+//     size: fixed number of the absorbed group elements
+pub fn absorb_segments<G, EFqSponge>(sponge: &mut EFqSponge, segments: &[G], size: Option<usize>)
+    where G: CommitmentCurve, EFqSponge: FqSponge<Fq<G>, G, Fr<G>>
+{
+    sponge.absorb_g(segments);
+    if let Some(size) = size
+    {
+        let dummy = G::of_coordinates(Fq::<G>::zero(), Fq::<G>::zero());
+        sponge.absorb_g(&vec![dummy; size.saturating_sub(segments.len())]);
+    }
+}
+
+// This function absorbs the domain separator into the transcript, its length first and then its bytes
+// packed by 31 into the scalar field elements
+//     sponge: transcript sponge
+//     separator: domain separator, nothing being absorbed without one
+pub fn absorb_separator<G: AffineCurve, EFqSponge: FqSponge<Fq<G>, G, Fr<G>>>(sponge: &mut EFqSponge, separator: &Option<Vec<u8>>)
+{
+    if let Some(separator) = separator
+    {
+        sponge.absorb_fr(&[Fr::<G>::from(separator.len() as u64)]);
+        sponge.absorb_fr(&separator.chunks(31).map(|c| Fr::<G>::from_random_bytes(c).unwrap()).collect::<Vec<_>>());
+    }
+}
+
+// This function computes the evaluation points of the rotations
+//     domain: evaluation domain
+//     rotations: rotations of the evaluation points
+//     zeta: evaluation point of the rotation 0
+//     RETURN: evaluation points zeta*w^rot, None unless the rotations start with the 0 and 1 ones
+pub fn evaluation_points<F: FftField>(domain: D<F>, rotations: &[u64], zeta: F) -> Option<Vec<F>>
+{
+    if rotations.len() < 2 || rotations[0] != 0 || rotations[1] != 1 {return None}
+    Some(rotations.iter().map(|rot| zeta * &domain.group_gen.pow(&[*rot])).collect())
+}
+
+// This function selects the smallest evaluation domains of the circuit fitting its gates followed by the
+// three zero-knowledge rows, the quotient polynomial being committed in the max_poly_size segments, and
+// validates the SRS against the domain size the public input evaluation of the verifier requires
+//     gates: number of the circuit gates, including public input ones
+//     srs: SRS the circuit is to be committed against
+//     RETURN: evaluation domains and the maximal polynomial segment size of the index
+pub fn select_domains<G: CommitmentCurve>(gates: usize, srs: &SRS<G>) -> Result<(EvaluationDomains<Fr<G>>, usize), ProofError>
+{
+    let domains = EvaluationDomains::<Fr<G>>::create(std::cmp::max(gates, 1) + 3).ok_or(ProofError::DomainCreation)?;
+    let n = domains.d1.size as usize;
+    if srs.g.len() < n {return Err(ProofError::SrsSize {required: n, found: srs.g.len()})}
+    Ok((domains, srs.g.len()))
+}
+
+// maximal number of the distinct nonzero selector values committed over the Lagrange base
+pub const QUANTIZED_SET_SIZE: usize = 3;
+
+// This function commits to the polynomial from its evaluations over the domain drawn from a small set
+// by summing the Lagrange base commitments of the rows per value and scaling the sums by the values,
+// so that the commitment costs the group additions instead of the multi-scalar multiplication
+//     basis: Lagrange base commitments of the domain
+//     evals: polynomial evaluations over the domain
+//     RETURN: polynomial commitment if the nonzero evaluations draw from the small set
+pub fn commit_quantized<G: CommitmentCurve>(basis: &[G], evals: &[Fr<G>]) -> Option<PolyComm<G>>
+{
+    let mut sums: Vec<(Fr<G>, G::Projective)> = Vec::new();
+    for (e, l) in evals.iter().zip(basis.iter()).filter(|(e, _)| !e.is_zero())
+    {
+        match sums.iter_mut().find(|(v, _)| v == e)
+        {
+            Some((_, s)) => s.add_assign_mixed(l),
+            None =>
+            {
+                if sums.len() == QUANTIZED_SET_SIZE {return None}
+                let mut s = G::Projective::zero();
+                s.add_assign_mixed(l);
+                sums.push((*e, s));
+            }
+        }
+    }
+    if sums.len() == 0 {return Some(PolyComm {unshifted: Vec::new(), shifted: None})}
+
+    let c = sums.iter().fold
+    (
+        G::Projective::zero(),
+        |c, (v, s)| if v.is_one() {c + s} else if (-*v).is_one() {c - s} else {c + &s.mul(*v)}
+    );
+    Some(PolyComm {unshifted: vec![c.into_affine()], shifted: None})
 }
 
 impl<'a, G: CommitmentCurve> Index<'a, G> where G::BaseField: PrimeField, G::ScalarField : CommitmentField
 {
     pub fn verifier_index(&self) -> VerifierIndex<G> {
+        self.verifier_index_with(None)
+    }
+
+    // This function computes the verifier index committing to the generic constraint selectors
+    // of the values drawn from a small set over the Lagrange base of the domain
+    //     basis: Lagrange base commitments of the domain, as computed by SRS::lagrange_basis
+    pub fn verifier_index_with_lagrange_basis(&self, basis: &Vec<G>) -> VerifierIndex<G> {
+        self.verifier_index_with(Some(basis))
+    }
+
+    fn verifier_index_with(&self, basis: Option<&Vec<G>>) -> VerifierIndex<G> {
         let srs = match &self.srs
         {
             SRSValue::Value(s) => SRSValue::Value(s.clone()),
             SRSValue::Ref(x) => SRSValue::Ref(x)
         };
 
+        // the selectors are committed over the Lagrange base if they fit a single chunk,
+        // the cached commitments being reused otherwise
+        let n = self.cs.domain.d1.size as usize;
+        let basis = basis.filter(|b| b.len() == n && self.max_poly_size >= n);
+        let selector = |c: &PolyComm<G>, q: fn(&CircuitGate<Fr<G>>) -> Fr<G>|
+            basis.and_then(|b| commit_quantized(b, &self.cs.gate_iter().map(|g| q(&g)).collect::<Vec<_>>()))
+                .unwrap_or_else(|| c.clone());
+        let c = &self.commitments;
+
         VerifierIndex
         {
             domain: self.cs.domain.d1,
 
-            sigma_comm: array_init(|i| srs.get_ref().commit_non_hiding(&self.cs.sigmam[i], None)),
-            ql_comm: srs.get_ref().commit_non_hiding(&self.cs.qlm, None),
-            qr_comm: srs.get_ref().commit_non_hiding(&self.cs.qrm, None),
-            qo_comm: srs.get_ref().commit_non_hiding(&self.cs.qom, None),
-            qm_comm: srs.get_ref().commit_non_hiding(&self.cs.qmm, None),
-            qc_comm: srs.get_ref().commit_non_hiding(&self.cs.qc, None),
+            sigma_comm: c.sigma_comm.clone(),
+            ql_comm: selector(&c.ql_comm, CircuitGate::ql),
+            qr_comm: selector(&c.qr_comm, CircuitGate::qr),
+            qo_comm: selector(&c.qo_comm, CircuitGate::qo),
+            qm_comm: selector(&c.qm_comm, CircuitGate::qm),
+            qc_comm: selector(&c.qc_comm, CircuitGate::qc),
+
+            rcm_comm: c.rcm_comm.clone(),
+            psm_comm: c.psm_comm.clone(),
 
-            rcm_comm: array_init(|i| srs.get_ref().commit_non_hiding(&self.cs.rcm[i], None)),
-            psm_comm: srs.get_ref().commit_non_hiding(&self.cs.psm, None),
+            add_comm: c.add_comm.clone(),
+            mul1_comm: c.mul1_comm.clone(),
+            mul2_comm: c.mul2_comm.clone(),
+            emul1_comm: c.emul1_comm.clone(),
+            emul2_comm: c.emul2_comm.clone(),
+            emul3_comm: c.emul3_comm.clone(),
 
-            add_comm: srs.get_ref().commit_non_hiding(&self.cs.addm, None),
-            mul1_comm: srs.get_ref().commit_non_hiding(&self.cs.mul1m, None),
-            mul2_comm: srs.get_ref().commit_non_hiding(&self.cs.mul2m, None),
-            emul1_comm: srs.get_ref().commit_non_hiding(&self.cs.emul1m, None),
-            emul2_comm: srs.get_ref().commit_non_hiding(&self.cs.emul2m, None),
-            emul3_comm: srs.get_ref().commit_non_hiding(&self.cs.emul3m, None),
+            lookup_comm: c.lookup_comm.clone(),
 
             w: zk_w(self.cs.domain.d1),
             fr_sponge_params: self.cs.fr_sponge_params.clone(),
@@ -139,6 +506,14 @@ impl<'a, G: CommitmentCurve> Index<'a, G> where G::BaseField: PrimeField, G::Sca
             endo: self.cs.endo,
             max_poly_size: self.max_poly_size,
             max_quot_size: self.max_quot_size,
+            opening_groups: self.opening_groups,
+            public_absorb_mode: self.public_absorb_mode,
+            opening_order: self.opening_order,
+            auxiliary: self.auxiliary.clone(),
+            blinding_degree: self.zk.wire_degree,
+            rotations: self.rotations.clone(),
+            domain_separator: self.domain_separator.clone(),
+            absorb_size: self.absorb_size,
             zkpm: self.cs.zkpm.clone(),
             srs,
             r: self.cs.r,
@@ -146,14 +521,204 @@ impl<'a, G: CommitmentCurve> Index<'a, G> where G::BaseField: PrimeField, G::Sca
         }
     }
 
+    // This function returns the zero-knowledge blinding configuration of the prover
+    pub fn zk_config(&self) -> ZkConfig
+    {
+        self.zk
+    }
+
+    // This function sets the zero-knowledge blinding configuration of the prover
+    //     config: blinding of the wire, z and t polynomials, ZkConfig::none() disabling the zero-knowledge
+    pub fn set_zk_config(&mut self, config: ZkConfig)
+    {
+        self.zk = config;
+    }
+
+    // This function computes the highest constraint degree the quotient polynomial bound accommodates,
+    // the quotient of the degree d constraint being of degree d*(n-1+b)-n for the wires blinded with
+    // the degree b polynomial
+    pub fn quotient_degree_bound(&self) -> usize
+    {
+        let n = self.cs.domain.d1.size as usize;
+        (self.max_quot_size + n - 1) / (n - 1 + self.zk.wire_degree)
+    }
+
+    // This function computes the size of the proof and the SRS requirements of the index
+    //     RETURN: proof profile, the sizes being the upper bounds for the proofs with the wires blinded by the prover
+    pub fn proof_profile(&self) -> ProofProfile
+    {
+        let n = self.cs.domain.d1.size as usize;
+        let chunks = |size: usize| (size + self.max_poly_size - 1) / self.max_poly_size;
+        let w = chunks(if self.zk.wire_degree > 0 {n + 1 + self.zk.wire_degree} else {n});
+        let z = chunks(n);
+        let t = chunks(self.max_quot_size);
+        let lookup = if self.cs.lookup.is_some() {2} else {0};
+
+        // l, r, o, z, t commitments along with the auxiliary and lookup ones
+        let commitments = 3*w + z + t + if self.max_quot_size % self.max_poly_size != 0 {1} else {0} +
+            (self.auxiliary.len() + lookup) * z;
+        // L, R rounds, delta and sg of the opening proofs
+        let openings = if self.opening_groups == OpeningGroups::Split {2} else {1};
+        let opening = 2 * ceil_log2(self.srs.get_ref().g.len()) + 2;
+
+        let srs_degree = self.max_poly_size.max(self.max_quot_size);
+        ProofProfile
+        {
+            group_elements: commitments + openings * opening,
+            field_elements: 2 * (3*w + 4*z + t),
+            srs_degree,
+            srs_sufficient: self.srs.get_ref().g.len() >= srs_degree,
+        }
+    }
+
+    // This function computes the highest degree of the circuit gate and declared auxiliary constraints
+    pub fn max_gate_degree(&self) -> usize
+    {
+        self.auxiliary.iter().filter_map(|a| a.degree).fold(self.cs.max_gate_degree(), |m, d| m.max(d))
+    }
+
+    // This function validates the constraint degrees against the quotient polynomial bound
+    pub fn check_gate_degree(&self) -> Result<(), ProofError>
+    {
+        if self.max_gate_degree() > self.quotient_degree_bound() {Err(ProofError::GateDegree)} else {Ok(())}
+    }
+
+    // This function validates the wire blinding degree, the blinding polynomial having to fit a single
+    // polynomial segment and the blinded constraints the quotient polynomial bound
+    //     RETURN: wire blinding error for the blinding exceeding the segment, blinding degree error for
+    //         the gate degree fitting the unblinded quotient bound only, gate degree error otherwise
+    pub fn check_blinding_degree(&self) -> Result<(), ProofError>
+    {
+        if self.zk.wire_degree >= self.max_poly_size {return Err(ProofError::WitnessBlinding)}
+        let n = self.cs.domain.d1.size as usize;
+        let (gate_degree, bound) = (self.max_gate_degree(), self.quotient_degree_bound());
+        if gate_degree <= bound {Ok(())}
+        else if gate_degree <= (self.max_quot_size + n - 1) / (n - 1)
+        {
+            Err(ProofError::BlindingDegree {degree: self.zk.wire_degree, gate_degree, bound})
+        }
+        else {Err(ProofError::GateDegree)}
+    }
+
+    // This function validates the rotations of the evaluation points, the 0 and 1 ones of the constraints
+    // having to come first
+    pub fn check_rotations(&self) -> Result<(), ProofError>
+    {
+        if self.rotations.len() < 2 || self.rotations[0] != 0 || self.rotations[1] != 1 {Err(ProofError::EvaluationRotations)} else {Ok(())}
+    }
+
+    // This function validates the fixed-size commitment absorption against the segments of the commitments,
+    // the quotient and the blinded wire ones being the longest
+    pub fn check_absorb_size(&self) -> Result<(), ProofError>
+    {
+        let n = self.cs.domain.d1.size as usize;
+        let chunks = |size: usize| (size + self.max_poly_size - 1) / self.max_poly_size;
+        match self.absorb_size
+        {
+            Some(k) if k < chunks(self.max_quot_size.max(n + 1 + self.zk.wire_degree.max(1))) => Err(ProofError::ProofStructure),
+            _ => Ok(()),
+        }
+    }
+
+    // This function validates the witness against the gate constraints, the public input
+    // contributing to the generic constraints of the public input rows
+    //     witness: wire values in the column layout
+    //     RETURN: first unsatisfied gate error
+    pub fn check_witness(&self, witness: &Vec<Fr<G>>) -> Result<(), ProofError>
+    {
+        self.cs.verify_gates(witness).map_err(Self::unsatisfied)
+    }
+
+    // This function validates the witness against the copy constraints of the permutation, each wire
+    // holding the value of the next wire of its permutation cycle, directly over the wire values
+    //     witness: wire values in the column layout
+    //     RETURN: first inconsistent wire pair error
+    pub fn check_permutation(&self, witness: &Vec<Fr<G>>) -> Result<(), ProofError>
+    {
+        self.cs.verify_permutation(witness).map_err(Self::unsatisfied)
+    }
+
+    // This function maps the unsatisfied constraint of the witness to the proof error
+    fn unsatisfied(error: UnsatisfiedConstraint<Fr<G>>) -> ProofError
+    {
+        match error
+        {
+            UnsatisfiedConstraint::WitnessLength {expected, found} => ProofError::WitnessLength {expected, found},
+            UnsatisfiedConstraint::Gate {row, ..} => ProofError::GateUnsatisfied(row),
+            UnsatisfiedConstraint::CopyConstraint {wire, next, ..} => ProofError::CopyConstraint {wire, next},
+            UnsatisfiedConstraint::Lookup => ProofError::ProofStructure,
+        }
+    }
+
+    // This function compiles the index from constraints and auxiliary witness polynomials
+    // validating the constraint degrees against the quotient polynomial bound
+    pub fn create_with_auxiliary
+    (
+        cs: ConstraintSystem<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs : SRSSpec<'a, G>,
+        auxiliary: Vec<Auxiliary<Fr<G>>>,
+    ) -> Result<Self, ProofError>
+    {
+        let mut index = Self::create(cs, fq_sponge_params, endo_q, srs);
+        index.auxiliary = auxiliary;
+        index.check_gate_degree()?;
+        Ok(index)
+    }
+
+    // This function compiles the index from constraints opening the polynomials at the rotations
+    // of the evaluation points, validating the rotations
+    pub fn create_with_rotations
+    (
+        cs: ConstraintSystem<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs : SRSSpec<'a, G>,
+        rotations: Vec<u64>,
+    ) -> Result<Self, ProofError>
+    {
+        let mut index = Self::create(cs, fq_sponge_params, endo_q, srs);
+        index.rotations = rotations;
+        index.check_rotations()?;
+        Ok(index)
+    }
+
+    // This function compiles the index from constraints and the index polynomial commitments computed
+    // against the SRS beforehand, so that no commitments are computed. The commitments are taken as
+    // given, they have to be those of the constraint system against the SRS
+    pub fn create_with_commitments
+    (
+        cs: ConstraintSystem<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs : SRSSpec<'a, G>,
+        commitments: IndexCommitments<G>,
+    ) -> Self
+    {
+        Self::compile(cs, fq_sponge_params, endo_q, srs, Some(commitments))
+    }
+
     // this function compiles the index from constraints
     pub fn create
     (
-        mut cs: ConstraintSystem<Fr<G>>,
+        cs: ConstraintSystem<Fr<G>>,
         fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
         endo_q: Fr<G>,
         srs : SRSSpec<'a, G>
     ) -> Self
+    {
+        Self::compile(cs, fq_sponge_params, endo_q, srs, None)
+    }
+
+    fn compile
+    (
+        mut cs: ConstraintSystem<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs : SRSSpec<'a, G>,
+        commitments: Option<IndexCommitments<G>>,
+    ) -> Self
     {
         let srs = SRSValue::create(srs);
         let max_poly_size = srs.get_ref().g.len();
@@ -166,6 +731,21 @@ impl<'a, G: CommitmentCurve> Index<'a, G> where G::BaseField: PrimeField, G::Sca
         {
             max_quot_size: PlonkSpongeConstants::SPONGE_BOX * (cs.domain.d1.size as usize - 1),
             fq_sponge_params,
+            opening_groups: OpeningGroups::Single,
+            public_absorb_mode: PublicAbsorb::Batched,
+            opening_order: OpeningOrder::AfterEvaluations,
+            auxiliary: Vec::new(),
+            // the unblinded wires keep the quotient bound of the degree 6 gates, see zk
+            zk: ZkConfig::default(),
+            pipelined_commit: true,
+            witness_check: false,
+            permutation_check: false,
+            commitments: commitments.unwrap_or_else(|| IndexCommitments::create(&cs, srs.get_ref())),
+            rotations: vec![0, 1],
+            domain_separator: None,
+            absorb_size: None,
+            #[cfg(feature = "debug")]
+            z_scale: None,
             max_poly_size,
             srs,
             cs,
@@ -0,0 +1,272 @@
+/*****************************************************************************************************************
+
+This source file implements the compatibility verifier of the proofs produced by the earlier prover versions.
+
+The proof format versions 1 to 3 all carry the same protocol: the wire, permutation and quotient polynomial
+commitments opened at the two evaluation points zeta and zeta*w, the quotient committed in the max_quot_size
+bounded chunks, the opening scaler challenges queried after the evaluations and neither the auxiliary nor the
+lookup argument. LegacyVerifier carries its own copy of the transcript and the verification algorithm of these
+versions, which the changes of the latest protocol do not reach, so that the already issued proofs keep
+verifying. The verifier is selected by the version tag the proof was serialized with.
+
+*****************************************************************************************************************/
+
+use algebra::{Field, AffineCurve, PrimeField, Zero};
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm};
+use oracle::{FqSponge, rndoracle::ProofError, sponge::ScalarChallenge};
+use plonk_circuits::{scalars::{RandomOracles, ProofEvaluations}, constraints::ConstraintSystem, domains::VanishingPolyEval, wires::COLUMNS};
+use ff_fft::EvaluationDomain;
+use groupmap::GroupMap;
+use rand::thread_rng;
+use crate::{prover::ProverProof, index::{VerifierIndex, OpeningGroups, PublicAbsorb, absorb_separator, absorb_segments}};
+use crate::{plonk_sponge::FrSponge, stream::PROOF_VERSION, range};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+// latest proof format version of the two evaluation point protocol without lookups
+pub const LEGACY_PROOF_VERSION: u32 = 3;
+
+// number of the alpha powers of the legacy constraints
+const LEGACY_ALPHA: usize = 17;
+
+// verifier of the proofs of the format versions up to LEGACY_PROOF_VERSION
+pub struct LegacyVerifier;
+
+impl LegacyVerifier
+{
+    // This function checks whether the proofs of the format version are verified by the legacy verifier
+    pub fn supports(version: u32) -> bool
+    {
+        version >= 1 && version <= LEGACY_PROOF_VERSION
+    }
+
+    // This function verifies the batch of the legacy zk-proofs with the verification algorithm of the
+    // legacy versions, independently of the one of the latest version
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: vector of Plonk proofs with their verifier indexes and public input Lagrange base commitments
+    //     RETURN: verification status
+    pub fn verify
+        <G: CommitmentCurve,
+         EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &Vec<(&VerifierIndex<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+    ) -> Result<bool, ProofError>
+    where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+    {
+        let rng = &mut thread_rng();
+        for (index, lgr_comm, proof) in proofs.iter()
+        {
+            Self::check_structure(index, proof)?;
+            let p_comm = ProverProof::public_comm(lgr_comm, &proof.public);
+            let (fq_sponge, oracles, p_eval, evlp, polys) = Self::oracles::<G, EFqSponge, EFrSponge>(index, &p_comm, proof);
+            let f_comm = Self::check_transcript(index, proof, &oracles, &p_eval, &evlp)?;
+
+            // the polynomials are opened in the order of the legacy prover
+            let mut polynoms = polys.iter().map
+            (
+                |(comm, evals)| (comm, evals.iter().collect::<Vec<_>>(), None)
+            ).collect::<Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>>();
+            polynoms.extend
+            (
+                vec!
+                [
+                    (&p_comm, p_eval.iter().collect::<Vec<_>>(), None),
+                    (&proof.commitments.l_comm, proof.evals.iter().map(|e| &e.l).collect::<Vec<_>>(), None),
+                    (&proof.commitments.r_comm, proof.evals.iter().map(|e| &e.r).collect::<Vec<_>>(), None),
+                    (&proof.commitments.o_comm, proof.evals.iter().map(|e| &e.o).collect::<Vec<_>>(), None),
+                    (&proof.commitments.z_comm, proof.evals.iter().map(|e| &e.z).collect::<Vec<_>>(), None),
+                    (&f_comm, proof.evals.iter().map(|e| &e.f).collect::<Vec<_>>(), None),
+                ]
+            );
+            let mut index_polynoms = (0..COLUMNS-1).map
+            (
+                |c| (&index.sigma_comm[c], proof.evals.iter().map(|e| e.sigma()[c]).collect::<Vec<_>>(), None)
+            ).collect::<Vec<_>>();
+
+            let points = vec![oracles.zeta, oracles.zeta * &index.domain.group_gen];
+            let mut batch = Vec::new();
+            match (index.opening_groups, &proof.index_proof)
+            {
+                (OpeningGroups::Single, None) => polynoms.append(&mut index_polynoms),
+                (OpeningGroups::Split, Some(index_proof)) =>
+                    batch.push((fq_sponge.clone(), points.clone(), oracles.v, oracles.u, index_polynoms, index_proof)),
+                _ => return Err(ProofError::ProofStructure),
+            }
+            polynoms.push((&proof.commitments.t_comm, proof.evals.iter().map(|e| &e.t).collect::<Vec<_>>(), Some(index.max_quot_size)));
+            batch.push((fq_sponge, points, oracles.v, oracles.u, polynoms, &proof.proof));
+
+            if !index.srs.get_ref().verify::<EFqSponge, _>(group_map, &mut batch, &mut *rng) {return Err(ProofError::OpenProof)}
+        }
+        Ok(true)
+    }
+
+    // This function checks the shapes of the legacy proof, evaluated at the two points zeta and zeta*w
+    // with neither the auxiliary nor the lookup argument and the quotient commitment shifted
+    fn check_structure<G: CommitmentCurve>(index: &VerifierIndex<G>, proof: &ProverProof<G>) -> Result<(), ProofError>
+        where G::ScalarField : CommitmentField
+    {
+        proof.check_structure(index)?;
+        if
+            index.rotations != [0, 1] || !index.auxiliary.is_empty() || index.lookup_comm.is_some() ||
+            proof.commitments.t_comm.shifted.is_none()
+        {return Err(ProofError::ProofStructure)}
+        Ok(())
+    }
+
+    // This function runs the random oracle argument of the legacy proof
+    //     index: Index
+    //     p_comm: public input polynomial commitment
+    //     proof: legacy proof
+    //     RETURN: sponge before the opening, oracles, public input evaluations, powers of the evaluation
+    //         points to the segment size and previous challenge polynomials
+    fn oracles
+        <G: CommitmentCurve,
+         EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        index: &VerifierIndex<G>,
+        p_comm: &PolyComm<G>,
+        proof: &ProverProof<G>,
+    ) -> (EFqSponge, RandomOracles<Fr<G>>, Vec<Vec<Fr<G>>>, Vec<Fr<G>>, Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>)
+        where G::ScalarField : CommitmentField
+    {
+        let mut oracles = RandomOracles::<Fr<G>>::zero();
+        let mut fq_sponge = EFqSponge::new(index.fq_sponge_params.clone());
+        absorb_separator::<G, EFqSponge>(&mut fq_sponge, &index.domain_separator);
+        // absorb the public input, l, r, o polycommitments into the argument
+        match index.public_absorb_mode
+        {
+            PublicAbsorb::Batched => absorb_segments(&mut fq_sponge, &p_comm.unshifted, index.absorb_size),
+            PublicAbsorb::PerElement => proof.public.iter().for_each(|x| fq_sponge.absorb_fr(&[*x])),
+        }
+        proof.nonce.iter().for_each(|x| fq_sponge.absorb_fr(&[*x]));
+        absorb_segments(&mut fq_sponge, &proof.commitments.l_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &proof.commitments.r_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &proof.commitments.o_comm.unshifted, index.absorb_size);
+        // sample beta, gamma oracles
+        oracles.beta = fq_sponge.challenge();
+        oracles.gamma = fq_sponge.challenge();
+        // absorb the z commitment into the argument and query alpha
+        absorb_segments(&mut fq_sponge, &proof.commitments.z_comm.unshifted, index.absorb_size);
+        oracles.alpha_chal = ScalarChallenge(fq_sponge.challenge());
+        oracles.alpha = oracles.alpha_chal.to_field(&index.srs.get_ref().endo_r);
+        // absorb the quotient polycommitment into the argument, the zero shifted one as the dummy point, and sample zeta
+        let max_t_size = (index.max_quot_size + index.max_poly_size - 1) / index.max_poly_size;
+        let dummy = G::of_coordinates(Fq::<G>::zero(), Fq::<G>::zero());
+        absorb_segments(&mut fq_sponge, &proof.commitments.t_comm.unshifted, Some(index.absorb_size.unwrap_or(max_t_size)));
+        // the shifted quotient segment is present by the structure check
+        let shifted = proof.commitments.t_comm.shifted.unwrap_or_else(G::zero);
+        fq_sponge.absorb_g(&[if shifted.is_zero() {dummy} else {shifted}]);
+        oracles.zeta_chal = ScalarChallenge(fq_sponge.challenge());
+        oracles.zeta = oracles.zeta_chal.to_field(&index.srs.get_ref().endo_r);
+
+        let mut fr_sponge = EFrSponge::new(index.fr_sponge_params.clone());
+        fr_sponge.absorb(&fq_sponge.clone().digest());
+
+        // evaluate public input polynomials at zeta and zeta*w, the zero one having no evaluations
+        let ep = [oracles.zeta, oracles.zeta * &index.domain.group_gen];
+        let w = (0..proof.public.len()).zip(index.domain.elements()).map(|(_,w)| w).collect::<Vec<_>>();
+        let mut lagrange = ep.iter().flat_map(|e| w.iter().map(move |w| *e - w)).collect::<Vec<_>>();
+        algebra::fields::batch_inversion::<Fr<G>>(&mut lagrange);
+        let p_eval = ep.iter().enumerate().map
+        (
+            |(i, e)| if proof.public.iter().all(|x| x.is_zero()) {Vec::<Fr<G>>::new()} else
+            {
+                vec![(proof.public.iter().zip(lagrange[i*proof.public.len()..].iter()).
+                    zip(index.domain.elements()).map(|((p, l), w)| -*l * p * &w).
+                    fold(Fr::<G>::zero(), |x, y| x + &y)) * &index.domain.vanishing_poly_eval(*e) * &index.domain.size_inv]
+            }
+        ).collect::<Vec<_>>();
+
+        // absorb the evaluations and query the opening scaler challenges
+        p_eval.iter().zip(proof.evals.iter()).for_each(|(p, e)| fr_sponge.absorb_evaluations(p, e));
+        oracles.v_chal = fr_sponge.challenge();
+        oracles.v = oracles.v_chal.to_field(&index.srs.get_ref().endo_r);
+        oracles.u_chal = fr_sponge.challenge();
+        oracles.u = oracles.u_chal.to_field(&index.srs.get_ref().endo_r);
+
+        let evlp = ep.iter().map(|e| e.pow(&[index.max_poly_size as u64])).collect::<Vec<_>>();
+        let polys = proof.prev_challenges.iter().zip(proof.prev_chal_evals(index, &ep, &evlp)).map(|(c, e)| (c.1.clone(), e)).collect();
+        (fq_sponge, oracles, p_eval, evlp, polys)
+    }
+
+    // This function checks the linearization polynomial evaluation consistency of the legacy proof
+    //     RETURN: linearization polynomial commitment
+    fn check_transcript<G: CommitmentCurve>
+    (
+        index: &VerifierIndex<G>,
+        proof: &ProverProof<G>,
+        oracles: &RandomOracles<Fr<G>>,
+        p_eval: &Vec<Vec<Fr<G>>>,
+        evlp: &[Fr<G>],
+    ) -> Result<PolyComm<G>, ProofError>
+        where G::ScalarField : CommitmentField
+    {
+        let mut alpha = oracles.alpha;
+        let alpha = (0..LEGACY_ALPHA).map(|_| {alpha *= &oracles.alpha; alpha}).collect::<Vec<_>>();
+        let evals = proof.evals.iter().zip(evlp.iter()).map(|(e, pt)| e.combine(*pt)).collect::<Vec<ProofEvaluations<Fr<G>>>>();
+        let zh = index.domain.vanishing_poly_eval(oracles.zeta);
+        let zkp = index.zkpm.evaluate(oracles.zeta);
+
+        let p = vec!
+        [
+            &proof.commitments.z_comm, &index.sigma_comm[COLUMNS-1],
+            &index.qm_comm, &index.ql_comm, &index.qr_comm, &index.qo_comm, &index.qc_comm,
+            &index.psm_comm, &index.rcm_comm[0], &index.rcm_comm[1], &index.rcm_comm[2],
+            &index.add_comm,
+            &index.mul1_comm, &index.mul2_comm,
+            &index.emul1_comm, &index.emul2_comm, &index.emul3_comm,
+        ];
+        let mut s = ConstraintSystem::perm_scalars(&evals, oracles, &index.shifts(), &alpha[range::PERM], index.domain.size, zkp, index.w);
+        s.extend(&ConstraintSystem::gnrc_scalars(&evals[0]));
+        s.extend(&ConstraintSystem::psdn_scalars(&evals, &index.fr_sponge_params, &alpha[range::PSDN]));
+        s.extend(&ConstraintSystem::ecad_scalars(&evals, &alpha[range::ADD]));
+        s.extend(&ConstraintSystem::vbmul_scalars(&evals, &alpha[range::MUL]));
+        s.extend(&ConstraintSystem::endomul_scalars(&evals, index.endo, &alpha[range::ENDML]));
+        let f_comm = PolyComm::multi_scalar_mul(&p, &s);
+
+        if ConstraintSystem::quot_eval
+        (
+            &evals,
+            if p_eval[0].len() > 0 {p_eval[0][0]} else {Fr::<G>::zero()},
+            Fr::<G>::zero(),
+            oracles,
+            &alpha[range::PERM],
+            zkp,
+            zh,
+            index.w
+        ) != evals[0].t * &zh {return Err(ProofError::ProofVerification)}
+
+        Ok(f_comm)
+    }
+}
+
+impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function verifies the batch of zk-proofs of the same format version with the verifier of the version
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     version: format version the proofs were serialized with
+    //     proofs: vector of Plonk proofs with their verifier indexes and public input Lagrange base commitments
+    //     RETURN: verification status
+    pub fn verify_versioned
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        version: u32,
+        proofs: &Vec<(&VerifierIndex<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+    ) -> Result<bool, ProofError>
+    {
+        if LegacyVerifier::supports(version) {LegacyVerifier::verify::<G, EFqSponge, EFrSponge, _>(group_map, proofs)}
+        else if version > LEGACY_PROOF_VERSION && version <= PROOF_VERSION {Self::verify::<EFqSponge, EFrSponge, _>(group_map, proofs)}
+        else {Err(ProofError::UnsupportedVersion {found: version, supported: PROOF_VERSION})}
+    }
+}
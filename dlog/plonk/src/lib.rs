@@ -3,3 +3,18 @@ pub mod verifier;
 pub mod plonk_sponge;
 pub mod index;
 pub mod range;
+pub mod auxiliary;
+pub mod selective;
+pub mod flatten;
+pub mod schema;
+pub mod stream;
+pub mod public;
+pub mod trace;
+pub mod legacy;
+pub mod aggregated;
+#[cfg(feature = "prover")]
+pub mod hooks;
+pub mod recursion;
+pub mod accumulation;
+#[cfg(feature = "prover")]
+mod secret;
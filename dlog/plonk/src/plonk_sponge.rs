@@ -10,6 +10,7 @@ pub trait FrSponge<Fr: Field> {
     fn absorb(&mut self, x: &Fr);
     fn challenge(&mut self) -> ScalarChallenge<Fr>;
     fn absorb_evaluations(&mut self, p: &[Fr], e: &ProofEvaluations<Vec<Fr>>);
+    fn digest(self) -> Fr;
 }
 
 impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
@@ -49,4 +50,8 @@ impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
             self.sponge.absorb(&self.params, p);
         }
     }
+
+    fn digest(mut self) -> Fr {
+        self.sponge.squeeze(&self.params)
+    }
 }
@@ -4,19 +4,40 @@ This source file implements prover's zk-proof primitive.
 
 *********************************************************************************************/
 
-use algebra::{Field, AffineCurve, Zero, One, UniformRand, PrimeField};
-use ff_fft::{DensePolynomial, DenseOrSparsePolynomial, Evaluations, Radix2EvaluationDomain as D};
-use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof, b_poly_coefficients};
-use oracle::{FqSponge, utils::PolyUtils, rndoracle::ProofError, sponge::ScalarChallenge};
-use plonk_circuits::{scalars::{ProofEvaluations, RandomOracles}, constraints::ConstraintSystem};
-pub use super::{index::Index, range};
-use crate::plonk_sponge::{FrSponge};
+use algebra::AffineCurve;
+use commitment_dlog::commitment::{PolyComm, OpeningProof};
+use plonk_circuits::scalars::ProofEvaluations;
+pub use super::{index::{Index, OpeningGroups, PublicAbsorb, OpeningOrder}, range, auxiliary::AuxiliaryPoint};
+#[cfg(feature = "prover")]
+use algebra::{Field, Zero, One, UniformRand, PrimeField, ToBytes};
+#[cfg(feature = "prover")]
+use ff_fft::{DensePolynomial, DenseOrSparsePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDomain as D};
+#[cfg(feature = "prover")]
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, b_poly_coefficients};
+#[cfg(feature = "prover")]
+use oracle::{FqSponge, utils::{PolyUtils, EvalUtils}, rndoracle::{ProofError, DivisionSite}, sponge::ScalarChallenge};
+#[cfg(feature = "prover")]
+use groupmap::GroupMap;
+#[cfg(feature = "prover")]
+use plonk_circuits::{scalars::RandomOracles, constraints::{ConstraintSystem, public_polynomial}, polynomial::WitnessOverDomains, witness::{WitnessLayout, WitnessSource}, wires::COLUMNS};
+#[cfg(feature = "prover")]
+use crate::{plonk_sponge::{FrSponge}, hooks::{ProverHooks, ProverPhase}, secret::{wipe, wipe_poly, wipe_blinder, clear}, index::{evaluation_points, absorb_separator, absorb_segments}};
+#[cfg(feature = "prover")]
 use rand::thread_rng;
+#[cfg(feature = "prover")]
+use rand_core::{RngCore, CryptoRng, Error as RngError, impls};
+#[cfg(feature = "prover")]
+use blake2::{Blake2b, Digest};
+#[cfg(all(feature = "prover", feature = "parallel"))]
+use rayon::prelude::*;
+#[cfg(feature = "prover")]
+use std::{future::Future, time::Instant, borrow::Cow, marker::PhantomData};
 
 type Fr<G> = <G as AffineCurve>::ScalarField;
+#[cfg(feature = "prover")]
 type Fq<G> = <G as AffineCurve>::BaseField;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "ocaml_types", derive(ocaml::ToValue, ocaml::FromValue))]
 pub struct ProverCommitments<G: AffineCurve>
 {
@@ -25,6 +46,8 @@ pub struct ProverCommitments<G: AffineCurve>
     pub o_comm: PolyComm<G>,
     pub z_comm: PolyComm<G>,
     pub t_comm: PolyComm<G>,
+    pub aux_comm: Vec<PolyComm<G>>,
+    pub lookup_comm: Vec<PolyComm<G>>,  // lookup multiplicities and running sum, if the lookup argument is present
 }
 
 #[cfg_attr(feature = "ocaml_types", derive(ocaml::ToValue, ocaml::FromValue))]
@@ -32,13 +55,19 @@ struct CamlProverProof<G: AffineCurve>
 {
     pub commitments: ProverCommitments<G>,
     pub proof: OpeningProof<G>,
+    pub index_proof: Option<OpeningProof<G>>,
     // OCaml doesn't have sized arrays, so we have to convert to a tuple..
+    // the OCaml proofs are opened at the evaluation points of the default rotations
     pub evals: (ProofEvaluations<Vec<Fr<G>>>, ProofEvaluations<Vec<Fr<G>>>),
+    pub aux_evals: (Vec<Vec<Fr<G>>>, Vec<Vec<Fr<G>>>),
     pub public: Vec<Fr<G>>,
     pub prev_challenges: Vec<(Vec<Fr<G>>, PolyComm<G>)>,
+    pub witness_checksum: Option<Vec<u8>>,
+    pub nonce: Option<Fr<G>>,
+    pub lookup_evals: (Vec<Vec<Fr<G>>>, Vec<Vec<Fr<G>>>),
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct ProverProof<G: AffineCurve>
 {
     // polynomial commitments
@@ -47,14 +76,364 @@ pub struct ProverProof<G: AffineCurve>
     // batched commitment opening proof
     pub proof: OpeningProof<G>,
 
-    // polynomial evaluations
-    pub evals: [ProofEvaluations<Vec<Fr<G>>>; 2],
+    // batched commitment opening proof of the index polynomials, if opened separately
+    pub index_proof: Option<OpeningProof<G>>,
+
+    // polynomial evaluations at the evaluation points of the index rotations
+    pub evals: Vec<ProofEvaluations<Vec<Fr<G>>>>,
+
+    // auxiliary witness polynomial evaluations
+    pub aux_evals: Vec<Vec<Vec<Fr<G>>>>,
 
     // public part of the witness
     pub public: Vec<Fr<G>>,
 
     // The challenges underlying the optional polynomials folded into the proof
     pub prev_challenges: Vec<(Vec<Fr<G>>, PolyComm<G>)>,
+
+    // optional hash of the witness the proof was created from, for integrity only
+    pub witness_checksum: Option<Vec<u8>>,
+
+    // optional per-proof nonce absorbed after the public input, binding the proof against replay
+    pub nonce: Option<Fr<G>>,
+
+    // lookup multiplicities, running sum, selector and table column polynomial evaluations,
+    // empty without the lookup argument
+    pub lookup_evals: Vec<Vec<Vec<Fr<G>>>>,
+}
+
+// committed polynomials of the proof along with their commitment blinders
+#[cfg(feature = "prover")]
+pub struct ProverPolys<G: AffineCurve, EFqSponge>
+{
+    pub(crate) l: DensePolynomial<Fr<G>>,
+    pub(crate) r: DensePolynomial<Fr<G>>,
+    pub(crate) o: DensePolynomial<Fr<G>>,
+    pub(crate) z: DensePolynomial<Fr<G>>,
+    pub(crate) t: DensePolynomial<Fr<G>>,
+    pub(crate) p: DensePolynomial<Fr<G>>,
+    pub(crate) omega: [PolyComm<Fr<G>>; 5],    // l, r, o, z, t commitment blinders
+    pub(crate) oracles: RandomOracles<Fr<G>>,  // random oracles of the proof transcript
+    pub(crate) fq_sponge: EFqSponge,           // transcript sponge before the evaluations
+}
+
+// the secret polynomials and their blinders are zeroed with the zeroize feature
+#[cfg(feature = "prover")]
+impl<G: AffineCurve, EFqSponge> Drop for ProverPolys<G, EFqSponge>
+{
+    fn drop(&mut self)
+    {
+        wipe_poly(&mut self.l);
+        wipe_poly(&mut self.r);
+        wipe_poly(&mut self.o);
+        wipe_poly(&mut self.z);
+        wipe_poly(&mut self.t);
+        self.omega.iter_mut().for_each(|omega| wipe_blinder(omega));
+    }
+}
+
+// witness of the proof, either the field values or the values of another type
+// converted to the field once into a buffer the prover owns
+#[cfg(feature = "prover")]
+pub enum ProverWitness<'a, F>
+{
+    Field(&'a Vec<F>),
+    Converted(usize, &'a dyn Fn(usize) -> Option<F>),   // witness size and the range checked converted value at the index
+    Owned(&'a mut Vec<F>),                              // caller-owned field values zeroed once not read any more
+}
+
+#[cfg(feature = "prover")]
+impl<'a, F: Copy> ProverWitness<'a, F>
+{
+    fn len(&self) -> usize
+    {
+        match self
+        {
+            ProverWitness::Field(w) => w.len(),
+            ProverWitness::Converted(len, _) => *len,
+            ProverWitness::Owned(w) => w.len(),
+        }
+    }
+
+    fn get(&self, i: usize) -> F
+    {
+        match self
+        {
+            ProverWitness::Field(w) => w[i],
+            ProverWitness::Converted(..) => unreachable!("the converted witness is buffered before the proof creation"),
+            ProverWitness::Owned(w) => w[i],
+        }
+    }
+}
+
+// randomness source of the deterministic proofs squeezing the transcript sponge forked
+// and seeded by the witness, unpredictable without the witness
+#[cfg(feature = "prover")]
+pub(crate) struct SpongeRng<G: AffineCurve, EFqSponge>
+{
+    sponge: EFqSponge,
+    buffer: Vec<u8>,    // squeezed bytes not consumed yet
+    curve: PhantomData<G>,
+}
+
+#[cfg(feature = "prover")]
+impl<G: AffineCurve, EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>> SpongeRng<G, EFqSponge>
+{
+    fn fork(sponge: &EFqSponge, witness: &[Fr<G>]) -> Self
+    {
+        let mut sponge = sponge.clone();
+        sponge.absorb_fr(witness);
+        SpongeRng {sponge, buffer: Vec::new(), curve: PhantomData}
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<G: AffineCurve, EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>> RngCore for SpongeRng<G, EFqSponge>
+{
+    fn next_u32(&mut self) -> u32 {impls::next_u32_via_fill(self)}
+
+    fn next_u64(&mut self) -> u64 {impls::next_u64_via_fill(self)}
+
+    fn fill_bytes(&mut self, dest: &mut [u8])
+    {
+        // the challenges are of the two lower limbs
+        while self.buffer.len() < dest.len()
+        {
+            let c = self.sponge.challenge().into_repr();
+            c.as_ref()[0..2].iter().for_each(|l| self.buffer.extend_from_slice(&l.to_le_bytes()));
+        }
+        dest.copy_from_slice(&self.buffer[0..dest.len()]);
+        self.buffer.drain(0..dest.len());
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError>
+    {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// randomness source of the commitment blinding and the opening masks
+#[cfg(feature = "prover")]
+pub(crate) enum Blinding<'r>
+{
+    Thread,                     // thread local RNG
+    Transcript,                 // witness seeded transcript sponge
+    Rng(&'r mut dyn RngCore),   // caller supplied RNG
+}
+
+// degree metrics of the quotient polynomial of the proof
+#[derive(Clone, Copy, Debug)]
+pub struct QuotientMetrics<F>
+{
+    pub degree: usize,  // actual degree of the quotient polynomial
+    pub leading: F,     // leading coefficient of the quotient polynomial
+}
+
+// commitment blinders of the proof, the commitments being the non-hiding ones masked by them
+#[derive(Clone)]
+pub struct ProofBlinders<F>
+{
+    pub l: PolyComm<F>,     // left wire commitment blinder
+    pub r: PolyComm<F>,     // right wire commitment blinder
+    pub o: PolyComm<F>,     // output wire commitment blinder
+    pub z: PolyComm<F>,     // permutation commitment blinder
+    pub t: PolyComm<F>,     // quotient commitment blinder
+}
+
+// polynomials computed by the prover, exposed to the test harnesses
+#[cfg(feature = "debug")]
+#[derive(Clone)]
+pub struct DebugPolys<F: Field>
+{
+    pub l: DensePolynomial<F>,  // left wire polynomial
+    pub r: DensePolynomial<F>,  // right wire polynomial
+    pub o: DensePolynomial<F>,  // output wire polynomial
+    pub z: DensePolynomial<F>,  // permutation polynomial
+    pub t: DensePolynomial<F>,  // quotient polynomial
+    pub p: DensePolynomial<F>,  // public input polynomial
+}
+
+// options of the proof creation, the default ones proving the witness of the block layout with the commitment
+// blinding and the opening masks sampled from the thread RNG. The options combine freely
+#[cfg(feature = "prover")]
+pub struct ProverConfig<'a, G: AffineCurve, EFqSponge>
+{
+    pub(crate) blinded: Option<[&'a DensePolynomial<Fr<G>>; 3]>,  // externally blinded wire polynomials
+    pub(crate) fq_sponge: Option<EFqSponge>,                       // parent protocol sponge, the fresh one if None
+    pub(crate) layout: WitnessLayout,                              // layout of the wire columns within the witness
+    pub(crate) deadline: Option<Instant>,                          // instant the proving is abandoned after
+    pub(crate) nonce: Option<Fr<G>>,                               // per-proof nonce absorbed after the public input
+    pub(crate) blinding: Blinding<'a>,                             // randomness source of the blinding
+    pub(crate) violated: Option<&'a mut Vec<usize>>,               // rows of the violated gate constraints
+    pub(crate) low_mem: bool,                                      // low memory proving mode
+    pub(crate) hooks: Option<&'a mut dyn ProverHooks>,             // instrumentation hooks of the proving phases
+    pub(crate) checksum: bool,                                     // witness checksum embedded into the proof
+}
+
+#[cfg(feature = "prover")]
+impl<'a, G: AffineCurve, EFqSponge> Default for ProverConfig<'a, G, EFqSponge>
+{
+    fn default() -> Self
+    {
+        ProverConfig
+        {
+            blinded: None,
+            fq_sponge: None,
+            layout: WitnessLayout::Blocks,
+            deadline: None,
+            nonce: None,
+            blinding: Blinding::Thread,
+            violated: None,
+            low_mem: false,
+            hooks: None,
+            checksum: false,
+        }
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<'a, G: AffineCurve, EFqSponge> ProverConfig<'a, G, EFqSponge>
+{
+    // This function creates the default options of the proof creation
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // This function samples the commitment blinding and the opening masks from the supplied RNG,
+    // so that a seeded RNG reproduces the proof
+    //     rng: randomness source of the proof
+    pub fn with_rng<R: RngCore + CryptoRng>(mut self, rng: &'a mut R) -> Self
+    {
+        self.blinding = Blinding::Rng(rng);
+        self
+    }
+
+    // This function makes the proof deterministic, the commitment blinding and the opening masks
+    // being squeezed from the transcript sponge forked and seeded by the witness instead of the RNG,
+    // so that the proof is the same for the same witness and index
+    pub fn with_deterministic_blinding(mut self) -> Self
+    {
+        self.blinding = Blinding::Transcript;
+        self
+    }
+
+    // This function selects the low memory mode for very large domains. The quotient contributions are
+    // accumulated in place as soon as they are computed, each of them being freed right after, and the wire
+    // evaluations over the d4 and d8 domains are freed once the last contribution is in. The proof is the
+    // same as the default one for the same RNG, at the price of serializing the contributions accumulation
+    // and, on the quotient identity failure, of evaluating the violated rows from the contributions polynomial
+    pub fn with_low_mem(mut self) -> Self
+    {
+        self.low_mem = true;
+        self
+    }
+
+    // This function sets the deadline checked at the boundaries of the wire interpolation, permutation,
+    // quotient and opening phases
+    //     deadline: instant the proving is abandoned after at the next phase boundary
+    pub fn with_deadline(mut self, deadline: Instant) -> Self
+    {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    // This function binds the proof to the nonce, so that the proofs of the same witness differ
+    // and a proof can not be replayed as a fresh one
+    //     nonce: caller-supplied per-proof unique nonce
+    pub fn with_nonce(mut self, nonce: Fr<G>) -> Self
+    {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    // This function sets the layout of the wire columns within the witness
+    //     layout: witness layout
+    pub fn with_layout(mut self, layout: WitnessLayout) -> Self
+    {
+        self.layout = layout;
+        self
+    }
+
+    // This function continues the transcript of a parent protocol
+    //     fq_sponge: parent protocol sponge, the verifier has to be given the same sponge state
+    pub fn with_sponge(mut self, fq_sponge: EFqSponge) -> Self
+    {
+        self.fq_sponge = Some(fq_sponge);
+        self
+    }
+
+    // This function proves from the wire polynomials blinded externally as w(X) + b(X)*Z_H(X),
+    // the prover does not add its own blinding to the wires. The degree of b(X) is bounded
+    // by the blinding degree of the index, or by 1 for the index without the blinding
+    //     wires: left, right and output blinded wire polynomials
+    pub fn with_blinded_wires(mut self, wires: [&'a DensePolynomial<Fr<G>>; 3]) -> Self
+    {
+        self.blinded = Some(wires);
+        self
+    }
+
+    // This function explains the quotient identity failure, the rows the constraint contributions
+    // are nonzero at being reported on the vanishing polynomial division error
+    //     violated: rows of the violated gate constraints, the copy constraints failing earlier
+    pub fn with_diagnostic(mut self, violated: &'a mut Vec<usize>) -> Self
+    {
+        self.violated = Some(violated);
+        self
+    }
+
+    // This function reports the boundaries of the proving phases to the hooks
+    //     hooks: instrumentation hooks of the proving phases
+    pub fn with_hooks(mut self, hooks: &'a mut dyn ProverHooks) -> Self
+    {
+        self.hooks = Some(hooks);
+        self
+    }
+
+    // This function makes the proof embed the checksum of the witness. The checksum
+    // is not absorbed into the transcript and does not affect the proof soundness
+    pub fn with_checksum(mut self) -> Self
+    {
+        self.checksum = true;
+        self
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<G: AffineCurve, EFqSponge> ProverPolys<G, EFqSponge>
+{
+    // This function returns the random oracles squeezed from the proof transcript,
+    // the same as the ones the verifier derives from the proof
+    pub fn oracles(&self) -> &RandomOracles<Fr<G>>
+    {
+        &self.oracles
+    }
+
+    // This function returns the commitment blinders of the proof, so that the caller can reproduce
+    // the commitments with mask_with or re-randomize them consistently in a follow-up proof
+    pub fn blinders(&self) -> ProofBlinders<Fr<G>>
+    {
+        let omega = &self.omega;
+        ProofBlinders {l: omega[0].clone(), r: omega[1].clone(), o: omega[2].clone(), z: omega[3].clone(), t: omega[4].clone()}
+    }
+
+    // This function returns the degree metrics of the quotient polynomial,
+    // a degenerate circuit collapsing the quotient below its expected degree
+    pub fn quotient_metrics(&self) -> QuotientMetrics<Fr<G>>
+    {
+        // the quotient coefficients are padded with zeros up to the quotient size bound
+        let degree = self.t.coeffs.iter().rposition(|c| !c.is_zero()).unwrap_or(0);
+        let leading = self.t.coeffs.get(degree).cloned().unwrap_or(Fr::<G>::zero());
+        QuotientMetrics {degree, leading}
+    }
+
+    // This function returns the wire, permutation, quotient and public input polynomials of the proof
+    #[cfg(feature = "debug")]
+    pub fn debug_polys(&self) -> DebugPolys<Fr<G>>
+    {
+        DebugPolys {l: self.l.clone(), r: self.r.clone(), o: self.o.clone(), z: self.z.clone(), t: self.t.clone(), p: self.p.clone()}
+    }
 }
 
 #[cfg(feature = "ocaml_types")]
@@ -65,12 +444,23 @@ unsafe impl<G: AffineCurve + ocaml::ToValue> ocaml::ToValue for ProverProof<G> w
             CamlProverProof{
                 commitments: self.commitments,
                 proof: self.proof,
+                index_proof: self.index_proof,
                 evals: {
-                    let [evals0, evals1] = self.evals;
-                    (evals0, evals1)
+                    let mut evals = self.evals.into_iter();
+                    (evals.next().unwrap(), evals.next().unwrap())
+                },
+                aux_evals: {
+                    let mut evals = self.aux_evals.into_iter();
+                    (evals.next().unwrap(), evals.next().unwrap())
                 },
                 public: self.public,
-                prev_challenges: self.prev_challenges
+                prev_challenges: self.prev_challenges,
+                witness_checksum: self.witness_checksum,
+                nonce: self.nonce,
+                lookup_evals: {
+                    let mut evals = self.lookup_evals.into_iter();
+                    (evals.next().unwrap(), evals.next().unwrap())
+                },
             })
     }
 }
@@ -83,199 +473,803 @@ unsafe impl<G: AffineCurve + ocaml::FromValue> ocaml::FromValue for ProverProof<
         ProverProof {
             commitments: p.commitments,
             proof: p.proof,
+            index_proof: p.index_proof,
             evals: {
                 let (evals0, evals1) = p.evals;
-                [evals0, evals1]
+                vec![evals0, evals1]
+            },
+            aux_evals: {
+                let (evals0, evals1) = p.aux_evals;
+                vec![evals0, evals1]
             },
             public: p.public,
-            prev_challenges: p.prev_challenges
+            prev_challenges: p.prev_challenges,
+            witness_checksum: p.witness_checksum,
+            nonce: p.nonce,
+            lookup_evals: {
+                let (evals0, evals1) = p.lookup_evals;
+                vec![evals0, evals1]
+            },
         }
     }
 }
 
+impl<G: AffineCurve> ProverProof<G>
+{
+    // This function compares the proofs field by field
+    //     other: proof to compare with
+    //     RETURN: names of the fields the proofs differ in, in the declaration order
+    pub fn diff(&self, other: &Self) -> Vec<&'static str>
+    {
+        let mut fields = Vec::new();
+        if self.commitments != other.commitments {fields.push("commitments")}
+        if self.proof != other.proof {fields.push("proof")}
+        if self.index_proof != other.index_proof {fields.push("index_proof")}
+        if self.evals != other.evals {fields.push("evals")}
+        if self.aux_evals != other.aux_evals {fields.push("aux_evals")}
+        if self.public != other.public {fields.push("public")}
+        if self.prev_challenges != other.prev_challenges {fields.push("prev_challenges")}
+        if self.witness_checksum != other.witness_checksum {fields.push("witness_checksum")}
+        if self.nonce != other.nonce {fields.push("nonce")}
+        if self.lookup_evals != other.lookup_evals {fields.push("lookup_evals")}
+        fields
+    }
+}
+
+#[cfg(feature = "prover")]
 impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
 {
-    // This function constructs prover's zk-proof from the witness & the Index against SRS instance
+    // This function constructs prover's zk-proof from the witness & the Index against SRS instance,
+    // the thread RNG being seeded from the getrandom source, the browser one with the wasm feature
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
     //     witness: computation witness
     //     index: Index
     //     RETURN: prover's zk-proof
     pub fn create
         <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
          EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: &Vec::<Fr<G>>,
+        index: &Index<G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+    )
+    -> Result<Self, ProofError>
+    {
+        Self::prove::<EFqSponge, EFrSponge, _>(group_map, ProverWitness::Field(witness), index, prev_challenges, ProverConfig::new())
+            .map(|(proof, _)| proof)
+    }
+
+    // This function constructs prover's zk-proof with the commitment blinding and the opening masks
+    // sampled from the supplied RNG, so that a seeded RNG reproduces the proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: computation witness
+    //     index: Index
+    //     rng: randomness source of the proof
+    //     RETURN: prover's zk-proof
+    pub fn create_with_rng
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         R: RngCore + CryptoRng,
+         M: GroupMap<Fq<G>>,
         >
     (
-        group_map: &G::Map,
+        group_map: &M,
         witness: &Vec::<Fr<G>>,
         index: &Index<G>,
         prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        rng: &mut R,
+    )
+    -> Result<Self, ProofError>
+    {
+        Self::prove::<EFqSponge, EFrSponge, _>(group_map, ProverWitness::Field(witness), index, prev_challenges, ProverConfig::new().with_rng(rng))
+            .map(|(proof, _)| proof)
+    }
+
+    // This function constructs prover's zk-proof from the witness values supplied as futures,
+    // the futures are resolved in the witness order before the witness polynomials are interpolated
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: futures of the computation witness values, the first failing one aborts the proof
+    //     index: Index
+    //     config: proof creation options
+    //     RETURN: prover's zk-proof
+    pub async fn create_async
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         W: Future<Output = Result<Fr<G>, ProofError>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: Vec<W>,
+        index: &Index<'_, G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        config: ProverConfig<'_, G, EFqSponge>,
+    )
+    -> Result<Self, ProofError>
+    {
+        let mut values = Vec::with_capacity(witness.len());
+        for w in witness {values.push(w.await?)}
+        Self::prove::<EFqSponge, EFrSponge, _>(group_map, ProverWitness::Field(&values), index, prev_challenges, config).map(|(proof, _)| proof)
+    }
+
+    // This function constructs prover's zk-proof from the witness generated by the witness source,
+    // such as the WASM witness generator, over the domain of the index
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     source: witness generator of the computation
+    //     inputs: computation inputs
+    //     index: Index
+    //     config: proof creation options
+    //     RETURN: prover's zk-proof, the runtime error if the witness generation fails
+    pub fn create_from_source
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        source: &dyn WitnessSource<Fr<G>>,
+        inputs: &[Fr<G>],
+        index: &Index<G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        config: ProverConfig<G, EFqSponge>,
     )
     -> Result<Self, ProofError>
     {
+        let mut witness = source.generate(inputs, index.cs.domain.d1.size as usize).ok_or(ProofError::RuntimeEnv)?;
+        Self::prove::<EFqSponge, EFrSponge, _>(group_map, ProverWitness::Owned(&mut witness), index, prev_challenges, config).map(|(proof, _)| proof)
+    }
+
+    // This function computes the checksum of the witness
+    //     witness: computation witness
+    //     RETURN: Blake2b hash of the witness
+    pub fn checksum(witness: &Vec::<Fr<G>>) -> Vec<u8>
+    {
+        let mut h = Blake2b::new();
+        let mut bytes = Vec::new();
+        witness.iter().for_each(|w| w.write(&mut bytes).unwrap());
+        h.input(&bytes);
+        h.result().to_vec()
+    }
+
+    // This function returns the witness checksum embedded into the proof
+    pub fn witness_checksum(&self) -> Option<&Vec<u8>>
+    {
+        self.witness_checksum.as_ref()
+    }
+
+    // This function constructs prover's zk-proof along with the committed polynomials, the caller-owned
+    // witness being zeroed regardless of the zeroize feature as soon as the prover does not read it any more,
+    // that is past the permutation polynomial computation, or on the proof creation failure
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: computation witness
+    //     index: Index
+    //     config: proof creation options
+    //     RETURN: prover's zk-proof and its committed polynomials
+    pub fn prove
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: ProverWitness<Fr<G>>,
+        index: &Index<G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        config: ProverConfig<G, EFqSponge>,
+    )
+    -> Result<(Self, ProverPolys<G, EFqSponge>), ProofError>
+    {
+        // the values are converted and range checked once, the buffer being zeroed as the caller-owned witness
+        let mut converted;
+        let mut witness = match witness
+        {
+            ProverWitness::Converted(len, f) =>
+            {
+                converted = (0..len).map(|i| f(i)).collect::<Option<Vec<_>>>().ok_or(ProofError::WitnessCsInconsistent)?;
+                ProverWitness::Owned(&mut converted)
+            }
+            witness => witness,
+        };
+        let proof = Self::prove_witness::<EFqSponge, EFrSponge, _>(group_map, &mut witness, index, prev_challenges, config);
+        if let ProverWitness::Owned(w) = &mut witness {clear(w)}
+        proof
+    }
+
+    // This function constructs prover's zk-proof, the caller-owned witness being zeroed
+    // past the permutation polynomial computation
+    fn prove_witness
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: &mut ProverWitness<Fr<G>>,
+        index: &Index<G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        config: ProverConfig<G, EFqSponge>,
+    )
+    -> Result<(Self, ProverPolys<G, EFqSponge>), ProofError>
+    {
+        let ProverConfig {blinded, fq_sponge, layout, deadline, nonce, blinding, violated, low_mem, hooks, checksum} = config;
         let n = index.cs.domain.d1.size as usize;
         assert!(n <= index.srs.get_ref().g.len());
-        if witness.len() != 3*n {return Err(ProofError::WitnessCsInconsistent)}
+        if witness.len() != COLUMNS*n {return Err(ProofError::WitnessLength {expected: COLUMNS*n, found: witness.len()})}
+        // the checksum is of the witness as supplied, before the caller-owned witness is zeroed
+        let witness_checksum = if checksum {Some(Self::checksum(&(0..witness.len()).map(|i| witness.get(i)).collect()))} else {None};
+        index.check_blinding_degree()?;
+        index.check_absorb_size()?;
+        index.check_rotations()?;
+
+        // the deadline is checked at the proving phase boundaries
+        let check_deadline = || match deadline
+        {
+            Some(d) if Instant::now() >= d => Err(ProofError::DeadlineExceeded),
+            _ => Ok(()),
+        };
+        check_deadline()?;
+
+        // the phase boundaries are reported to the hooks
+        let mut hooks = hooks;
+        let mut phase = |end: Option<ProverPhase>, begin: Option<ProverPhase>| if let Some(h) = hooks.as_mut()
+        {
+            end.iter().for_each(|p| h.end(*p));
+            begin.iter().for_each(|p| h.begin(*p));
+        };
+        phase(None, Some(ProverPhase::Wires));
 
         let mut oracles = RandomOracles::<Fr<G>>::zero();
 
-        // the transcript of the random oracle non-interactive argument
-        let mut fq_sponge = EFqSponge::new(index.fq_sponge_params.clone());
+        // the transcript of the random oracle non-interactive argument, domain separated first
+        let mut fq_sponge = fq_sponge.unwrap_or_else(|| EFqSponge::new(index.fq_sponge_params.clone()));
+        absorb_separator::<G, EFqSponge>(&mut fq_sponge, &index.domain_separator);
+
+        // wire value at the block layout index
+        let wire = |i: usize| witness.get(layout.position(i, n));
+
+        // validate the witness against the gate constraints before the commitments
+        if index.witness_check {index.check_witness(&(0..COLUMNS*n).map(|i| wire(i)).collect())?}
+        // validate the witness against the copy constraints before the permutation product
+        if index.permutation_check {index.check_permutation(&(0..COLUMNS*n).map(|i| wire(i)).collect())?}
 
         // compute public input polynomial
-        let public = witness[0..index.cs.public].to_vec();
-        let p = -Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(public.clone(), index.cs.domain.d1).interpolate();
+        let public = (0..index.cs.public).map(|i| wire(i)).collect::<Vec<_>>();
+        let p = public_polynomial(index.cs.domain.d1, &public);
 
-        let rng = &mut thread_rng();
+        // the blinding is squeezed from the transcript sponge forked before the absorptions
+        // and seeded by the witness in the deterministic mode
+        let mut thread;
+        let mut forked;
+        let rng: &mut dyn RngCore = match blinding
+        {
+            Blinding::Thread => {thread = thread_rng(); &mut thread}
+            Blinding::Transcript =>
+            {
+                forked = SpongeRng::<G, EFqSponge>::fork(&fq_sponge, &(0..witness.len()).map(|i| witness.get(i)).collect::<Vec<_>>());
+                &mut forked
+            }
+            Blinding::Rng(rng) => rng,
+        };
 
-        // compute witness polynomials
-        let l = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gates.iter().map(|gate| witness[gate.wires.l.0]).collect(), index.cs.domain.d1).interpolate();
-        let r = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gates.iter().map(|gate| witness[gate.wires.r.0]).collect(), index.cs.domain.d1).interpolate();
-        let o = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gates.iter().map(|gate| witness[gate.wires.o.0]).collect(), index.cs.domain.d1).interpolate();
+        // compute witness polynomials, interpolated concurrently with the parallel feature
+        #[cfg(feature = "parallel")]
+        let (mut l, mut r, mut o) =
+        {
+            let d1 = index.cs.domain.d1;
+            let columns = vec!
+            [
+                index.cs.gate_iter().map(|gate| wire(gate.wires.l.0)).collect::<Vec<_>>(),
+                index.cs.gate_iter().map(|gate| wire(gate.wires.r.0)).collect::<Vec<_>>(),
+                index.cs.gate_iter().map(|gate| wire(gate.wires.o.0)).collect::<Vec<_>>(),
+            ];
+            let mut wires = columns.into_par_iter().map(|e| Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(e, d1).interpolate()).collect::<Vec<_>>();
+            let o = wires.pop().unwrap();
+            let r = wires.pop().unwrap();
+            (wires.pop().unwrap(), r, o)
+        };
+        #[cfg(not(feature = "parallel"))]
+        let mut l = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gate_iter().map(|gate| wire(gate.wires.l.0)).collect(), index.cs.domain.d1).interpolate();
+        #[cfg(not(feature = "parallel"))]
+        let mut r = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gate_iter().map(|gate| wire(gate.wires.r.0)).collect(), index.cs.domain.d1).interpolate();
+        #[cfg(not(feature = "parallel"))]
+        let mut o = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(index.cs.gate_iter().map(|gate| wire(gate.wires.o.0)).collect(), index.cs.domain.d1).interpolate();
+
+        // blind the witness polynomials with the random multiples of the vanishing polynomial
+        // unless blinded externally
+        if index.zk.wire_degree > 0 && blinded.is_none()
+        {
+            for w in [&mut l, &mut r, &mut o].iter_mut()
+            {
+                let mut b = DensePolynomial::from_coefficients_vec((0..=index.zk.wire_degree).map(|_| Fr::<G>::rand(rng)).collect());
+                **w += &b.mul_by_vanishing_poly(index.cs.domain.d1);
+                wipe_poly(&mut b);
+            }
+        }
 
         // commit to the l, r, o wire values
-        let (l_comm, omega_l) = index.srs.get_ref().commit(&l, None, rng);
-        let (r_comm, omega_r) = index.srs.get_ref().commit(&r, None, rng);
-        let (o_comm, omega_o) = index.srs.get_ref().commit(&o, None, rng);
+        let ((l, l_comm, omega_l), (r, r_comm, omega_r), (o, o_comm, omega_o)) = match blinded
+        {
+            None =>
+            {
+                // the wire MSMs overlap when pipelined, the blinding consuming the randomness
+                // in the wire order so that the proof does not depend on the pipelining
+                let srs = index.srs.get_ref();
+                let wires = [&l, &r, &o];
+                #[cfg(feature = "parallel")]
+                let comms = if index.pipelined_commit {wires.par_iter().map(|p| srs.commit_non_hiding(p, None)).collect::<Vec<_>>()}
+                    else {wires.iter().map(|p| srs.commit_non_hiding(p, None)).collect::<Vec<_>>()};
+                #[cfg(not(feature = "parallel"))]
+                let comms = wires.iter().map(|p| srs.commit_non_hiding(p, None)).collect::<Vec<_>>();
+
+                // the wires are blinded by the commitment randomness unless disabled in the index
+                let mut masked = comms.into_iter().zip(index.zk.wire_mask.iter()).map
+                (
+                    |(comm, blind)| if *blind {srs.mask(comm, rng)} else
+                    {
+                        let omega = PolyComm {unshifted: vec![Fr::<G>::zero(); comm.unshifted.len()], shifted: None};
+                        (comm, omega)
+                    }
+                ).collect::<Vec<_>>();
+                let (o_comm, omega_o) = masked.pop().unwrap();
+                let (r_comm, omega_r) = masked.pop().unwrap();
+                let (l_comm, omega_l) = masked.pop().unwrap();
+                ((l, l_comm, omega_l), (r, r_comm, omega_r), (o, o_comm, omega_o))
+            }
+            Some(wires) =>
+            {
+                // the externally blinded wires have to agree with the witness over the domain, their blinding
+                // being bounded by the blinding degree of the index as the commitment sizes are
+                let mut blinded = Vec::new();
+                for (w, p) in wires.iter().zip([&l, &r, &o].iter())
+                {
+                    if w.degree() < n || w.degree() > n + index.zk.wire_degree.max(1) {return Err(ProofError::WitnessBlinding)}
+                    let (_, res) = w.divide_by_vanishing_poly(index.cs.domain.d1).map_or(Err(ProofError::PolyDivisionAt(DivisionSite::WireBlinding)), |s| Ok(s))?;
+                    if (&res - *p).is_zero() == false {return Err(ProofError::WitnessCsInconsistent)}
+                    let comm = index.srs.get_ref().commit_non_hiding(w, None);
+                    let omega = PolyComm {unshifted: vec![Fr::<G>::zero(); comm.unshifted.len()], shifted: None};
+                    blinded.push(((*w).clone(), comm, omega));
+                }
+                let o = blinded.pop().unwrap();
+                let r = blinded.pop().unwrap();
+                let l = blinded.pop().unwrap();
+                (l, r, o)
+            }
+        };
+
+        check_deadline()?;
+        phase(Some(ProverPhase::Wires), Some(ProverPhase::Permutation));
 
         // absorb the public input, l, r, o polycommitments into the argument
-        let public_input_comm = &index.srs.get_ref().commit_non_hiding(&p, None).unshifted;
-        // this breaks tests with empty public input :: assert_eq!(public_input_comm.len(), 1);
-        fq_sponge.absorb_g(&public_input_comm);
-        fq_sponge.absorb_g(&l_comm.unshifted);
-        fq_sponge.absorb_g(&r_comm.unshifted);
-        fq_sponge.absorb_g(&o_comm.unshifted);
+        match index.public_absorb_mode
+        {
+            PublicAbsorb::Batched =>
+            {
+                let public_input_comm = &index.srs.get_ref().commit_non_hiding(&p, None).unshifted;
+                // the zero public input polynomial, the empty public input included, is committed with no segments
+                absorb_segments(&mut fq_sponge, &public_input_comm, index.absorb_size);
+            }
+            PublicAbsorb::PerElement => public.iter().for_each(|x| fq_sponge.absorb_fr(&[*x])),
+        }
+        nonce.iter().for_each(|x| fq_sponge.absorb_fr(&[*x]));
+        absorb_segments(&mut fq_sponge, &l_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &r_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &o_comm.unshifted, index.absorb_size);
+
+        // compute, commit to and absorb the auxiliary witness polynomials
+        // from the witness in the block layout
+        let blocks = if index.auxiliary.len() == 0 {None} else
+        {
+            Some(match &*witness
+            {
+                ProverWitness::Field(w) if layout == WitnessLayout::Blocks => Cow::Borrowed(*w),
+                ProverWitness::Field(w) => Cow::Owned(layout.to_blocks(w, n)),
+                ProverWitness::Owned(w) if layout == WitnessLayout::Blocks => Cow::Borrowed(&**w),
+                ProverWitness::Owned(w) => Cow::Owned(layout.to_blocks(w, n)),
+                ProverWitness::Converted(..) => unreachable!("the converted witness is buffered before the proof creation"),
+            })
+        };
+        let aux = index.auxiliary.iter().map
+        (
+            |a|
+            {
+                let mut e = (a.witness)(blocks.as_deref().unwrap());
+                e.resize(n, Fr::<G>::zero());
+                e[n-2] = Fr::<G>::rand(rng);
+                e[n-1] = Fr::<G>::rand(rng);
+                Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(e, index.cs.domain.d1).interpolate()
+            }
+        ).collect::<Vec<_>>();
+        if let Some(Cow::Owned(mut b)) = blocks {wipe(&mut b)}
+        let (aux_comm, omega_aux): (Vec<_>, Vec<_>) = aux.iter().map(|a| index.srs.get_ref().commit(a, None, rng)).unzip();
+        aux_comm.iter().for_each(|c| absorb_segments(&mut fq_sponge, &c.unshifted, index.absorb_size));
+
+        // compute, commit to and absorb the multiplicities of the table rows among the looked up wire values
+        let lookup = match &index.cs.lookup
+        {
+            None => None,
+            Some(c) =>
+            {
+                let lw = (0..c.table.len()).map
+                (
+                    |i| index.cs.gate_iter().map(|gate| wire(gate.wires.columns()[i].0)).collect::<Vec<_>>()
+                ).collect::<Vec<_>>();
+                let mut me = index.cs.lookup_multiplicities(&lw.iter().map(|w| &w[..]).collect::<Vec<_>>()).ok_or(ProofError::WitnessCsInconsistent)?;
+                me[n-2] = Fr::<G>::rand(rng);
+                me[n-1] = Fr::<G>::rand(rng);
+                let m = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(me.clone(), index.cs.domain.d1).interpolate();
+                let (m_comm, omega_m) = index.srs.get_ref().commit(&m, None, rng);
+                absorb_segments(&mut fq_sponge, &m_comm.unshifted, index.absorb_size);
+                Some((lw, me, m, m_comm, omega_m))
+            }
+        };
 
         // sample beta, gamma oracles
         oracles.beta = fq_sponge.challenge();
         oracles.gamma = fq_sponge.challenge();
+        if let Some(c) = &index.cs.lookup
+        {
+            oracles.lookup = fq_sponge.challenge();
+            if c.table.len() > 1 {oracles.lookup_combiner = fq_sponge.challenge()}
+        }
 
         // compute permutation polynomial
 
+        // the row factors are computed concurrently with the parallel feature from the wire values
+        // gathered once, the witness source not being shared across the threads
+        #[cfg(feature = "parallel")]
+        let mut columns = (0..COLUMNS*n).map(|i| wire(i)).collect::<Vec<_>>();
+        let shifts = index.cs.shifts();
         let mut z = vec![Fr::<G>::one(); n];
+        #[cfg(feature = "parallel")]
+        {
+            let (cs, columns) = (&index.cs, &columns);
+            z[1..=n-3].par_iter_mut().enumerate().for_each
+            (
+                |(j, z)| *z = (0..COLUMNS).fold
+                (
+                    Fr::<G>::one(),
+                    |acc, c| acc * &(columns[j+c*n] + &(cs.sigmal1[c][j] * &oracles.beta) + &oracles.gamma)
+                )
+            );
+        }
+        #[cfg(not(feature = "parallel"))]
         (0..n-3).for_each
         (
-            |j| z[j+1] =
-                (witness[j] + &(index.cs.sigmal1[0][j] * &oracles.beta) + &oracles.gamma) *&
-                (witness[j+n] + &(index.cs.sigmal1[1][j] * &oracles.beta) + &oracles.gamma) *&
-                (witness[j+2*n] + &(index.cs.sigmal1[2][j] * &oracles.beta) + &oracles.gamma)
+            |j| z[j+1] = (0..COLUMNS).fold
+            (
+                Fr::<G>::one(),
+                |acc, c| acc * &(wire(j+c*n) + &(index.cs.sigmal1[c][j] * &oracles.beta) + &oracles.gamma)
+            )
         );
+        // the denominators are checked before the batch inversion, reporting the wire of the zero one
+        if let Some(j) = (0..n-3).find(|j| z[j+1].is_zero())
+        {
+            let column = (0..COLUMNS).find(|c| (wire(j+c*n) + &(index.cs.sigmal1[*c][j] * &oracles.beta) + &oracles.gamma).is_zero()).unwrap_or(0);
+            #[cfg(feature = "parallel")]
+            wipe(&mut columns);
+            return Err(ProofError::ZeroDenominator {column, row: j})
+        }
         algebra::fields::batch_inversion::<Fr<G>>(&mut z[1..=n-3]);
+        #[cfg(feature = "parallel")]
+        {
+            // the row ratios are computed concurrently, the running product of them sequentially
+            let (cs, columns, shifts) = (&index.cs, &columns, &shifts);
+            z[1..=n-3].par_iter_mut().enumerate().for_each
+            (
+                |(j, z)| *z = (0..COLUMNS).fold
+                (
+                    *z,
+                    |acc, c| acc * &(columns[j+c*n] + &(cs.sid[j] * &oracles.beta * &shifts[c]) + &oracles.gamma)
+                )
+            );
+            (0..n-3).for_each(|j| {let x = z[j]; z[j+1] *= &x});
+        }
+        #[cfg(feature = "parallel")]
+        wipe(&mut columns);
+        #[cfg(not(feature = "parallel"))]
         (0..n-3).for_each
         (
             |j|
             {
-                let x = z[j];
-                z[j+1] *=
-                &(x * &(witness[j] + &(index.cs.sid[j] * &oracles.beta) + &oracles.gamma) *&
-                (witness[j+n] + &(index.cs.sid[j] * &oracles.beta * &index.cs.r) + &oracles.gamma) *&
-                (witness[j+2*n] + &(index.cs.sid[j] * &oracles.beta * &index.cs.o) + &oracles.gamma))
+                let x = (0..COLUMNS).fold
+                (
+                    z[j],
+                    |acc, c| acc * &(wire(j+c*n) + &(index.cs.sid[j] * &oracles.beta * &shifts[c]) + &oracles.gamma)
+                );
+                z[j+1] *= &x
             }
         );
 
         if z[n-3] != Fr::<G>::one() {return Err(ProofError::ProofCreation)};
-        z[n-2] = Fr::<G>::rand(rng);
-        z[n-1] = Fr::<G>::rand(rng);
+
+        // the caller-owned witness is not read past the permutation product
+        if let ProverWitness::Owned(w) = &mut *witness {clear(w)}
+
+        // the permutation polynomial of the test harnesses skips the normalization, the boundary
+        // conditions not being enforced by the prover then
+        #[cfg(feature = "debug")]
+        let unnormalized = match index.z_scale
+        {
+            Some(c) => {z[0..=n-3].iter_mut().for_each(|x| *x *= &c); true}
+            None => false,
+        };
+        #[cfg(not(feature = "debug"))]
+        let unnormalized = false;
+        if index.zk.z
+        {
+            z[n-2] = Fr::<G>::rand(rng);
+            z[n-1] = Fr::<G>::rand(rng);
+        }
         let z = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(z, index.cs.domain.d1).interpolate();
 
-        // commit to z
-        let (z_comm, omega_z) = index.srs.get_ref().commit(&z, None, rng);
+        // commit to z, the commitment being unblinded without the zero-knowledge
+        let unblinded = |comm: PolyComm<G>| {let omega = comm.map(|_| Fr::<G>::zero()); (comm, omega)};
+        let (z_comm, omega_z) = if index.zk.z {index.srs.get_ref().commit(&z, None, rng)}
+            else {unblinded(index.srs.get_ref().commit_non_hiding(&z, None))};
 
-        // absorb the z commitment into the argument and query alpha
-        fq_sponge.absorb_g(&z_comm.unshifted);
+        // compute the lookup running sum and commit to it
+        let lookup_phi = match &lookup
+        {
+            None => None,
+            Some((lw, me, _, _, _)) =>
+            {
+                let mut phi = index.cs.lookup_sum(&lw.iter().map(|w| &w[..]).collect::<Vec<_>>(), me, oracles.lookup, oracles.lookup_combiner)?;
+                if phi[n-3].is_zero() == false {return Err(ProofError::ProofCreation)}
+                phi[n-2] = Fr::<G>::rand(rng);
+                phi[n-1] = Fr::<G>::rand(rng);
+                let phi = Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(phi, index.cs.domain.d1).interpolate();
+                let (phi_comm, omega_phi) = index.srs.get_ref().commit(&phi, None, rng);
+                Some((phi, phi_comm, omega_phi))
+            }
+        };
+        check_deadline()?;
+        phase(Some(ProverPhase::Permutation), Some(ProverPhase::Quotient));
+
+        // absorb the z and the lookup running sum commitments into the argument and query alpha
+        absorb_segments(&mut fq_sponge, &z_comm.unshifted, index.absorb_size);
+        lookup_phi.iter().for_each(|(_, c, _)| absorb_segments(&mut fq_sponge, &c.unshifted, index.absorb_size));
         oracles.alpha_chal = ScalarChallenge(fq_sponge.challenge());
         oracles.alpha = oracles.alpha_chal.to_field(&index.srs.get_ref().endo_r);
         let mut alpha = oracles.alpha;
-        let alpha = (0..17).map(|_| {alpha *= &oracles.alpha; alpha}).collect::<Vec<_>>();
+        let lookup_alpha = if lookup.is_some() {range::LOOKUP} else {0};
+        let alpha = (0..17 + aux.len() + lookup_alpha).map(|_| {alpha *= &oracles.alpha; alpha}).collect::<Vec<_>>();
 
         // evaluate polynomials over domains
         let lagrange = index.cs.evaluate(&l, &r, &o, &z);
 
         // compute quotient polynomial
 
-        // generic constraints contribution
-        let (gen4, genp) = index.cs.gnrc_quot(&lagrange, &p);
+        // auxiliary constraints contribution
+        let aux_quot = |lagrange: &WitnessOverDomains<Fr<G>>| aux.iter().zip(index.auxiliary.iter()).zip(alpha[range::AUX].iter()).fold
+        (
+            Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain(vec![Fr::<G>::zero(); index.cs.domain.d8.size as usize], index.cs.domain.d8),
+            |acc, ((a, auxiliary), alpha)|
+            {
+                let this = a.evaluate_over_domain_by_ref(index.cs.domain.d8);
+                let next = this.shift(8);
+                &acc + &Evaluations::<Fr<G>, D<Fr<G>>>::from_vec_and_domain
+                (
+                    (0..this.evals.len()).map
+                    (
+                        |i| (auxiliary.constraint)(&AuxiliaryPoint
+                        {
+                            l: lagrange.d8.this.l.evals[i],
+                            r: lagrange.d8.this.r.evals[i],
+                            o: lagrange.d8.this.o.evals[i],
+                            aux: this.evals[i],
+                            aux_w: next.evals[i],
+                            zkp: index.cs.zkpl.evals[i],
+                        }) * alpha
+                    ).collect(),
+                    index.cs.domain.d8
+                )
+            }
+        );
+
+        // lookup argument contribution
+        let lookup_quot = |lagrange: &WitnessOverDomains<Fr<G>>| match (&lookup, &lookup_phi)
+        {
+            (Some((_, _, m, _, _)), Some((phi, _, _))) => index.cs.lookup_quot
+                (&[&lagrange.d8.this.l, &lagrange.d8.this.r, &lagrange.d8.this.o], m, phi, oracles.lookup, oracles.lookup_combiner, &alpha[range::AUX][aux.len()..]).ok_or(ProofError::PolyDivisionAt(DivisionSite::LookupBoundary)).map(Some),
+            _ => Ok(None),
+        };
+
+        let (num, t48, genp, posp, lookup_bnd) = if low_mem
+        {
+            // each contribution is accumulated in place as soon as it is computed and freed
+            // right after, the wire evaluations being freed once the last contribution is in
+            let (mut t4, genp) = index.cs.gnrc_quot(&lagrange, &p);
+            let (pos4, mut t8, posp) = index.cs.psdn_quot(&lagrange, &index.cs.fr_sponge_params, &alpha[range::PSDN]);
+            t4 += &pos4;
+            drop(pos4);
+            {
+                let (mul4, mul8) = index.cs.vbmul_quot(&lagrange, &alpha[range::MUL]);
+                t4 += &mul4;
+                t8 += &mul8;
+            }
+            {
+                let (emul4, emul8) = index.cs.endomul_quot(&lagrange, &alpha[range::ENDML]);
+                t4 += &emul4;
+                t8 += &emul8;
+            }
+            t4 += &index.cs.ecad_quot(&lagrange, &alpha[range::ADD]);
+            t8 += &index.cs.perm_quot(&lagrange, &oracles);
+            if aux.len() > 0 {t8 += &aux_quot(&lagrange)}
+            let lookup_bnd = match lookup_quot(&lagrange)?
+            {
+                Some((lkp8, lkpp)) => {t8 += &lkp8; Some(lkpp)}
+                None => None,
+            };
+            drop(lagrange);
+
+            // the contributions are interpolated in place, the evaluations not kept for the diagnostic
+            let mut num = t8.interpolate();
+            num += &t4.interpolate();
+            num += &genp;
+            num += &posp;
+            (num, None, genp, posp, lookup_bnd)
+        }
+        else
+        {
+            // the contributions are computed concurrently with the parallel feature
+            #[cfg(feature = "parallel")]
+            let (((gen4, genp), (pos4, pos8, posp)), (((mul4, mul8), (emul4, emul8)), (eca, perm))) =
+            {
+                let (cs, lagrange, oracles, alpha) = (&index.cs, &lagrange, &oracles, &alpha);
+                rayon::join
+                (
+                    || rayon::join(|| cs.gnrc_quot(lagrange, &p), || cs.psdn_quot(lagrange, &cs.fr_sponge_params, &alpha[range::PSDN])),
+                    || rayon::join
+                    (
+                        || rayon::join(|| cs.vbmul_quot(lagrange, &alpha[range::MUL]), || cs.endomul_quot(lagrange, &alpha[range::ENDML])),
+                        || rayon::join(|| cs.ecad_quot(lagrange, &alpha[range::ADD]), || cs.perm_quot(lagrange, oracles)),
+                    ),
+                )
+            };
 
-        // poseidon constraints contribution
-        let (pos4, pos8, posp) = index.cs.psdn_quot(&lagrange, &index.cs.fr_sponge_params, &alpha[range::PSDN]);
+            // generic constraints contribution
+            #[cfg(not(feature = "parallel"))]
+            let (gen4, genp) = index.cs.gnrc_quot(&lagrange, &p);
 
-        // variable base scalar multiplication constraints contribution
-        let (mul4, mul8) = index.cs.vbmul_quot(&lagrange, &alpha[range::MUL]);
+            // poseidon constraints contribution
+            #[cfg(not(feature = "parallel"))]
+            let (pos4, pos8, posp) = index.cs.psdn_quot(&lagrange, &index.cs.fr_sponge_params, &alpha[range::PSDN]);
 
-        // group endomorphism optimised variable base scalar multiplication constraints contribution
-        let (emul4, emul8) = index.cs.endomul_quot(&lagrange, &alpha[range::ENDML]);
+            // variable base scalar multiplication constraints contribution
+            #[cfg(not(feature = "parallel"))]
+            let (mul4, mul8) = index.cs.vbmul_quot(&lagrange, &alpha[range::MUL]);
 
-        // EC addition constraints contribution
-        let eca = index.cs.ecad_quot(&lagrange, &alpha[range::ADD]);
+            // group endomorphism optimised variable base scalar multiplication constraints contribution
+            #[cfg(not(feature = "parallel"))]
+            let (emul4, emul8) = index.cs.endomul_quot(&lagrange, &alpha[range::ENDML]);
 
-        // permutation check contribution
-        let perm = index.cs.perm_quot(&lagrange, &oracles);
+            // EC addition constraints contribution
+            #[cfg(not(feature = "parallel"))]
+            let eca = index.cs.ecad_quot(&lagrange, &alpha[range::ADD]);
 
-        // collect contribution evaluations
-        let t4 = &(&gen4 + &pos4) + &(&eca + &(&mul4 + &emul4));
-        let t8 = &(&pos8 + &(&mul8 + &emul8)) + &perm;
+            // permutation check contribution
+            #[cfg(not(feature = "parallel"))]
+            let perm = index.cs.perm_quot(&lagrange, &oracles);
+
+            let aux8 = aux_quot(&lagrange);
+            let lookup_quot = lookup_quot(&lagrange)?;
+
+            // collect contribution evaluations
+            let t4 = &(&gen4 + &pos4) + &(&eca + &(&mul4 + &emul4));
+            let mut t8 = &(&(&pos8 + &(&mul8 + &emul8)) + &perm) + &aux8;
+            if let Some((lkp8, _)) = &lookup_quot {t8 = &t8 + lkp8}
+
+            let num = &(&t4.interpolate_by_ref() + &t8.interpolate_by_ref()) + &(&genp + &posp);
+            (num, Some((t4, t8)), genp, posp, lookup_quot.map(|(_, lkpp)| lkpp))
+        };
 
         // divide contributions with vanishing polynomial
-        let (mut t, res) = (&(&t4.interpolate() + &t8.interpolate()) + &(&genp + &posp)).
-            divide_by_vanishing_poly(index.cs.domain.d1).map_or(Err(ProofError::PolyDivision), |s| Ok(s))?;
-        if res.is_zero() == false {return Err(ProofError::PolyDivision)}
+        let (mut t, res) = num.divide_by_vanishing_poly(index.cs.domain.d1).map_or(Err(ProofError::PolyDivisionAt(DivisionSite::Vanishing)), |s| Ok(s))?;
+        if res.is_zero() == false
+        {
+            // the rows of the nonzero contributions over the domain are the violated constraint positions,
+            // evaluated from the contributions polynomial in the low memory mode
+            if let Some(violated) = violated
+            {
+                violated.extend(index.cs.domain.d1.elements().enumerate().filter
+                (
+                    |(i, x)| !match &t48
+                    {
+                        Some((t4, t8)) => t4.evals[4*i] + &t8.evals[8*i] + &genp.evaluate(*x) + &posp.evaluate(*x),
+                        None => num.evaluate(*x),
+                    }.is_zero()
+                ).map(|(i, _)| i));
+            }
+            return Err(ProofError::PolyDivisionAt(DivisionSite::Vanishing))
+        }
+        drop(t48);
 
         // permutation boundary condition check contribution
         let (bnd1, res) =
             DenseOrSparsePolynomial::divide_with_q_and_r(&(&z - &DensePolynomial::from_coefficients_slice(&[Fr::<G>::one()])).into(),
                 &DensePolynomial::from_coefficients_slice(&[-Fr::<G>::one(), Fr::<G>::one()]).into()).
-                map_or(Err(ProofError::PolyDivision), |s| Ok(s))?;
-        if res.is_zero() == false {return Err(ProofError::PolyDivision)}
+                map_or(Err(ProofError::PolyDivisionAt(DivisionSite::PermutationBoundary)), |s| Ok(s))?;
+        if res.is_zero() == false && !unnormalized {return Err(ProofError::PolyDivisionAt(DivisionSite::PermutationBoundary))}
 
         let (bnd2, res) =
             DenseOrSparsePolynomial::divide_with_q_and_r(&(&z - &DensePolynomial::from_coefficients_slice(&[Fr::<G>::one()])).into(),
                 &DensePolynomial::from_coefficients_slice(&[-index.cs.sid[n-3], Fr::<G>::one()]).into()).
-                map_or(Err(ProofError::PolyDivision), |s| Ok(s))?;
-        if res.is_zero() == false {return Err(ProofError::PolyDivision)}
+                map_or(Err(ProofError::PolyDivisionAt(DivisionSite::PermutationBoundary)), |s| Ok(s))?;
+        if res.is_zero() == false && !unnormalized {return Err(ProofError::PolyDivisionAt(DivisionSite::PermutationBoundary))}
 
         t += &(&bnd1.scale(alpha[3]) + &bnd2.scale(alpha[4]));
+        if let Some(lkpp) = &lookup_bnd {t += lkpp}
         t.coeffs.resize(index.max_quot_size, Fr::<G>::zero());
 
         // commit to t
-        let (t_comm, omega_t) = index.srs.get_ref().commit(&t, Some(index.max_quot_size), rng);
+        let (t_comm, omega_t) = if index.zk.t {index.srs.get_ref().commit(&t, Some(index.max_quot_size), rng)}
+            else {unblinded(index.srs.get_ref().commit_non_hiding(&t, Some(index.max_quot_size)))};
+        check_deadline()?;
+        phase(Some(ProverPhase::Quotient), Some(ProverPhase::Evaluations));
 
         // absorb the polycommitments into the argument and sample zeta
         let max_t_size = (index.max_quot_size + index.max_poly_size - 1) / index.max_poly_size;
         let dummy = G::of_coordinates(Fq::<G>::zero(), Fq::<G>::zero());
-        fq_sponge.absorb_g(&t_comm.unshifted);
-        fq_sponge.absorb_g(&vec![dummy; max_t_size - t_comm.unshifted.len()]);
-        {
-            let s = t_comm.shifted.unwrap();
-            if s.is_zero() {
-                fq_sponge.absorb_g(&[dummy])
-            } else {
-                fq_sponge.absorb_g(&[s])
-            }
-        };
+        absorb_segments(&mut fq_sponge, &t_comm.unshifted, Some(index.absorb_size.unwrap_or(max_t_size)));
+        // the shifted segment, missing for the bound of a multiple of the segment size, is absorbed
+        // as the dummy point when missing or zero
+        match t_comm.shifted
+        {
+            Some(s) if !s.is_zero() => fq_sponge.absorb_g(&[s]),
+            _ => fq_sponge.absorb_g(&[dummy]),
+        }
 
         oracles.zeta_chal = ScalarChallenge(fq_sponge.challenge());
         oracles.zeta = oracles.zeta_chal.to_field(&index.srs.get_ref().endo_r);
 
         // evaluate the polynomials
 
-        let evlp = [oracles.zeta, oracles.zeta * &index.cs.domain.d1.group_gen];
-        let evals = evlp.iter().map
+        let evlp = evaluation_points(index.cs.domain.d1, &index.rotations, oracles.zeta).ok_or(ProofError::EvaluationRotations)?;
+
+        // the polynomials are evaluated at all of the points concurrently with the parallel feature,
+        // the values being collected in the evaluation structure field order
+        let max_poly_size = index.max_poly_size;
+        let evaluated = [&l, &r, &o, &z, &t].iter().cloned().chain(index.cs.sigmam[..COLUMNS-1].iter()).collect::<Vec<_>>();
+        let points = evlp.iter().flat_map(|e| evaluated.iter().map(move |p| (*e, *p))).collect::<Vec<_>>();
+        #[cfg(feature = "parallel")]
+        let values = points.par_iter().map(|(e, p)| p.eval(*e, max_poly_size)).collect::<Vec<_>>();
+        #[cfg(not(feature = "parallel"))]
+        let values = points.iter().map(|(e, p)| p.eval(*e, max_poly_size)).collect::<Vec<_>>();
+        let mut evals = values.chunks(evaluated.len()).map
         (
-            |e| ProofEvaluations::<Vec<Fr<G>>>
+            |v| ProofEvaluations::<Vec<Fr<G>>>
             {
-                l : l.eval(*e, index.max_poly_size),
-                r : r.eval(*e, index.max_poly_size),
-                o : o.eval(*e, index.max_poly_size),
-                z : z.eval(*e, index.max_poly_size),
-                t : t.eval(*e, index.max_poly_size),
+                l : v[0].clone(),
+                r : v[1].clone(),
+                o : v[2].clone(),
+                z : v[3].clone(),
+                t : v[4].clone(),
 
-                sigma1: index.cs.sigmam[0].eval(*e, index.max_poly_size),
-                sigma2: index.cs.sigmam[1].eval(*e, index.max_poly_size),
+                sigma1: v[5].clone(),
+                sigma2: v[6].clone(),
 
                 f: Vec::new(),
             }
         ).collect::<Vec<_>>();
-        let mut evals = [evals[0].clone(), evals[1].clone()];
+        let aux_evals = evlp.iter().map(|e| aux.iter().map(|a| a.eval(*e, index.max_poly_size)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let lookup_polys = match (&lookup, &lookup_phi, &index.cs.lookup, &index.commitments.lookup_comm)
+        {
+            (Some((_, _, m, _, omega_m)), Some((phi, _, omega_phi)), Some(c), Some(comm)) =>
+            {
+                // the selector and table column commitments are non-hiding, of a zero blinder per segment
+                let mut polys = vec![(m, omega_m.clone()), (phi, omega_phi.clone())];
+                polys.extend(std::iter::once(&c.lkpm).chain(c.tablem.iter()).zip(comm.iter()).
+                    map(|(p, comm)| (p, PolyComm {unshifted: vec![Fr::<G>::zero(); comm.unshifted.len()], shifted: None})));
+                polys
+            }
+            _ => Vec::new(),
+        };
+        let lookup_evals = evlp.iter().map(|e| lookup_polys.iter().map(|(p, _)| p.eval(*e, index.max_poly_size)).collect::<Vec<_>>()).collect::<Vec<_>>();
 
-        let evlp1 = [evlp[0].pow(&[index.max_poly_size as u64]), evlp[1].pow(&[index.max_poly_size as u64])];
+        let evlp1 = evlp.iter().map(|e| e.pow(&[index.max_poly_size as u64])).collect::<Vec<_>>();
         let e = &evals.iter().zip(evlp1.iter()).map
         (
             |(es, &e1)| ProofEvaluations::<Fr<G>>
@@ -303,8 +1297,7 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
             &index.cs.endomul_lnrz(&e, &alpha[range::ENDML])) +
             &index.cs.perm_lnrz(&e, &z, &oracles, &alpha[range::PERM]);
 
-        evals[0].f = f.eval(evlp[0], index.max_poly_size);
-        evals[1].f = f.eval(evlp[1], index.max_poly_size);
+        evals.iter_mut().zip(evlp.iter()).for_each(|(es, e)| es.f = f.eval(*e, index.max_poly_size));
 
         let fq_sponge_before_evaluations = fq_sponge.clone();
         let mut fr_sponge =
@@ -313,9 +1306,25 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
             s.absorb(&fq_sponge.digest());
             s
         };
-        let p_eval = if p.is_zero() {[Vec::new(), Vec::new()]}
-            else {[vec![p.evaluate(evlp[0])], vec![p.evaluate(evlp[1])]]};
-        for i in 0..2 {fr_sponge.absorb_evaluations(&p_eval[i], &evals[i])}
+        // the zero public input polynomial has no evaluations
+        let p_eval = evlp.iter().map(|e| if p.is_zero() {Vec::new()} else {vec![p.evaluate(*e)]}).collect::<Vec<_>>();
+        // the evaluations are bound to v, u either directly or through their digest
+        let absorb = |s: &mut EFrSponge|
+        {
+            p_eval.iter().zip(evals.iter()).for_each(|(p, e)| s.absorb_evaluations(p, e));
+            aux_evals.iter().flatten().flatten().for_each(|x| s.absorb(x));
+            lookup_evals.iter().flatten().flatten().for_each(|x| s.absorb(x));
+        };
+        match index.opening_order
+        {
+            OpeningOrder::AfterEvaluations => absorb(&mut fr_sponge),
+            OpeningOrder::DigestedEvaluations =>
+            {
+                let mut s = EFrSponge::new(index.cs.fr_sponge_params.clone());
+                absorb(&mut s);
+                fr_sponge.absorb(&s.digest());
+            }
+        }
 
         // query opening scaler challenges
         oracles.v_chal = fr_sponge.challenge();
@@ -323,6 +1332,9 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
         oracles.u_chal = fr_sponge.challenge();
         oracles.u = oracles.u_chal.to_field(&index.srs.get_ref().endo_r);
 
+        check_deadline()?;
+        phase(Some(ProverPhase::Evaluations), Some(ProverPhase::Opening));
+
         // construct the proof
         // --------------------------------------------------------------------
         let polys = prev_challenges.iter().map(|(chals, comm)| {
@@ -339,12 +1351,12 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
         // perm_scalars[0] * the coefficient in the z commitment.
         let omega_f = {
             let zkp = index.cs.zkpm.evaluate(oracles.zeta);
-            let evals = (0..2).map(|i| evals[i].combine(evlp[i])).collect::<Vec<_>>();
+            let evals = evals.iter().zip(evlp.iter()).map(|(e, pt)| e.combine(*pt)).collect::<Vec<_>>();
             let perm_scalar0 = ConstraintSystem::perm_scalars
             (
                 &evals,
                 &oracles,
-                (index.cs.r, index.cs.o),
+                &index.cs.shifts(),
                 &alpha[range::PERM],
                 n as u64,
                 zkp,
@@ -355,6 +1367,8 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
             omega_z.map(|x| perm_scalar0 * x)
         };
 
+        let omega = [omega_l.clone(), omega_r.clone(), omega_o.clone(), omega_z.clone(), omega_t.clone()];
+
         let mut polynoms = polys.iter().map(|(p, n)| (p, None, non_hiding(*n) )).collect::<Vec<_>>();
         polynoms.extend(
             vec!
@@ -365,10 +1379,27 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
                 (&o, None, omega_o),
                 (&z, None, omega_z),
                 (&f, None, omega_f),
-                (&index.cs.sigmam[0], None, non_hiding(1)),
-                (&index.cs.sigmam[1], None, non_hiding(1)),
-                (&t, Some(index.max_quot_size), omega_t),
             ]);
+        polynoms.extend(aux.iter().zip(omega_aux.into_iter()).map(|(a, omega)| (a, None, omega)));
+        polynoms.extend(lookup_polys.iter().map(|(p, omega)| (*p, None, omega.clone())));
+
+        // the index polynomials are opened either together with or separately from the witness ones
+        let mut index_polynoms = index.cs.sigmam[..COLUMNS-1].iter().map(|s| (s, None, non_hiding(1))).collect::<Vec<_>>();
+        let index_proof = match index.opening_groups
+        {
+            OpeningGroups::Single => {polynoms.append(&mut index_polynoms); None},
+            OpeningGroups::Split => Some(index.srs.get_ref().open
+            (
+                group_map,
+                index_polynoms,
+                &evlp.to_vec(),
+                oracles.v,
+                oracles.u,
+                fq_sponge_before_evaluations.clone(),
+                rng
+            )),
+        };
+        polynoms.push((&t, Some(index.max_quot_size), omega_t));
 
         let proof =
             Self
@@ -379,6 +1410,8 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
                     o_comm,
                     z_comm,
                     t_comm,
+                    aux_comm,
+                    lookup_comm: lookup.iter().map(|(_, _, _, c, _)| c.clone()).chain(lookup_phi.iter().map(|(_, c, _)| c.clone())).collect(),
                 },
                 proof: index.srs.get_ref().open
                 (
@@ -387,14 +1420,37 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField,
                     &evlp.to_vec(),
                     oracles.v,
                     oracles.u,
-                    fq_sponge_before_evaluations,
+                    fq_sponge_before_evaluations.clone(),
                     rng
                 ),
+                index_proof,
                 evals,
+                aux_evals,
+                lookup_evals,
                 public,
                 prev_challenges,
+                witness_checksum,
+                nonce,
             };
 
-        Ok(proof)
+        phase(Some(ProverPhase::Opening), None);
+
+        // zero the secret values not returned
+        let mut aux = aux;
+        aux.iter_mut().for_each(|a| wipe_poly(a));
+        if let Some((mut lw, mut me, mut m, _, mut omega_m)) = lookup
+        {
+            lw.iter_mut().for_each(|w| wipe(w));
+            wipe(&mut me);
+            wipe_poly(&mut m);
+            wipe_blinder(&mut omega_m);
+        }
+        if let Some((mut phi, _, mut omega_phi)) = lookup_phi
+        {
+            wipe_poly(&mut phi);
+            wipe_blinder(&mut omega_phi);
+        }
+
+        Ok((proof, ProverPolys {l, r, o, z, t, p, omega, oracles, fq_sponge: fq_sponge_before_evaluations}))
     }
 }
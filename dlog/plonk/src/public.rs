@@ -0,0 +1,72 @@
+/*****************************************************************************************************************
+
+This source file implements the commitments to the public input of the Plonk proofs.
+
+The public input commitment is either
+
+    Poseidon: Poseidon hash of the public input length followed by the public input elements
+        under the index scalar field sponge parameters
+    Pedersen: commitment to the public input values over the Lagrange base commitments
+
+so that the verifier storing only the commitment can bind the proof to the intended public input.
+
+*****************************************************************************************************************/
+
+use algebra::AffineCurve;
+use commitment_dlog::commitment::{CommitmentCurve, PolyComm};
+use oracle::poseidon::{ArithmeticSponge, ArithmeticSpongeParams, PlonkSpongeConstants, Sponge};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+
+#[derive(Clone, Debug)]
+pub enum PublicCommitment<G: AffineCurve>
+{
+    Poseidon(Fr<G>),
+    Pedersen(PolyComm<G>),
+}
+
+impl<G: CommitmentCurve> PublicCommitment<G>
+{
+    // This function computes the Poseidon public input commitment
+    //     params: scalar field sponge parameters
+    //     public: public input
+    pub fn poseidon(params: &ArithmeticSpongeParams<Fr<G>>, public: &[Fr<G>]) -> Self
+    {
+        let mut sponge = ArithmeticSponge::<Fr<G>, PlonkSpongeConstants>::new();
+        sponge.absorb(params, &[Fr::<G>::from(public.len() as u64)]);
+        sponge.absorb(params, public);
+        PublicCommitment::Poseidon(sponge.squeeze(params))
+    }
+
+    // This function computes the Pedersen public input commitment
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     public: public input
+    pub fn pedersen(lgr_comm: &Vec<PolyComm<G>>, public: &[Fr<G>]) -> Self
+    {
+        PublicCommitment::Pedersen(PolyComm::<G>::multi_scalar_mul
+            (&lgr_comm.iter().take(public.len()).collect(), &public.to_vec()))
+    }
+
+    // This function checks the commitment against the public input
+    //     params: scalar field sponge parameters
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     public: public input
+    pub fn matches(&self, params: &ArithmeticSpongeParams<Fr<G>>, lgr_comm: &Vec<PolyComm<G>>, public: &[Fr<G>]) -> bool
+    {
+        match (self, Self::compute(self, params, lgr_comm, public))
+        {
+            (PublicCommitment::Poseidon(a), PublicCommitment::Poseidon(b)) => *a == b,
+            (PublicCommitment::Pedersen(a), PublicCommitment::Pedersen(b)) => a.unshifted == b.unshifted && a.shifted == b.shifted,
+            _ => false,
+        }
+    }
+
+    fn compute(&self, params: &ArithmeticSpongeParams<Fr<G>>, lgr_comm: &Vec<PolyComm<G>>, public: &[Fr<G>]) -> Self
+    {
+        match self
+        {
+            PublicCommitment::Poseidon(_) => Self::poseidon(params, public),
+            PublicCommitment::Pedersen(_) => Self::pedersen(lgr_comm, public),
+        }
+    }
+}
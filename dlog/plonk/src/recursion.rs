@@ -0,0 +1,141 @@
+/*****************************************************************************************************************
+
+This source file implements the deferred values of the zk-proof verification for the recursive composition
+of the proofs over a cycle of curves.
+
+The verifier circuit of the proof runs over the base field of the proof curve, where the group operations of the
+verification are native. The scalar field computations of the verification, namely the Fiat-Shamir challenges,
+the combined inner product of the evaluations and the combined evaluation of the opening challenge polynomial
+at the evaluation points, are deferred to the verifier circuit over the other curve of the cycle and passed to
+the circuits as their public input. The opening challenges together with the challenge polynomial commitment sg
+are the previous challenges of the next proof of the recursion, which accumulates the check of sg.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField, Zero, One};
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, b_poly, combined_inner_product, shift_scalar};
+use oracle::{FqSponge, rndoracle::ProofError, sponge::{ScalarChallenge, CHALLENGE_LENGTH_IN_LIMBS}};
+use plonk_circuits::scalars::RandomOracles;
+use crate::{prover::ProverProof, index::VerifierIndex as Index, plonk_sponge::FrSponge};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+#[derive(Clone, Debug)]
+pub struct DeferredValues<G: AffineCurve>
+{
+    pub oracles: RandomOracles<Fr<G>>,              // Fiat-Shamir challenges of the proof
+    pub combined_inner_product: Fr<G>,              // combined inner product of the evaluations
+    pub b: Fr<G>,                                   // combined challenge polynomial evaluation at the evaluation points
+    pub prechallenges: Vec<ScalarChallenge<Fr<G>>>, // opening proof endoscalar challenges
+    pub challenges: Vec<Fr<G>>,                     // opening proof challenges
+    pub sg: G,                                      // challenge polynomial commitment
+}
+
+impl<G: CommitmentCurve> DeferredValues<G> where Fr<G>: PrimeField, Fq<G>: PrimeField
+{
+    // This function returns the opening challenges and the challenge polynomial commitment
+    // as the previous challenges of the next proof of the recursion
+    pub fn prev_challenges(&self) -> (Vec<Fr<G>>, PolyComm<G>)
+    {
+        (self.challenges.clone(), PolyComm {unshifted: vec![self.sg], shifted: None})
+    }
+
+    // This function lays out the deferred values as the public input of the verifier circuit over the base
+    // field. The challenges beta, gamma, alpha, zeta, v, u are laid out in the transcript order, followed by
+    // the combined inner product, the combined challenge polynomial evaluation, the opening endoscalar
+    // challenges and the coordinates of sg. The challenges take CHALLENGE_LENGTH_IN_LIMBS 64-bit limbs each,
+    // the scalar field elements take all of their 64-bit limbs, lower limb first
+    //     RETURN: base field public input of the verifier circuit
+    pub fn to_public_input(&self) -> Vec<Fq<G>>
+    {
+        let limbs = |x: &Fr<G>, length: usize| x.into_repr().as_ref()[0..length].iter().map(|l| Fq::<G>::from(*l)).collect::<Vec<_>>();
+        let size = Fr::<G>::zero().into_repr().as_ref().len();
+
+        let mut input = [self.oracles.beta, self.oracles.gamma, self.oracles.alpha_chal.0, self.oracles.zeta_chal.0,
+            self.oracles.v_chal.0, self.oracles.u_chal.0].iter().flat_map(|chal| limbs(chal, CHALLENGE_LENGTH_IN_LIMBS)).collect::<Vec<_>>();
+        input.extend(limbs(&self.combined_inner_product, size));
+        input.extend(limbs(&self.b, size));
+        input.extend(self.prechallenges.iter().flat_map(|chal| limbs(&chal.0, CHALLENGE_LENGTH_IN_LIMBS)));
+        let (x, y) = self.sg.to_coordinates().unwrap_or((Fq::<G>::zero(), Fq::<G>::zero()));
+        input.extend(vec![x, y]);
+        input
+    }
+}
+
+impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
+{
+    // This function computes the deferred values of the zk-proof verification, running the transcript
+    // of the verifier through the opening proof challenges
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: deferred values of the verification, proof structure error for the proof malformed
+    //         against the index, the structure being checked before the transcript is run
+    pub fn deferred_values
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<DeferredValues<G>, ProofError>
+    where Fr<G>: PrimeField
+    {
+        // the transcript is run once for both the oracles and the opening parts
+        let (parts, oracles) = self.opening_parts_with_oracles::<EFqSponge, EFrSponge>(index, lgr_comm)?;
+        let srs = index.srs.get_ref();
+
+        // combine the evaluations as the opening proof verification does
+        let es = parts.commitments.iter().map
+        (
+            |(comm, evals, bound)|
+            (
+                evals.iter().collect::<Vec<_>>(),
+                bound.filter(|_| comm.shifted.map_or(false, |s| !s.is_zero())),
+            )
+        ).collect::<Vec<_>>();
+        let combined_inner_product = combined_inner_product::<G>(&parts.points, &parts.v, &parts.u, &es, srs.g.len());
+
+        let mut sponge = parts.sponge;
+        sponge.absorb_fr(&[shift_scalar(combined_inner_product)]);
+        let prechallenges = self.proof.prechallenges(&mut sponge);
+        let challenges = prechallenges.iter().map(|chal| chal.to_field(&srs.endo_r)).collect::<Vec<_>>();
+
+        let mut scale = Fr::<G>::one();
+        let b = parts.points.iter().fold
+        (
+            Fr::<G>::zero(),
+            |acc, e| {let term = scale * &b_poly(&challenges, *e); scale *= &parts.u; acc + &term}
+        );
+
+        Ok(DeferredValues
+        {
+            oracles,
+            combined_inner_product,
+            b,
+            prechallenges,
+            challenges,
+            sg: self.proof.sg,
+        })
+    }
+
+    // This function lays out the deferred values of the zk-proof verification as the public
+    // input of the verifier circuit over the base field
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: base field public input of the verifier circuit
+    pub fn to_public_input
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<Vec<Fq<G>>, ProofError>
+    where Fr<G>: PrimeField, Fq<G>: PrimeField
+    {
+        Ok(self.deferred_values::<EFqSponge, EFrSponge>(index, lgr_comm)?.to_public_input())
+    }
+}
@@ -0,0 +1,88 @@
+/*****************************************************************************************************************
+
+This source file implements the public input schema of the Plonk proofs.
+
+The schema describes the structure of the public input vector as the sequence of fields, each field
+being one of:
+
+    version: single element that has to be equal to the schema version
+    scalar: single element of any value
+    data: length element followed by as many data elements, the length being bounded by the maximal one
+
+The public input conforms to the schema if its fields exactly cover the public input vector.
+
+*****************************************************************************************************************/
+
+use algebra::PrimeField;
+use oracle::rndoracle::ProofError;
+
+// public input field descriptor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SchemaField<F>
+{
+    Version(F),     // version element
+    Scalar,         // single element
+    Data(usize),    // length prefixed data of the maximal length
+}
+
+// decoded public input field
+#[derive(Clone, Debug, PartialEq)]
+pub enum PublicInputValue<F>
+{
+    Version(F),
+    Scalar(F),
+    Data(Vec<F>),
+}
+
+#[derive(Clone, Debug)]
+pub struct PublicInputSchema<F>
+{
+    pub fields: Vec<SchemaField<F>>,
+}
+
+impl<F: PrimeField> PublicInputSchema<F>
+{
+    pub fn create(fields: Vec<SchemaField<F>>) -> Self
+    {
+        PublicInputSchema {fields}
+    }
+
+    // This function decodes the public input according to the schema
+    //     public: public input vector
+    //     RETURN: decoded public input fields
+    pub fn decode(&self, public: &[F]) -> Result<Vec<PublicInputValue<F>>, ProofError>
+    {
+        let mut values = Vec::new();
+        let mut i = 0;
+        for field in self.fields.iter()
+        {
+            if i >= public.len() {return Err(ProofError::PublicInputSchema)}
+            match field
+            {
+                SchemaField::Version(v) =>
+                {
+                    if public[i] != *v {return Err(ProofError::PublicInputSchema)}
+                    values.push(PublicInputValue::Version(public[i]));
+                    i += 1;
+                }
+                SchemaField::Scalar =>
+                {
+                    values.push(PublicInputValue::Scalar(public[i]));
+                    i += 1;
+                }
+                SchemaField::Data(max) =>
+                {
+                    let len = public[i].into_repr();
+                    if len.as_ref()[1..].iter().any(|l| *l != 0) || len.as_ref()[0] > *max as u64 {return Err(ProofError::PublicInputSchema)}
+                    let len = len.as_ref()[0] as usize;
+                    i += 1;
+                    if i + len > public.len() {return Err(ProofError::PublicInputSchema)}
+                    values.push(PublicInputValue::Data(public[i..i+len].to_vec()));
+                    i += len;
+                }
+            }
+        }
+        if i != public.len() {return Err(ProofError::PublicInputSchema)}
+        Ok(values)
+    }
+}
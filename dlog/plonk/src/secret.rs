@@ -0,0 +1,55 @@
+/*****************************************************************************************************************
+
+This source file implements the zeroizing of the secret values of the prover.
+
+With the zeroize feature, the following values are zeroed once the proof is created:
+
+    wire, permutation and quotient polynomials and their commitment blinders
+    auxiliary witness polynomials and their commitment blinders
+    lookup multiplicities, running sum and looked up wire values
+    wire blinding polynomials
+    copies of the witness converted to the block layout
+
+The proof, that is its public input, commitments and evaluations, is meant to be shared and is not zeroed.
+Without the zeroize feature the zeroizing is a no-op, apart from the caller-owned ProverWitness::Owned witness
+that is cleared either way.
+
+*****************************************************************************************************************/
+
+use algebra::Field;
+use commitment_dlog::commitment::PolyComm;
+use ff_fft::DensePolynomial;
+
+// This function zeroes the field elements
+pub(crate) fn wipe<F: Field>(elems: &mut [F])
+{
+    #[cfg(feature = "zeroize")]
+    {
+        use zeroize::Zeroize;
+        // the zero field element is represented by the zero bytes
+        unsafe {std::slice::from_raw_parts_mut(elems.as_mut_ptr() as *mut u8, elems.len() * std::mem::size_of::<F>())}.zeroize();
+    }
+    #[cfg(not(feature = "zeroize"))]
+    let _ = elems;
+}
+
+// This function zeroes the field elements, with the plain assignment without the zeroize feature
+pub(crate) fn clear<F: Field>(elems: &mut [F])
+{
+    wipe(elems);
+    #[cfg(not(feature = "zeroize"))]
+    elems.iter_mut().for_each(|x| *x = F::zero());
+}
+
+// This function zeroes the polynomial coefficients
+pub(crate) fn wipe_poly<F: Field>(p: &mut DensePolynomial<F>)
+{
+    wipe(&mut p.coeffs)
+}
+
+// This function zeroes the commitment blinder
+pub(crate) fn wipe_blinder<F: Field>(omega: &mut PolyComm<F>)
+{
+    wipe(&mut omega.unshifted);
+    omega.shifted.iter_mut().for_each(|s| wipe(std::slice::from_mut(s)));
+}
@@ -0,0 +1,195 @@
+/*****************************************************************************************************************
+
+This source file implements the selective disclosure proof of the Plonk polynomials.
+
+The selective proof carries all the prover's polynomial commitments, so that the evaluation point zeta
+is bound by the complete transcript, while only the evaluations at zeta of the requested polynomials
+are included and opened. The selective proof does not prove the circuit satisfiability.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, PrimeField};
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof};
+use oracle::{FqSponge, rndoracle::ProofError};
+use groupmap::GroupMap;
+use crate::plonk_sponge::FrSponge;
+use crate::prover::{ProverProof, ProverCommitments};
+use crate::index::VerifierIndex;
+use rand::thread_rng;
+#[cfg(feature = "prover")]
+use oracle::utils::PolyUtils;
+#[cfg(feature = "prover")]
+use crate::{prover::{ProverWitness, ProverConfig}, index::Index};
+#[cfg(feature = "prover")]
+use rand_core::{RngCore, CryptoRng};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+// identifiers of the committed polynomials
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PolyId
+{
+    L,  // left wire polynomial
+    R,  // right wire polynomial
+    O,  // output wire polynomial
+    Z,  // permutation polynomial
+    T,  // quotient polynomial
+}
+
+#[derive(Clone)]
+pub struct SelectiveProof<G: AffineCurve>
+{
+    // polynomial commitments
+    pub commitments: ProverCommitments<G>,
+
+    // revealed polynomials along with their evaluations at zeta
+    pub evals: Vec<(PolyId, Vec<Fr<G>>)>,
+
+    // batched commitment opening proof of the revealed polynomials
+    pub proof: OpeningProof<G>,
+
+    // public part of the witness
+    pub public: Vec<Fr<G>>,
+}
+
+impl<G: CommitmentCurve> SelectiveProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function constructs the selective disclosure proof from the witness & the Index against SRS instance
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: computation witness
+    //     index: Index
+    //     reveal: polynomials to be revealed at zeta
+    //     RETURN: selective disclosure proof
+    #[cfg(feature = "prover")]
+    pub fn create_selective
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: &Vec::<Fr<G>>,
+        index: &Index<G>,
+        reveal: &[PolyId],
+    )
+    -> Result<Self, ProofError>
+    {
+        Self::create_selective_with_rng::<EFqSponge, EFrSponge, _, _>(group_map, witness, index, reveal, &mut thread_rng())
+    }
+
+    // This function constructs the selective disclosure proof with the commitment blinding and the opening
+    // masks sampled from the supplied RNG, so that a seeded RNG reproduces the proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: computation witness
+    //     index: Index
+    //     reveal: polynomials to be revealed at zeta
+    //     rng: randomness source of the proof
+    //     RETURN: selective disclosure proof
+    #[cfg(feature = "prover")]
+    pub fn create_selective_with_rng
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         R: RngCore + CryptoRng,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: &Vec::<Fr<G>>,
+        index: &Index<G>,
+        reveal: &[PolyId],
+        rng: &mut R,
+    )
+    -> Result<Self, ProofError>
+    {
+        let (proof, polys) = ProverProof::prove::<EFqSponge, EFrSponge, _>
+            (group_map, ProverWitness::Field(witness), index, vec![], ProverConfig::new().with_rng(&mut *rng))?;
+
+        let polynoms = reveal.iter().map
+        (
+            |id| match id
+            {
+                PolyId::L => (&polys.l, None, polys.omega[0].clone()),
+                PolyId::R => (&polys.r, None, polys.omega[1].clone()),
+                PolyId::O => (&polys.o, None, polys.omega[2].clone()),
+                PolyId::Z => (&polys.z, None, polys.omega[3].clone()),
+                PolyId::T => (&polys.t, Some(index.max_quot_size), polys.omega[4].clone()),
+            }
+        ).collect::<Vec<_>>();
+        let evals = reveal.iter().zip(polynoms.iter()).map(|(id, (p, _, _))| (*id, p.eval(polys.oracles.zeta, index.max_poly_size))).collect::<Vec<_>>();
+
+        // query opening scaler challenges
+        let (v, u) = Self::opening_oracles::<EFqSponge, EFrSponge>(&polys.fq_sponge, &index.cs.fr_sponge_params, &evals, &index.srs.get_ref().endo_r);
+
+        Ok(SelectiveProof
+        {
+            proof: index.srs.get_ref().open(group_map, polynoms, &vec![polys.oracles.zeta], v, u, polys.fq_sponge.clone(), rng),
+            commitments: proof.commitments,
+            public: proof.public,
+            evals,
+        })
+    }
+
+    // This function verifies the selective disclosure proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: evaluation point zeta along with the verified evaluations of the revealed polynomials
+    pub fn verify
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        index: &VerifierIndex<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<(Fr<G>, Vec<(PolyId, Vec<Fr<G>>)>), ProofError>
+    {
+        if self.public.len() > lgr_comm.len() {return Err(ProofError::ProofStructure)}
+        let p_comm = ProverProof::<G>::public_comm(lgr_comm, &self.public);
+        let (fq_sponge, oracles) = ProverProof::<G>::commitment_oracles::<EFqSponge>
+            (index, &p_comm, &self.public, None, &self.commitments, EFqSponge::new(index.fq_sponge_params.clone()));
+        let (v, u) = Self::opening_oracles::<EFqSponge, EFrSponge>(&fq_sponge, &index.fr_sponge_params, &self.evals, &index.srs.get_ref().endo_r);
+
+        let polynoms = self.evals.iter().map
+        (
+            |(id, e)| match id
+            {
+                PolyId::L => (&self.commitments.l_comm, vec![e], None),
+                PolyId::R => (&self.commitments.r_comm, vec![e], None),
+                PolyId::O => (&self.commitments.o_comm, vec![e], None),
+                PolyId::Z => (&self.commitments.z_comm, vec![e], None),
+                PolyId::T => (&self.commitments.t_comm, vec![e], Some(index.max_quot_size)),
+            }
+        ).collect::<Vec<_>>();
+
+        let mut batch = vec![(fq_sponge, vec![oracles.zeta], v, u, polynoms, &self.proof)];
+        match index.srs.get_ref().verify::<EFqSponge, _>(group_map, &mut batch, &mut thread_rng())
+        {
+            false => Err(ProofError::OpenProof),
+            true => Ok((oracles.zeta, self.evals.clone()))
+        }
+    }
+
+    // This function queries the opening scaler challenges from the transcript and the revealed evaluations
+    fn opening_oracles
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        fq_sponge: &EFqSponge,
+        fr_sponge_params: &oracle::poseidon::ArithmeticSpongeParams<Fr<G>>,
+        evals: &Vec<(PolyId, Vec<Fr<G>>)>,
+        endo_r: &Fr<G>,
+    ) -> (Fr<G>, Fr<G>)
+    {
+        let mut fr_sponge = EFrSponge::new(fr_sponge_params.clone());
+        fr_sponge.absorb(&fq_sponge.clone().digest());
+        evals.iter().for_each(|(id, e)| {fr_sponge.absorb(&Fr::<G>::from(*id as u64)); e.iter().for_each(|x| fr_sponge.absorb(x))});
+        let v = fr_sponge.challenge().to_field(endo_r);
+        let u = fr_sponge.challenge().to_field(endo_r);
+        (v, u)
+    }
+}
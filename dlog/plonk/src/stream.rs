@@ -0,0 +1,545 @@
+/*****************************************************************************************************************
+
+This source file implements streaming serialization of the Plonk proofs.
+
+The proof is written field by field directly to the writer, so that no serialized copy of the proof
+is kept in memory. The proof is prefixed with its u32 format version, the vectors with their u64
+lengths and the optional values with the u8 presence flags, the fields following in the order of
+the proof structure declaration. The format versions are:
+
+    1: proof without the witness checksum, deserialized with no checksum
+    2: proof with the optional witness checksum, deserialized with no nonce
+    3: proof with the optional witness checksum and nonce
+    4: proof with the lookup argument commitments and evaluations following the nonce, deserialized
+        with no lookup argument from the earlier versions
+    5: proof with the evaluations at the points of all of the rotations, their number preceding the
+        evaluations, deserialized with the default two evaluation points from the earlier versions
+
+The proofs are serialized in the latest version and deserialized from any of the supported ones. The
+deserialization rejects the presence flags other than 0 and 1 and the points off the curve or off its
+prime order subgroup.
+
+The index polynomial commitments, the part of the prover and verifier keys to be computed against the SRS,
+are serialized the same way with no version prefix, so that the keys can be restored from the constraint
+system and the stored commitments. The commitment and opening proof encodings are exposed for the
+protocols embedding them.
+
+The index is serialized as its circuit gates, lookup argument evaluations, configuration and polynomial
+commitments, and is restored by recomputing the constraint system from the gates against the sponge
+parameters, endomorphism coefficient and SRS supplied the same way as to Index::create, the commitments
+being taken as stored. The auxiliary polynomials are code and the indexes having any are not serialized.
+
+The ByteEncoding trait exposes the encodings as the byte vectors, the decoding rejecting trailing bytes,
+the commitments and opening proofs being foreign types serde cannot be implemented for in this crate.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, FftField, PrimeField, ToBytes, FromBytes};
+use commitment_dlog::{CommitmentField, commitment::{CommitmentCurve, PolyComm, OpeningProof}};
+use plonk_circuits::{scalars::ProofEvaluations, constraints::ConstraintSystem, domains::EvaluationDomains, gate::{CircuitGate, GateType}, wires::COLUMNS};
+use array_init::array_init;
+use oracle::{poseidon::ArithmeticSpongeParams, rndoracle::ProofError};
+use std::io::{Read, Write, Error as IoError, ErrorKind, Result as IoResult};
+use crate::prover::{ProverProof, ProverCommitments};
+use crate::index::{Index, IndexCommitments, SRSSpec, OpeningGroups, PublicAbsorb, OpeningOrder, ZkConfig};
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+// latest proof format version
+pub const PROOF_VERSION: u32 = 5;
+
+fn write_vec<T, W: Write>(v: &Vec<T>, writer: &mut W, f: impl Fn(&T, &mut W) -> IoResult<()>) -> IoResult<()>
+{
+    (v.len() as u64).write(&mut *writer)?;
+    for x in v.iter() {f(x, writer)?}
+    Ok(())
+}
+
+fn read_vec<T, R: Read>(reader: &mut R, f: impl Fn(&mut R) -> IoResult<T>) -> IoResult<Vec<T>>
+{
+    let len = u64::read(&mut *reader)? as usize;
+    (0..len).map(|_| f(&mut *reader)).collect()
+}
+
+fn write_option<T, W: Write>(v: &Option<T>, writer: &mut W, f: impl Fn(&T, &mut W) -> IoResult<()>) -> IoResult<()>
+{
+    match v
+    {
+        None => 0u8.write(&mut *writer),
+        Some(x) => {1u8.write(&mut *writer)?; f(x, writer)}
+    }
+}
+
+fn read_option<T, R: Read>(reader: &mut R, f: impl Fn(&mut R) -> IoResult<T>) -> IoResult<Option<T>>
+{
+    match u8::read(&mut *reader)?
+    {
+        0 => Ok(None),
+        1 => Ok(Some(f(reader)?)),
+        _ => Err(IoError::new(ErrorKind::InvalidData, "malformed presence flag")),
+    }
+}
+
+fn write_flag<W: Write>(flag: bool, writer: &mut W) -> IoResult<()>
+{
+    (flag as u8).write(writer)
+}
+
+fn read_flag<R: Read>(reader: &mut R) -> IoResult<bool>
+{
+    match u8::read(reader)?
+    {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(IoError::new(ErrorKind::InvalidData, "malformed flag")),
+    }
+}
+
+// the deserialized points are checked to be on the curve and in its prime order subgroup
+fn read_point<G: CommitmentCurve, R: Read>(reader: &mut R) -> IoResult<G>
+{
+    let point = G::read(reader)?;
+    if point.is_valid() {Ok(point)} else {Err(IoError::new(ErrorKind::InvalidData, "point not in the prime order subgroup"))}
+}
+
+fn write_elems<T: ToBytes, W: Write>(v: &Vec<T>, writer: &mut W) -> IoResult<()>
+{
+    write_vec(v, writer, |x, w| x.write(w))
+}
+
+fn read_elems<T: FromBytes, R: Read>(reader: &mut R) -> IoResult<Vec<T>>
+{
+    read_vec(reader, |r| T::read(r))
+}
+
+pub fn write_comm<G: AffineCurve, W: Write>(comm: &PolyComm<G>, writer: &mut W) -> IoResult<()>
+{
+    write_elems(&comm.unshifted, writer)?;
+    write_option(&comm.shifted, writer, |x, w| x.write(w))
+}
+
+pub fn read_comm<G: CommitmentCurve, R: Read>(reader: &mut R) -> IoResult<PolyComm<G>>
+{
+    let unshifted = read_vec(reader, |r| read_point(r))?;
+    let shifted = read_option(reader, |r| read_point(r))?;
+    Ok(PolyComm {unshifted, shifted})
+}
+
+pub fn write_opening<G: AffineCurve, W: Write>(proof: &OpeningProof<G>, writer: &mut W) -> IoResult<()>
+{
+    write_vec(&proof.lr, writer, |(l, r), w| {l.write(&mut *w)?; r.write(w)})?;
+    proof.delta.write(&mut *writer)?;
+    proof.z1.write(&mut *writer)?;
+    proof.z2.write(&mut *writer)?;
+    proof.sg.write(writer)
+}
+
+pub fn read_opening<G: CommitmentCurve, R: Read>(reader: &mut R) -> IoResult<OpeningProof<G>>
+{
+    let lr = read_vec(reader, |r| Ok((read_point(&mut *r)?, read_point(r)?)))?;
+    let delta = read_point(&mut *reader)?;
+    let z1 = G::ScalarField::read(&mut *reader)?;
+    let z2 = G::ScalarField::read(&mut *reader)?;
+    let sg = read_point(reader)?;
+    Ok(OpeningProof {lr, delta, z1, z2, sg})
+}
+
+fn write_evals<F: ToBytes, W: Write>(e: &ProofEvaluations<Vec<F>>, writer: &mut W) -> IoResult<()>
+{
+    for v in [&e.l, &e.r, &e.o, &e.z, &e.t, &e.f, &e.sigma1, &e.sigma2].iter() {write_elems(v, writer)?}
+    Ok(())
+}
+
+fn read_evals<F: FromBytes, R: Read>(reader: &mut R) -> IoResult<ProofEvaluations<Vec<F>>>
+{
+    Ok(ProofEvaluations
+    {
+        l: read_elems(reader)?,
+        r: read_elems(reader)?,
+        o: read_elems(reader)?,
+        z: read_elems(reader)?,
+        t: read_elems(reader)?,
+        f: read_elems(reader)?,
+        sigma1: read_elems(reader)?,
+        sigma2: read_elems(reader)?,
+    })
+}
+
+impl<G: CommitmentCurve> ProverProof<G>
+{
+    // This function writes the proof to the writer
+    //     writer: destination of the serialized proof
+    pub fn serialize_to(&self, writer: &mut impl Write) -> IoResult<()>
+    {
+        PROOF_VERSION.write(&mut *writer)?;
+        let c = &self.commitments;
+        for comm in [&c.l_comm, &c.r_comm, &c.o_comm, &c.z_comm, &c.t_comm].iter() {write_comm(comm, writer)?}
+        write_vec(&c.aux_comm, writer, |x, w| write_comm(x, w))?;
+
+        write_opening(&self.proof, writer)?;
+        write_option(&self.index_proof, writer, |x, w| write_opening(x, w))?;
+        (self.evals.len() as u64).write(&mut *writer)?;
+        for e in self.evals.iter() {write_evals(e, writer)?}
+        for e in self.aux_evals.iter() {write_vec(e, writer, |x, w| write_elems(x, w))?}
+        write_elems(&self.public, writer)?;
+        write_vec(&self.prev_challenges, writer, |(chal, comm), w| {write_elems(chal, w)?; write_comm(comm, w)})?;
+        write_option(&self.witness_checksum, writer, |x, w| write_elems(x, w))?;
+        write_option(&self.nonce, writer, |x, w| x.write(w))?;
+        write_vec(&c.lookup_comm, writer, |x, w| write_comm(x, w))?;
+        for e in self.lookup_evals.iter() {write_vec(e, writer, |x, w| write_elems(x, w))?}
+        Ok(())
+    }
+
+    // This function reads the proof written by serialize_to from the reader
+    //     reader: source of the serialized proof
+    //     RETURN: deserialized proof, upgraded to the latest version
+    pub fn deserialize_from(reader: &mut impl Read) -> Result<Self, ProofError>
+    {
+        Self::deserialize_versioned_from(reader).map(|(proof, _)| proof)
+    }
+
+    // This function reads the proof written by serialize_to from the reader along with its version
+    //     reader: source of the serialized proof
+    //     RETURN: deserialized proof, upgraded to the latest version, and the version it was written in
+    pub fn deserialize_versioned_from(reader: &mut impl Read) -> Result<(Self, u32), ProofError>
+    {
+        let version = u32::read(&mut *reader).map_err(|_| ProofError::ProofStructure)?;
+        match version
+        {
+            1 ..= PROOF_VERSION => Self::read_proof(reader, version).map(|proof| (proof, version)).map_err(|_| ProofError::ProofStructure),
+            _ => Err(ProofError::UnsupportedVersion {found: version, supported: PROOF_VERSION}),
+        }
+    }
+
+    fn read_proof(reader: &mut impl Read, version: u32) -> IoResult<Self>
+    {
+        let mut commitments = ProverCommitments
+        {
+            l_comm: read_comm(reader)?,
+            r_comm: read_comm(reader)?,
+            o_comm: read_comm(reader)?,
+            z_comm: read_comm(reader)?,
+            t_comm: read_comm(reader)?,
+            aux_comm: read_vec(reader, |r| read_comm(r))?,
+            lookup_comm: Vec::new(),
+        };
+
+        let proof = read_opening(reader)?;
+        let index_proof = read_option(reader, |r| read_opening(r))?;
+        // the proofs of the earlier versions are evaluated at the default two points
+        let points = if version < 5 {2} else {u64::read(&mut *reader)? as usize};
+        let evals = (0..points).map(|_| read_evals(reader)).collect::<IoResult<Vec<_>>>()?;
+        let aux_evals = (0..points).map(|_| read_vec(reader, |r| read_elems(r))).collect::<IoResult<Vec<_>>>()?;
+        let public = read_elems(reader)?;
+        let prev_challenges = read_vec(reader, |r| Ok((read_elems(&mut *r)?, read_comm(r)?)))?;
+        let witness_checksum = if version < 2 {None} else {read_option(reader, |r| read_elems(r))?};
+        let nonce = if version < 3 {None} else {read_option(reader, |r| G::ScalarField::read(r))?};
+        let mut lookup_evals = vec![Vec::new(); points];
+        if version >= 4
+        {
+            commitments.lookup_comm = read_vec(reader, |r| read_comm(r))?;
+            lookup_evals = (0..points).map(|_| read_vec(reader, |r| read_elems(r))).collect::<IoResult<Vec<_>>>()?;
+        }
+
+        Ok(ProverProof
+        {
+            commitments,
+            proof,
+            index_proof,
+            evals,
+            aux_evals,
+            public,
+            prev_challenges,
+            witness_checksum,
+            nonce,
+            lookup_evals,
+        })
+    }
+}
+
+// serde serialization of the proof as the bytes of its streaming serialization, so that the serde formats
+// carry the same versioned encoding
+#[cfg(feature = "serde")]
+impl<G: CommitmentCurve> serde::Serialize for ProverProof<G>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        let mut bytes = Vec::new();
+        self.serialize_to(&mut bytes).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, G: CommitmentCurve> serde::Deserialize<'de> for ProverProof<G>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        let bytes = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::deserialize_from(&mut &bytes[..]).map_err(|e| serde::de::Error::custom(format!("malformed proof: {:?}", e)))
+    }
+}
+
+impl<G: CommitmentCurve> IndexCommitments<G>
+{
+    // This function writes the index polynomial commitments to the writer
+    //     writer: destination of the serialized commitments
+    pub fn serialize_to(&self, writer: &mut impl Write) -> IoResult<()>
+    {
+        for comm in self.sigma_comm.iter().chain(self.rcm_comm.iter()) {write_comm(comm, writer)?}
+        for comm in [&self.ql_comm, &self.qr_comm, &self.qo_comm, &self.qm_comm, &self.qc_comm, &self.psm_comm, &self.add_comm,
+            &self.mul1_comm, &self.mul2_comm, &self.emul1_comm, &self.emul2_comm, &self.emul3_comm].iter() {write_comm(comm, writer)?}
+        write_option(&self.lookup_comm, writer, |x, w| write_vec(x, w, |c, w| write_comm(c, w)))
+    }
+
+    // This function reads the index polynomial commitments written by serialize_to from the reader
+    //     reader: source of the serialized commitments
+    //     RETURN: deserialized commitments
+    pub fn deserialize_from(reader: &mut impl Read) -> Result<Self, ProofError>
+    {
+        Self::read_commitments(reader).map_err(|_| ProofError::ProofStructure)
+    }
+
+    fn read_commitments(reader: &mut impl Read) -> IoResult<Self>
+    {
+        let mut sigma_comm = (0..COLUMNS).map(|_| read_comm(&mut *reader)).collect::<IoResult<Vec<_>>>()?.into_iter();
+        let sigma_comm = array_init(|_| sigma_comm.next().unwrap());
+        let rcm_comm = [read_comm(reader)?, read_comm(reader)?, read_comm(reader)?];
+        Ok(IndexCommitments
+        {
+            sigma_comm,
+            rcm_comm,
+            ql_comm: read_comm(reader)?,
+            qr_comm: read_comm(reader)?,
+            qo_comm: read_comm(reader)?,
+            qm_comm: read_comm(reader)?,
+            qc_comm: read_comm(reader)?,
+            psm_comm: read_comm(reader)?,
+            add_comm: read_comm(reader)?,
+            mul1_comm: read_comm(reader)?,
+            mul2_comm: read_comm(reader)?,
+            emul1_comm: read_comm(reader)?,
+            emul2_comm: read_comm(reader)?,
+            emul3_comm: read_comm(reader)?,
+            lookup_comm: read_option(reader, |r| read_vec(r, |r| read_comm(r)))?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<G: CommitmentCurve> serde::Serialize for IndexCommitments<G>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        let mut bytes = Vec::new();
+        self.serialize_to(&mut bytes).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, G: CommitmentCurve> serde::Deserialize<'de> for IndexCommitments<G>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        let bytes = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::deserialize_from(&mut &bytes[..]).map_err(|e| serde::de::Error::custom(format!("malformed index commitments: {:?}", e)))
+    }
+}
+
+// byte encoding of the values as their streaming serialization
+pub trait ByteEncoding: Sized
+{
+    // This function encodes the value
+    //     RETURN: serialized value
+    fn to_bytes(&self) -> Vec<u8>;
+
+    // This function decodes the value encoded by to_bytes
+    //     bytes: serialized value, with no trailing bytes
+    //     RETURN: deserialized value
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError>;
+}
+
+// the writes to the vector do not fail
+fn encode(f: impl FnOnce(&mut Vec<u8>) -> IoResult<()>) -> Vec<u8>
+{
+    let mut bytes = Vec::new();
+    f(&mut bytes).expect("serialization to the vector failed");
+    bytes
+}
+
+fn decode<T>(bytes: &[u8], f: impl FnOnce(&mut &[u8]) -> Result<T, ProofError>) -> Result<T, ProofError>
+{
+    let mut reader = bytes;
+    let value = f(&mut reader)?;
+    if reader.len() == 0 {Ok(value)} else {Err(ProofError::ProofStructure)}
+}
+
+impl<G: CommitmentCurve> ByteEncoding for PolyComm<G>
+{
+    fn to_bytes(&self) -> Vec<u8> {encode(|w| write_comm(self, w))}
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {decode(bytes, |r| read_comm(r).map_err(|_| ProofError::ProofStructure))}
+}
+
+impl<G: CommitmentCurve> ByteEncoding for OpeningProof<G>
+{
+    fn to_bytes(&self) -> Vec<u8> {encode(|w| write_opening(self, w))}
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {decode(bytes, |r| read_opening(r).map_err(|_| ProofError::ProofStructure))}
+}
+
+impl<G: CommitmentCurve> ByteEncoding for ProverProof<G>
+{
+    fn to_bytes(&self) -> Vec<u8> {encode(|w| self.serialize_to(w))}
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {decode(bytes, |r| Self::deserialize_from(r))}
+}
+
+impl<G: CommitmentCurve> ByteEncoding for IndexCommitments<G>
+{
+    fn to_bytes(&self) -> Vec<u8> {encode(|w| self.serialize_to(w))}
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {decode(bytes, |r| Self::deserialize_from(r))}
+}
+
+// the deserialized gates are checked to wire their own rows of the domain and to carry the selectors
+// of their type, so that the constraint system computation does not panic
+fn valid_gate<F: FftField>(gate: &CircuitGate<F>, n: usize) -> bool
+{
+    let w = &gate.wires;
+    w.l.0 < n && w.r.0 >= n && w.r.0 < 2*n && w.o.0 >= 2*n && w.o.0 < 3*n && [w.l.1, w.r.1, w.o.1].iter().all(|wire| *wire < 3*n) &&
+    match gate.typ
+    {
+        GateType::Generic => gate.c.len() >= 5,
+        GateType::Poseidon => gate.c.len() >= 3,
+        _ => true,
+    }
+}
+
+impl<'a, G: CommitmentCurve> Index<'a, G> where G::BaseField : PrimeField, G::ScalarField : CommitmentField
+{
+    // This function writes the index to the writer
+    //     writer: destination of the serialized index
+    //     RETURN: invalid input error if the index has auxiliary polynomials
+    pub fn serialize_to(&self, writer: &mut impl Write) -> IoResult<()>
+    {
+        if self.auxiliary.len() > 0 {return Err(IoError::new(ErrorKind::InvalidInput, "auxiliary polynomials are not serializable"))}
+        (self.cs.public as u64).write(&mut *writer)?;
+        // the gates of the run-length encoded circuit are expanded one by one
+        (self.cs.domain.d1.size as u64).write(&mut *writer)?;
+        for gate in self.cs.gate_iter() {gate.write(&mut *writer)?}
+        write_option(&self.cs.lookup, writer, |l, w| {write_vec(&l.table, w, |c, w| write_elems(c, w))?; write_elems(&l.lkpl1, w)})?;
+
+        (self.max_quot_size as u64).write(&mut *writer)?;
+        (match self.opening_groups {OpeningGroups::Single => 0u8, OpeningGroups::Split => 1}).write(&mut *writer)?;
+        (match self.public_absorb_mode {PublicAbsorb::Batched => 0u8, PublicAbsorb::PerElement => 1}).write(&mut *writer)?;
+        (match self.opening_order {OpeningOrder::AfterEvaluations => 0u8, OpeningOrder::DigestedEvaluations => 1}).write(&mut *writer)?;
+        for flag in self.zk.wire_mask.iter().chain([self.zk.z, self.zk.t, self.pipelined_commit, self.witness_check, self.permutation_check].iter())
+        {
+            write_flag(*flag, writer)?
+        }
+        (self.zk.wire_degree as u64).write(&mut *writer)?;
+        write_elems(&self.rotations, writer)?;
+        write_option(&self.domain_separator, writer, |x, w| write_elems(x, w))?;
+        write_option(&self.absorb_size, writer, |x, w| (*x as u64).write(w))?;
+
+        self.commitments.serialize_to(writer)
+    }
+
+    // This function encodes the index
+    //     RETURN: serialized index, invalid input error if the index has auxiliary polynomials
+    pub fn to_bytes(&self) -> IoResult<Vec<u8>>
+    {
+        let mut bytes = Vec::new();
+        self.serialize_to(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    // This function reads the index written by serialize_to from the reader, recomputing its constraint
+    // system. The stored commitments are taken as given, they have to be computed against the SRS
+    //     reader: source of the serialized index
+    //     fr_sponge_params: Fr sponge parameters of the constraint system
+    //     fq_sponge_params: Fq sponge parameters of the index
+    //     endo_q: endomorphism coefficient of the index
+    //     srs: SRS of the index
+    //     RETURN: deserialized index
+    pub fn deserialize_from
+    (
+        reader: &mut impl Read,
+        fr_sponge_params: ArithmeticSpongeParams<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs: SRSSpec<'a, G>,
+    ) -> Result<Self, ProofError>
+    {
+        let index = Self::read_index(reader, fr_sponge_params, fq_sponge_params, endo_q, srs).map_err(|_| ProofError::ProofStructure)?;
+        index.check_rotations()?;
+        Ok(index)
+    }
+
+    // This function decodes the index encoded by to_bytes, see deserialize_from
+    pub fn from_bytes
+    (
+        bytes: &[u8],
+        fr_sponge_params: ArithmeticSpongeParams<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs: SRSSpec<'a, G>,
+    ) -> Result<Self, ProofError>
+    {
+        decode(bytes, |r| Self::deserialize_from(r, fr_sponge_params, fq_sponge_params, endo_q, srs))
+    }
+
+    fn read_index
+    (
+        reader: &mut impl Read,
+        fr_sponge_params: ArithmeticSpongeParams<Fr<G>>,
+        fq_sponge_params: ArithmeticSpongeParams<Fq<G>>,
+        endo_q: Fr<G>,
+        srs: SRSSpec<'a, G>,
+    ) -> IoResult<Self>
+    {
+        let invalid = |msg: &str| IoError::new(ErrorKind::InvalidData, msg);
+        let public = u64::read(&mut *reader)? as usize;
+        let gates = read_vec(reader, |r| CircuitGate::<Fr<G>>::read(r))?;
+        let n = EvaluationDomains::<Fr<G>>::create(gates.len()).ok_or_else(|| invalid("circuit too large"))?.d1.size as usize;
+        if public > gates.len() || !gates.iter().all(|g| valid_gate(g, n)) {return Err(invalid("malformed gates"))}
+        let lookup = read_option(reader, |r| Ok((read_vec(&mut *r, |r| read_elems::<Fr<G>, _>(r))?, read_elems::<Fr<G>, _>(r)?)))?;
+
+        let mut cs = ConstraintSystem::<Fr<G>>::create(gates, fr_sponge_params, public).ok_or_else(|| invalid("malformed constraint system"))?;
+        if let Some((columns, selector)) = lookup
+        {
+            cs = cs.with_lookup_evaluations(columns, selector).ok_or_else(|| invalid("malformed lookup argument"))?;
+        }
+
+        let max_quot_size = u64::read(&mut *reader)? as usize;
+        let opening_groups = match u8::read(&mut *reader)? {0 => OpeningGroups::Single, 1 => OpeningGroups::Split, _ => return Err(invalid("malformed opening groups"))};
+        let public_absorb_mode = match u8::read(&mut *reader)? {0 => PublicAbsorb::Batched, 1 => PublicAbsorb::PerElement, _ => return Err(invalid("malformed public absorption"))};
+        let opening_order = match u8::read(&mut *reader)? {0 => OpeningOrder::AfterEvaluations, 1 => OpeningOrder::DigestedEvaluations, _ => return Err(invalid("malformed opening order"))};
+        let wire_mask = [read_flag(reader)?, read_flag(reader)?, read_flag(reader)?];
+        let (z, t, pipelined_commit) = (read_flag(reader)?, read_flag(reader)?, read_flag(reader)?);
+        let (witness_check, permutation_check) = (read_flag(reader)?, read_flag(reader)?);
+        let wire_degree = u64::read(&mut *reader)? as usize;
+        let rotations = read_elems(reader)?;
+        let domain_separator = read_option(reader, |r| read_elems(r))?;
+        let absorb_size = read_option(reader, |r| Ok(u64::read(r)? as usize))?;
+
+        let commitments = IndexCommitments::read_commitments(reader)?;
+        if commitments.lookup_comm.as_ref().map(|c| c.len()) != cs.lookup.as_ref().map(|l| l.table.len() + 1)
+        {
+            return Err(invalid("lookup commitments inconsistent with the lookup argument"))
+        }
+
+        let mut index = Self::create_with_commitments(cs, fq_sponge_params, endo_q, srs, commitments);
+        index.max_quot_size = max_quot_size;
+        index.opening_groups = opening_groups;
+        index.public_absorb_mode = public_absorb_mode;
+        index.opening_order = opening_order;
+        index.set_zk_config(ZkConfig {wire_degree, wire_mask, z, t});
+        index.pipelined_commit = pipelined_commit;
+        index.witness_check = witness_check;
+        index.permutation_check = permutation_check;
+        index.rotations = rotations;
+        index.domain_separator = domain_separator;
+        index.absorb_size = absorb_size;
+        Ok(index)
+    }
+}
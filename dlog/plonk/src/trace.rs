@@ -0,0 +1,185 @@
+/*****************************************************************************************************************
+
+This source file implements the commitment to the execution trace of the Plonk proofs.
+
+The trace commitment consists of the wire polynomial commitments of the proof, so that it is bound to the
+proven computation. The prover keeps the wire polynomials along with their commitment blinders and opens
+them at w^row for disclosing the l, r, o values of the row without revealing the rest of the trace. The
+opening oracles are sampled from the sponge absorbing the trace commitment, the row and its values.
+
+*****************************************************************************************************************/
+
+use algebra::{AffineCurve, Field, PrimeField};
+use ff_fft::EvaluationDomain;
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof};
+use oracle::FqSponge;
+use groupmap::GroupMap;
+use crate::index::VerifierIndex;
+use rand::thread_rng;
+#[cfg(feature = "prover")]
+use ff_fft::DensePolynomial;
+#[cfg(feature = "prover")]
+use oracle::{utils::PolyUtils, rndoracle::ProofError};
+#[cfg(feature = "prover")]
+use crate::{plonk_sponge::FrSponge, prover::{ProverProof, ProverWitness, ProverConfig}, index::Index};
+#[cfg(feature = "prover")]
+use array_init::array_init;
+
+type Fr<G> = <G as AffineCurve>::ScalarField;
+type Fq<G> = <G as AffineCurve>::BaseField;
+
+#[derive(Clone)]
+pub struct TraceCommitment<G: AffineCurve>
+{
+    pub l_comm: PolyComm<G>,
+    pub r_comm: PolyComm<G>,
+    pub o_comm: PolyComm<G>,
+}
+
+// prover's trace, kept for the row openings
+#[cfg(feature = "prover")]
+pub struct Trace<G: AffineCurve>
+{
+    pub commitment: TraceCommitment<G>,
+    polys: [DensePolynomial<Fr<G>>; 3],     // l, r, o wire polynomials
+    omega: [PolyComm<Fr<G>>; 3],            // l, r, o commitment blinders
+}
+
+#[derive(Clone)]
+pub struct RowOpening<G: AffineCurve>
+{
+    pub row: usize,
+    pub values: [Vec<Fr<G>>; 3],    // l, r, o wire polynomial chunk evaluations at w^row
+    pub proof: OpeningProof<G>,
+}
+
+#[cfg(feature = "prover")]
+impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function constructs prover's zk-proof along with the commitment to its execution trace
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     witness: computation witness
+    //     index: Index
+    //     config: proof creation options
+    //     RETURN: prover's zk-proof and the trace
+    pub fn create_with_trace_commitment
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        witness: &Vec::<Fr<G>>,
+        index: &Index<G>,
+        prev_challenges: Vec< (Vec<Fr<G>>, PolyComm<G>) >,
+        config: ProverConfig<G, EFqSponge>,
+    )
+    -> Result<(Self, Trace<G>), ProofError>
+    {
+        let (proof, polys) = Self::prove::<EFqSponge, EFrSponge, _>
+            (group_map, ProverWitness::Field(witness), index, prev_challenges, config)?;
+        let trace = Trace
+        {
+            commitment: TraceCommitment
+            {
+                l_comm: proof.commitments.l_comm.clone(),
+                r_comm: proof.commitments.r_comm.clone(),
+                o_comm: proof.commitments.o_comm.clone(),
+            },
+            polys: [polys.l.clone(), polys.r.clone(), polys.o.clone()],
+            omega: [polys.omega[0].clone(), polys.omega[1].clone(), polys.omega[2].clone()],
+        };
+        Ok((proof, trace))
+    }
+}
+
+impl<G: CommitmentCurve> TraceCommitment<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function samples the row opening oracles
+    fn opening_oracles<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>>
+    (
+        &self,
+        params: &oracle::poseidon::ArithmeticSpongeParams<Fq<G>>,
+        row: usize,
+        values: &[Vec<Fr<G>>; 3],
+    ) -> (EFqSponge, Fr<G>, Fr<G>)
+    {
+        let mut sponge = EFqSponge::new(params.clone());
+        [&self.l_comm, &self.r_comm, &self.o_comm].iter().for_each(|c| sponge.absorb_g(&c.unshifted));
+        sponge.absorb_fr(&[Fr::<G>::from(row as u64)]);
+        values.iter().for_each(|v| sponge.absorb_fr(v));
+        let v = sponge.challenge();
+        let u = sponge.challenge();
+        (sponge, v, u)
+    }
+
+    // This function verifies the row opening against the trace commitment
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     opening: row opening
+    //     RETURN: whether the trace had the opening values at the row
+    pub fn verify_row<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>
+    (
+        &self,
+        group_map: &M,
+        index: &VerifierIndex<G>,
+        opening: &RowOpening<G>,
+    ) -> bool
+    {
+        if opening.row >= index.domain.size() || opening.values.iter().any(|v| v.len() != self.l_comm.unshifted.len()) {return false}
+        let (sponge, v, u) = self.opening_oracles::<EFqSponge>(&index.fq_sponge_params, opening.row, &opening.values);
+        let point = index.domain.group_gen.pow(&[opening.row as u64]);
+        index.srs.get_ref().verify::<EFqSponge, _>
+        (
+            group_map,
+            &mut vec![(
+                sponge,
+                vec![point],
+                v,
+                u,
+                vec!
+                [
+                    (&self.l_comm, vec![&opening.values[0]], None),
+                    (&self.r_comm, vec![&opening.values[1]], None),
+                    (&self.o_comm, vec![&opening.values[2]], None),
+                ],
+                &opening.proof,
+            )],
+            &mut thread_rng()
+        )
+    }
+}
+
+#[cfg(feature = "prover")]
+impl<G: CommitmentCurve> Trace<G> where G::ScalarField : CommitmentField, G::BaseField : PrimeField
+{
+    // This function opens the l, r, o wire values of the trace row
+    //     group_map: hash-to-curve map of the opening argument, has to match the verifier's one
+    //     index: Index the trace was committed under
+    //     row: trace row
+    //     RETURN: row opening
+    pub fn open_row<EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>
+    (
+        &self,
+        group_map: &M,
+        index: &Index<G>,
+        row: usize,
+    ) -> Result<RowOpening<G>, ProofError>
+    {
+        if row >= index.cs.domain.d1.size() {return Err(ProofError::ProofStructure)}
+        let point = index.cs.domain.d1.group_gen.pow(&[row as u64]);
+        let values: [Vec<Fr<G>>; 3] = array_init(|i| self.polys[i].eval(point, index.max_poly_size));
+        let (sponge, v, u) = self.commitment.opening_oracles::<EFqSponge>(&index.fq_sponge_params, row, &values);
+        let proof = index.srs.get_ref().open
+        (
+            group_map,
+            (0..3).map(|i| (&self.polys[i], None, self.omega[i].clone())).collect(),
+            &vec![point],
+            v,
+            u,
+            sponge,
+            &mut thread_rng()
+        );
+        Ok(RowOpening {row, values, proof})
+    }
+}
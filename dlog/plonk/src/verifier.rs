@@ -4,15 +4,21 @@ This source file implements zk-proof batch verifier functionality.
 
 *********************************************************************************************/
 
-pub use super::prover::{ProverProof, range};
-pub use super::index::VerifierIndex as Index;
-use oracle::{FqSponge, rndoracle::ProofError, sponge::ScalarChallenge};
-use plonk_circuits::{scalars::RandomOracles, constraints::ConstraintSystem};
-use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, b_poly, b_poly_coefficients, combined_inner_product};
-use ff_fft::{EvaluationDomain};
-use algebra::{Field, AffineCurve, Zero, One};
-use crate::plonk_sponge::FrSponge;
+pub use super::prover::{ProverProof, ProverCommitments, range};
+pub use super::index::{VerifierIndex as Index, OpeningGroups, PublicAbsorb, OpeningOrder, QuotientLayout};
+use crate::index::{evaluation_points, absorb_separator, absorb_segments};
+use oracle::{FqSponge, rndoracle::ProofError, sponge::{ScalarChallenge, CHALLENGE_LENGTH_IN_LIMBS}};
+use groupmap::GroupMap;
+use plonk_circuits::{scalars::{RandomOracles, ProofEvaluations}, constraints::ConstraintSystem, domains::VanishingPolyEval, wires::COLUMNS};
+use commitment_dlog::commitment::{CommitmentField, CommitmentCurve, PolyComm, OpeningProof, b_poly, b_poly_coefficients, combined_inner_product, ceil_log2};
+use commitment_dlog::srs::SRS;
+use ff_fft::{EvaluationDomain, DensePolynomial};
+use algebra::{Field, AffineCurve, Zero, One, PrimeField};
+use crate::{plonk_sponge::FrSponge, auxiliary::AuxiliaryPoint, schema::{PublicInputSchema, PublicInputValue}, public::PublicCommitment};
 use rand::thread_rng;
+use std::collections::HashSet;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 type Fr<G> = <G as AffineCurve>::ScalarField;
 type Fq<G> = <G as AffineCurve>::BaseField;
@@ -24,6 +30,34 @@ pub struct CachedValues<Fs> {
     pub alpha: Vec<Fs>,
 }
 
+// Opening proof of the zk-proof together with the opened polynomials
+pub struct OpeningParts<'a, G: AffineCurve, EFqSponge>
+{
+    pub proof: &'a OpeningProof<G>,                                     // batched opening proof
+    pub evals: &'a [ProofEvaluations<Vec<Fr<G>>>],                      // evaluations at the evaluation points
+    pub points: Vec<Fr<G>>,                                             // evaluation points
+    pub v: Fr<G>,                                                       // polynomial combination scaler
+    pub u: Fr<G>,                                                       // evaluation point combination scaler
+    pub commitments: Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>, Option<usize>)>, // opened polynomials and their degree bounds
+    pub sponge: EFqSponge,                                              // transcript sponge before the opening
+}
+
+impl<'a, G: AffineCurve, EFqSponge: Clone> OpeningParts<'a, G, EFqSponge>
+{
+    // This function lays out the opening proof parts as the batch entry of the SRS opening proof verification
+    pub fn batch(&self) -> (EFqSponge, Vec<Fr<G>>, Fr<G>, Fr<G>, Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>, &OpeningProof<G>)
+    {
+        (
+            self.sponge.clone(),
+            self.points.clone(),
+            self.v,
+            self.u,
+            self.commitments.iter().map(|(c, e, s)| (c, e.iter().collect(), *s)).collect(),
+            self.proof,
+        )
+    }
+}
+
 impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
 {
     pub fn prev_chal_evals(&self, index: &Index<G>, evaluation_points: &[Fr<G>], evlp : &[Fr<G>]) -> Vec<Vec<Vec<Fr<G>>>> {
@@ -33,7 +67,7 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
             let b_len = 1 << chals.len();
             let mut b : Option<Vec<Fr<G>>> = None;
 
-            (0..2).map
+            (0..evaluation_points.len()).map
             (
                 |i|
                 {
@@ -64,7 +98,137 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
         }).collect()
     }
 
+    // This function computes the public input polynomial commitment, the zero public input, the empty
+    // one included, being committed with no segments as the zero public input polynomial is by the prover
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     public: public input
+    //     RETURN: public input polynomial commitment
+    pub fn public_comm(lgr_comm: &Vec<PolyComm<G>>, public: &[Fr<G>]) -> PolyComm<G>
+    {
+        if public.iter().all(|x| x.is_zero()) {return PolyComm::<G> {unshifted: Vec::new(), shifted: None}}
+        PolyComm::<G>::multi_scalar_mul(&lgr_comm.iter().take(public.len()).map(|l| l).collect(), &public.iter().map(|s| -*s).collect())
+    }
+
+    // This function checks the shapes of the proof commitments and evaluations against
+    // the ones expected for the index, so that malformed proofs are rejected before MSM
+    //     index: Index
+    //     RETURN: structural consistency status
+    pub fn check_structure(&self, index: &Index<G>) -> Result<(), ProofError>
+    {
+        let chunks = |size: usize| (size + index.max_poly_size - 1) / index.max_poly_size;
+        let max_w_size = chunks(index.domain.size as usize);
+        // wire polynomials blinded externally are of degree n+1, the ones blinded by the prover of degree n+b
+        let max_b_size = chunks(index.domain.size as usize + 1 + index.blinding_degree.max(1));
+        let max_t_size = chunks(index.max_quot_size);
+
+        // wire, permutation and auxiliary polynomials are committed without degree bound
+        if
+            [
+                &self.commitments.l_comm,
+                &self.commitments.r_comm,
+                &self.commitments.o_comm,
+            ].iter().any(|c| c.shifted.is_some() || c.unshifted.len() > max_b_size) ||
+            [
+                &self.commitments.z_comm,
+            ].iter().cloned().chain(self.commitments.aux_comm.iter()).
+            any(|c| c.shifted.is_some() || c.unshifted.len() > max_w_size) ||
+            self.commitments.aux_comm.len() != index.auxiliary.len()
+        {return Err(ProofError::ProofStructure)}
+
+        // quotient polynomial is committed with the max_quot_size degree bound
+        // shift, which SRS::commit produces, the zero one for the zero or the short quotient included,
+        // unless the bound is a multiple of the segment size
+        if
+            self.commitments.t_comm.unshifted.len() > max_t_size ||
+            self.commitments.t_comm.shifted.is_some() != (index.max_quot_size % index.max_poly_size != 0)
+        {return Err(ProofError::ProofStructure)}
+
+        // the commitments fit the fixed-size absorption
+        if let Some(k) = index.absorb_size
+        {
+            let c = &self.commitments;
+            if
+                [&c.l_comm, &c.r_comm, &c.o_comm, &c.z_comm, &c.t_comm].iter().cloned().
+                chain(c.aux_comm.iter()).chain(c.lookup_comm.iter()).any(|c| c.unshifted.len() > k)
+            {return Err(ProofError::ProofStructure)}
+        }
+
+        // the polynomials are evaluated at the points of the index rotations
+        if
+            index.rotations.len() < 2 || index.rotations[0] != 0 || index.rotations[1] != 1 ||
+            self.evals.len() != index.rotations.len() ||
+            self.aux_evals.len() != index.rotations.len() ||
+            self.lookup_evals.len() != index.rotations.len()
+        {return Err(ProofError::ProofStructure)}
+
+        // evaluations are chunked by the polynomial segment size
+        for e in self.evals.iter()
+        {
+            if
+                e.w().iter().any(|e| e.len() > max_b_size) ||
+                [&e.z, &e.f].iter().chain(e.sigma().iter()).any(|e| e.len() > max_w_size) ||
+                e.t.len() != max_t_size
+            {return Err(ProofError::ProofStructure)}
+        }
+        if self.evals[1..].iter().any
+        (
+            |e|
+            self.evals[0].l.len() != e.l.len() ||
+            self.evals[0].r.len() != e.r.len() ||
+            self.evals[0].o.len() != e.o.len() ||
+            self.evals[0].z.len() != e.z.len() ||
+            self.evals[0].f.len() != e.f.len() ||
+            (0..COLUMNS-1).any(|c| self.evals[0].sigma()[c].len() != e.sigma()[c].len())
+        )
+        {return Err(ProofError::ProofStructure)}
+
+        // auxiliary polynomials are evaluated as the wire ones
+        if
+            self.aux_evals.iter().any(|e| e.len() != index.auxiliary.len() || e.iter().any(|e| e.len() > max_w_size)) ||
+            self.aux_evals[1..].iter().any(|e| self.aux_evals[0].iter().zip(e.iter()).any(|(e0, e1)| e0.len() != e1.len()))
+        {return Err(ProofError::ProofStructure)}
+
+        // lookup multiplicities and running sum are committed as the wire ones, the lookup polynomials
+        // being evaluated along with the index selector and table column ones
+        let lookup = if index.lookup_comm.is_some() {2} else {0};
+        let lookup_evals = lookup + index.lookup_comm.as_ref().map_or(0, |c| c.len());
+        if
+            self.commitments.lookup_comm.len() != lookup ||
+            self.commitments.lookup_comm.iter().any(|c| c.shifted.is_some() || c.unshifted.len() > max_w_size) ||
+            self.lookup_evals.iter().any(|e| e.len() != lookup_evals || e.iter().any(|e| e.len() > max_w_size)) ||
+            self.lookup_evals[1..].iter().any(|e| self.lookup_evals[0].iter().zip(e.iter()).any(|(e0, e1)| e0.len() != e1.len()))
+        {return Err(ProofError::ProofStructure)}
+
+        // opening proofs have a round per SRS halving
+        if self.proof.lr.len() != ceil_log2(index.srs.get_ref().g.len()) {return Err(ProofError::ProofStructure)}
+        match (index.opening_groups, &self.index_proof)
+        {
+            (OpeningGroups::Single, None) => {},
+            (OpeningGroups::Split, Some(p)) if p.lr.len() == self.proof.lr.len() => {},
+            _ => return Err(ProofError::ProofStructure),
+        }
+
+        Ok(())
+    }
+
+    // This function computes the quotient polynomial commitment layout of the proof
+    //     index: Index
+    //     RETURN: quotient layout
+    pub fn quotient_layout(&self, index: &Index<G>) -> QuotientLayout
+    {
+        let (bound, size) = (index.max_quot_size, index.max_poly_size);
+        QuotientLayout
+        {
+            bound,
+            max_poly_size: size,
+            chunks: (0..self.commitments.t_comm.unshifted.len()).map(|k| std::cmp::min(size, bound.saturating_sub(k*size))).collect(),
+            absorbed: (bound + size - 1) / size,
+            shifted: if bound % size == 0 {None} else {Some(bound % size)},
+        }
+    }
+
     // This function runs random oracle argument
+    //     RETURN: proof structure error for the proof malformed against the index
     pub fn oracles
         <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
          EFrSponge: FrSponge<Fr<G>>,
@@ -73,40 +237,96 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
         &self,
         index: &Index<G>,
         p_comm: &PolyComm<G>,
-    ) -> (EFqSponge, Fr<G>, RandomOracles<Fr<G>>, Vec<Fr<G>>, [Vec<Fr<G>>; 2], [Fr<G>; 2], Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>, Fr<G>, Fr<G>)
+    ) -> Result<(EFqSponge, Fr<G>, RandomOracles<Fr<G>>, Vec<Fr<G>>, Vec<Vec<Fr<G>>>, Vec<Fr<G>>, Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>, Fr<G>, Fr<G>), ProofError>
+    {
+        self.oracles_with_sponge::<EFqSponge, EFrSponge>(index, p_comm, EFqSponge::new(index.fq_sponge_params.clone()))
+    }
+
+    // This function runs the commitment part of the random oracle argument
+    //     index: Index
+    //     p_comm: public input polynomial commitment
+    //     public: public input
+    //     nonce: optional proof nonce
+    //     commitments: prover's polynomial commitments
+    //     fq_sponge: transcript sponge
+    //     RETURN: transcript sponge before the evaluations and the beta, gamma, alpha, zeta oracles
+    pub(crate) fn commitment_oracles
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>>
+    (
+        index: &Index<G>,
+        p_comm: &PolyComm<G>,
+        public: &[Fr<G>],
+        nonce: Option<&Fr<G>>,
+        commitments: &ProverCommitments<G>,
+        fq_sponge: EFqSponge,
+    ) -> (EFqSponge, RandomOracles<Fr<G>>)
     {
-        let n = index.domain.size;
-        // Run random oracle argument to sample verifier oracles
         let mut oracles = RandomOracles::<Fr<G>>::zero();
-        let mut fq_sponge = EFqSponge::new(index.fq_sponge_params.clone());
+        let mut fq_sponge = fq_sponge;
+        absorb_separator::<G, EFqSponge>(&mut fq_sponge, &index.domain_separator);
         // absorb the public input, l, r, o polycommitments into the argument
-        fq_sponge.absorb_g(&p_comm.unshifted);
-        fq_sponge.absorb_g(&self.commitments.l_comm.unshifted);
-        fq_sponge.absorb_g(&self.commitments.r_comm.unshifted);
-        fq_sponge.absorb_g(&self.commitments.o_comm.unshifted);
+        match index.public_absorb_mode
+        {
+            PublicAbsorb::Batched => absorb_segments(&mut fq_sponge, &p_comm.unshifted, index.absorb_size),
+            PublicAbsorb::PerElement => public.iter().for_each(|x| fq_sponge.absorb_fr(&[*x])),
+        }
+        nonce.iter().for_each(|x| fq_sponge.absorb_fr(&[**x]));
+        absorb_segments(&mut fq_sponge, &commitments.l_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &commitments.r_comm.unshifted, index.absorb_size);
+        absorb_segments(&mut fq_sponge, &commitments.o_comm.unshifted, index.absorb_size);
+        // absorb the auxiliary witness polycommitments into the argument
+        commitments.aux_comm.iter().for_each(|c| absorb_segments(&mut fq_sponge, &c.unshifted, index.absorb_size));
+        // absorb the lookup multiplicities polycommitment into the argument
+        commitments.lookup_comm.iter().take(1).for_each(|c| absorb_segments(&mut fq_sponge, &c.unshifted, index.absorb_size));
         // sample beta, gamma oracles
         oracles.beta = fq_sponge.challenge();
         oracles.gamma = fq_sponge.challenge();
-        // absorb the z commitment into the argument and query alpha
-        fq_sponge.absorb_g(&self.commitments.z_comm.unshifted);
+        if let Some(c) = &index.lookup_comm
+        {
+            oracles.lookup = fq_sponge.challenge();
+            if c.len() > 2 {oracles.lookup_combiner = fq_sponge.challenge()}
+        }
+        // absorb the z and the lookup running sum commitments into the argument and query alpha
+        absorb_segments(&mut fq_sponge, &commitments.z_comm.unshifted, index.absorb_size);
+        commitments.lookup_comm.iter().skip(1).for_each(|c| absorb_segments(&mut fq_sponge, &c.unshifted, index.absorb_size));
         oracles.alpha_chal = ScalarChallenge(fq_sponge.challenge());
         oracles.alpha = oracles.alpha_chal.to_field(&index.srs.get_ref().endo_r);
         // absorb the polycommitments into the argument and sample zeta
         let max_t_size = (index.max_quot_size + index.max_poly_size - 1) / index.max_poly_size;
         let dummy = G::of_coordinates(Fq::<G>::zero(), Fq::<G>::zero());
-        fq_sponge.absorb_g(&self.commitments.t_comm.unshifted);
-        fq_sponge.absorb_g(&vec![dummy; max_t_size - self.commitments.t_comm.unshifted.len()]);
+        absorb_segments(&mut fq_sponge, &commitments.t_comm.unshifted, Some(index.absorb_size.unwrap_or(max_t_size)));
+        // the shifted segment, missing for the bound of a multiple of the segment size, is absorbed
+        // as the dummy point when missing or zero
+        match commitments.t_comm.shifted
         {
-            let s = self.commitments.t_comm.shifted.unwrap();
-            if s.is_zero() {
-                fq_sponge.absorb_g(&[dummy])
-            } else {
-                fq_sponge.absorb_g(&[s])
-            }
-        };
+            Some(s) if !s.is_zero() => fq_sponge.absorb_g(&[s]),
+            _ => fq_sponge.absorb_g(&[dummy]),
+        }
 
         oracles.zeta_chal = ScalarChallenge(fq_sponge.challenge());
         oracles.zeta = oracles.zeta_chal.to_field(&index.srs.get_ref().endo_r);
+        (fq_sponge, oracles)
+    }
+
+    // This function runs random oracle argument continuing the transcript of a parent protocol, the
+    // proof being checked against the index structure first
+    //     fq_sponge: parent protocol sponge, has to be in the prover's one state
+    //     RETURN: proof structure error for the proof malformed against the index
+    pub fn oracles_with_sponge
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        p_comm: &PolyComm<G>,
+        fq_sponge: EFqSponge,
+    ) -> Result<(EFqSponge, Fr<G>, RandomOracles<Fr<G>>, Vec<Fr<G>>, Vec<Vec<Fr<G>>>, Vec<Fr<G>>, Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>, Fr<G>, Fr<G>), ProofError>
+    {
+        self.check_structure(index)?;
+        let n = index.domain.size;
+        // Run random oracle argument to sample verifier oracles
+        let (fq_sponge, mut oracles) = Self::commitment_oracles(index, p_comm, &self.public, self.nonce.as_ref(), &self.commitments, fq_sponge);
         let digest = fq_sponge.clone().digest();
         let mut fr_sponge =
         {
@@ -117,29 +337,45 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
 
         // prepare some often used values
         let zeta1 = oracles.zeta.pow(&[n]);
-        let zetaw = oracles.zeta * &index.domain.group_gen;
+        // the rotations of the index are validated by the proof structure check
+        let ep = evaluation_points(index.domain, &index.rotations, oracles.zeta).unwrap();
         let mut alpha = oracles.alpha;
-        let alpha = (0..17).map(|_| {alpha *= &oracles.alpha; alpha}).collect::<Vec<_>>();
+        let lookup_alpha = if index.lookup_comm.is_some() {range::LOOKUP} else {0};
+        let alpha = (0..17 + index.auxiliary.len() + lookup_alpha).map(|_| {alpha *= &oracles.alpha; alpha}).collect::<Vec<_>>();
 
-        // compute Lagrange base evaluation denominators
+        // compute Lagrange base evaluation denominators at each of the evaluation points
         let w = (0..self.public.len()).zip(index.domain.elements()).map(|(_,w)| w).collect::<Vec<_>>();
-        let mut lagrange = w.iter().map(|w| oracles.zeta - w).collect::<Vec<_>>();
-        (0..self.public.len()).zip(w.iter()).for_each(|(_,w)| lagrange.push(zetaw - w));
+        let mut lagrange = ep.iter().flat_map(|e| w.iter().map(move |w| *e - w)).collect::<Vec<_>>();
         algebra::fields::batch_inversion::<Fr<G>>(&mut lagrange);
 
-        // evaluate public input polynomials
+        // evaluate public input polynomials, the zero one having no evaluations as with the prover
         // NOTE: this works only in the case when the poly segment size is not smaller than that of the domain 
-        let p_eval = if self.public.len() > 0
-        {[
-            vec![(self.public.iter().zip(lagrange.iter()).
-                zip(index.domain.elements()).map(|((p, l), w)| -*l * p * &w).
-                fold(Fr::<G>::zero(), |x, y| x + &y)) * &(zeta1 - &Fr::<G>::one()) * &index.domain.size_inv],
-            vec![(self.public.iter().zip(lagrange[self.public.len()..].iter()).
-                zip(index.domain.elements()).map(|((p, l), w)| -*l * p * &w).
-                fold(Fr::<G>::zero(), |x, y| x + &y)) * &index.domain.size_inv * &(zetaw.pow(&[n as u64]) - &Fr::<G>::one())]
-        ]}
-        else {[Vec::<Fr<G>>::new(), Vec::<Fr<G>>::new()]};
-        for i in 0..2 {fr_sponge.absorb_evaluations(&p_eval[i], &self.evals[i])}
+        let p_eval = ep.iter().enumerate().map
+        (
+            |(i, e)| if self.public.iter().all(|x| x.is_zero()) {Vec::<Fr<G>>::new()} else
+            {
+                vec![(self.public.iter().zip(lagrange[i*self.public.len()..].iter()).
+                    zip(index.domain.elements()).map(|((p, l), w)| -*l * p * &w).
+                    fold(Fr::<G>::zero(), |x, y| x + &y)) * &index.domain.vanishing_poly_eval(*e) * &index.domain.size_inv]
+            }
+        ).collect::<Vec<_>>();
+        // the evaluations are bound to v, u either directly or through their digest
+        let absorb = |s: &mut EFrSponge|
+        {
+            p_eval.iter().zip(self.evals.iter()).for_each(|(p, e)| s.absorb_evaluations(p, e));
+            self.aux_evals.iter().flatten().flatten().for_each(|x| s.absorb(x));
+            self.lookup_evals.iter().flatten().flatten().for_each(|x| s.absorb(x));
+        };
+        match index.opening_order
+        {
+            OpeningOrder::AfterEvaluations => absorb(&mut fr_sponge),
+            OpeningOrder::DigestedEvaluations =>
+            {
+                let mut s = EFrSponge::new(index.fr_sponge_params.clone());
+                absorb(&mut s);
+                fr_sponge.absorb(&s.digest());
+            }
+        }
 
         // query opening scaler challenges
         oracles.v_chal = fr_sponge.challenge();
@@ -147,13 +383,7 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
         oracles.u_chal = fr_sponge.challenge();
         oracles.u = oracles.u_chal.to_field(&index.srs.get_ref().endo_r);
 
-        let ep = [oracles.zeta, zetaw];
-
-        let evlp =
-        [
-            oracles.zeta.pow(&[index.max_poly_size as u64]),
-            zetaw.pow(&[index.max_poly_size as u64])
-        ];
+        let evlp = ep.iter().map(|e| e.pow(&[index.max_poly_size as u64])).collect::<Vec<_>>();
 
         let polys : Vec<(PolyComm<G>, _)> = self.prev_challenges
             .iter()
@@ -173,164 +403,547 @@ impl<G: CommitmentCurve> ProverProof<G> where G::ScalarField : CommitmentField
                     (self.evals.iter().map(|e| &e.z).collect::<Vec<_>>(), None),
 
                     (self.evals.iter().map(|e| &e.f).collect::<Vec<_>>(), None),
-
-                    (self.evals.iter().map(|e| &e.sigma1).collect::<Vec<_>>(), None),
-                    (self.evals.iter().map(|e| &e.sigma2).collect::<Vec<_>>(), None),
-
-                    (self.evals.iter().map(|e| &e.t).collect::<Vec<_>>(), Some(index.max_quot_size)),
                 ]
             );
+            es.extend((0..self.aux_evals[0].len()).map(|i| (self.aux_evals.iter().map(|e| &e[i]).collect::<Vec<_>>(), None)));
+            es.extend((0..self.lookup_evals[0].len()).map(|i| (self.lookup_evals.iter().map(|e| &e[i]).collect::<Vec<_>>(), None)));
+            es.extend((0..COLUMNS-1).map(|c| (self.evals.iter().map(|e| e.sigma()[c]).collect::<Vec<_>>(), None)));
+            // the quotient bound is applied unless the shifted segment is missing or zero, as with the opening proof
+            let bound = Some(index.max_quot_size).filter(|_| self.commitments.t_comm.shifted.map_or(false, |s| !s.is_zero()));
+            es.push((self.evals.iter().map(|e| &e.t).collect::<Vec<_>>(), bound));
 
             combined_inner_product::<G>(&ep, &oracles.v, &oracles.u, &es, index.srs.get_ref().g.len())
         };
 
-        (fq_sponge, digest, oracles, alpha, p_eval, evlp, polys, zeta1, combined_inner_product)
+        Ok((fq_sponge, digest, oracles, alpha, p_eval, evlp, polys, zeta1, combined_inner_product))
+    }
+
+    // This function re-derives the Fiat-Shamir challenges of the zk-proof in the transcript order
+    // of the verifier, without running the verification itself
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: beta, gamma, alpha, zeta, v, u oracles along with their scalar challenges, proof
+    //         structure error for the proof malformed against the index
+    pub fn random_oracles
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<RandomOracles<Fr<G>>, ProofError>
+    {
+        let p_comm = Self::public_comm(lgr_comm, &self.public);
+        Ok(self.oracles::<EFqSponge, EFrSponge>(index, &p_comm)?.2)
+    }
+
+    // This function computes the Fiat-Shamir challenges of the proof as the witness segment of the
+    // in-circuit verifier. The challenges are laid out in the transcript order beta, gamma, alpha,
+    // zeta, v, u, each of them as its CHALLENGE_LENGTH_IN_LIMBS 64-bit limbs, lower limb first
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: base field witness of the challenges, proof structure error for the proof malformed
+    //         against the index
+    pub fn challenges_as_recursion_witness
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<Vec<Fq<G>>, ProofError>
+    where Fr<G>: PrimeField, Fq<G>: PrimeField
+    {
+        let oracles = self.random_oracles::<EFqSponge, EFrSponge>(index, lgr_comm)?;
+
+        Ok([oracles.beta, oracles.gamma, oracles.alpha_chal.0, oracles.zeta_chal.0, oracles.v_chal.0, oracles.u_chal.0].iter().flat_map
+        (
+            |chal| chal.into_repr().as_ref()[0..CHALLENGE_LENGTH_IN_LIMBS].iter().map(|limb| Fq::<G>::from(*limb)).collect::<Vec<_>>()
+        ).collect())
+    }
+
+    // This function verifies the batch of zk-proofs of the same Index, the opening
+    // proofs of the batch are checked with the single combined multi-scalar multiplication
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: Plonk proofs of the Index
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: verification status, the batch failing on any of its proofs failing
+    pub fn verify_batch
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &[ProverProof<G>],
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<bool, ProofError>
+    {
+        Self::verify::<EFqSponge, EFrSponge, _>(group_map, &proofs.iter().map(|proof| (index, lgr_comm, proof)).collect())
     }
 
     // This function verifies the batch of zk-proofs
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
     //     proofs: vector of Plonk proofs
     //     index: Index
     //     RETURN: verification status
     pub fn verify
         <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
          EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+    ) -> Result<bool, ProofError>
+    {
+        let sponges = proofs.iter().map(|(index, _, _)| EFqSponge::new(index.fq_sponge_params.clone())).collect::<Vec<_>>();
+        Self::verify_with_sponges::<EFqSponge, EFrSponge, _>(group_map, proofs, sponges)
+    }
+
+    // This function verifies the zk-proof and checks the timestamp it embeds into a public input
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     slot: index of the public input holding the timestamp
+    //     max_age: maximal age of the proof timestamp
+    //     now: current time in the timestamp units
+    //     RETURN: verification status
+    pub fn verify_with_freshness
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+        slot: usize,
+        max_age: u64,
+        now: u64,
+    ) -> Result<bool, ProofError>
+    {
+        if slot >= self.public.len() {return Err(ProofError::ProofStructure)}
+        let timestamp = self.public[slot].into_repr();
+        if timestamp.as_ref()[1..].iter().any(|l| *l != 0) {return Err(ProofError::ProofFreshness)}
+        let timestamp = timestamp.as_ref()[0];
+        if timestamp > now || now - timestamp > max_age {return Err(ProofError::ProofFreshness)}
+
+        Self::verify::<EFqSponge, EFrSponge, _>(group_map, &vec![(index, lgr_comm, self)])
+    }
+
+    // This function verifies the zk-proof and checks the nonce it is bound to against the expected
+    // one and against the set of the nonces already seen, rejecting the replayed proofs
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     expected: nonce the proof has to be bound to
+    //     seen: nonces of the proofs already accepted
+    //     RETURN: verification status
+    pub fn verify_with_nonce
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+        expected: Fr<G>,
+        seen: &HashSet<Fr<G>>,
+    ) -> Result<bool, ProofError>
+    {
+        match self.nonce
+        {
+            Some(nonce) if nonce == expected && !seen.contains(&nonce) => {},
+            _ => return Err(ProofError::ProofFreshness),
+        }
+
+        Self::verify::<EFqSponge, EFrSponge, _>(group_map, &vec![(index, lgr_comm, self)])
+    }
+
+    // This function verifies the zk-proof after checking its public input against the public input
+    // commitment, the commitment being checked before the proof
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     public_commitment: commitment to the expected public input
+    pub fn verify_against_public_commitment
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+        public_commitment: &PublicCommitment<G>,
+    ) -> Result<bool, ProofError>
+    {
+        if self.public.len() > lgr_comm.len() {return Err(ProofError::ProofStructure)}
+        if !public_commitment.matches(&index.fr_sponge_params, lgr_comm, &self.public) {return Err(ProofError::PublicInputCommitment)}
+        Self::verify::<EFqSponge, EFrSponge, _>(group_map, &vec![(index, lgr_comm, self)])
+    }
+
+    // This function verifies the zk-proof and decodes its public input according to the schema
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     schema: public input schema
+    //     RETURN: decoded public input
+    pub fn verify_with_schema
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        &self,
+        group_map: &M,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+        schema: &PublicInputSchema<Fr<G>>,
+    ) -> Result<Vec<PublicInputValue<Fr<G>>>, ProofError>
+    {
+        Self::verify::<EFqSponge, EFrSponge, _>(group_map, &vec![(index, lgr_comm, self)])?;
+        schema.decode(&self.public)
+    }
+
+    // This function verifies the batch of zk-proofs continuing the transcripts of parent protocols
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: vector of Plonk proofs
+    //     sponges: parent protocol sponges of the proofs, have to be in the prover's ones state
+    //     RETURN: verification status
+    pub fn verify_with_sponges
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
+        >
+    (
+        group_map: &M,
+        proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+        sponges: Vec<EFqSponge>,
+    ) -> Result<bool, ProofError>
+    {
+        assert_eq!(proofs.len(), sponges.len());
+        if proofs.len() == 0 {
+            return Ok(true);
+        }
+
+        let params = proofs.iter().zip(sponges.into_iter()).map
+        (
+            |((index, lgr_comm, proof), sponge)| proof.verify_transcript::<EFqSponge, EFrSponge>(index, lgr_comm, sponge)
+        ).collect::<Result<Vec<_>, _>>()?;
+        Self::verify_openings::<EFqSponge, _>(group_map, proofs, params)
+    }
+
+    // This function verifies the batch of zk-proofs concurrently on the thread pool, the opening
+    // proofs of the batch are still checked with the single combined multi-scalar multiplication
+    //     group_map: hash-to-curve map of the opening argument, has to match the prover's one
+    //     proofs: vector of Plonk proofs
+    //     pool: thread pool verifying the proof transcripts
+    //     RETURN: verification status
+    #[cfg(feature = "parallel")]
+    pub fn verify_parallel
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>> + Send,
+         EFrSponge: FrSponge<Fr<G>>,
+         M: GroupMap<Fq<G>>,
         >
     (
-        group_map: &G::Map,
+        group_map: &M,
         proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+        pool: &rayon::ThreadPool,
     ) -> Result<bool, ProofError>
     {
         if proofs.len() == 0 {
             return Ok(true);
         }
 
-        let params = proofs.iter().map
+        let params = pool.install
         (
-            |(index, lgr_comm, proof)|
+            || proofs.par_iter().map
+            (
+                |(index, lgr_comm, proof)|
+                    proof.verify_transcript::<EFqSponge, EFrSponge>(index, lgr_comm, EFqSponge::new(index.fq_sponge_params.clone()))
+            ).collect::<Result<Vec<_>, _>>()
+        )?;
+        Self::verify_openings::<EFqSponge, _>(group_map, proofs, params)
+    }
+
+    // This function runs the random oracle argument of the proof and checks its linearization
+    // polynomial evaluation consistency
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     sponge: transcript sponge
+    //     RETURN: values needed for the opening proof verification
+    fn verify_transcript
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+        sponge: EFqSponge,
+    ) -> Result<(Vec<Vec<Fr<G>>>, PolyComm<G>, PolyComm<G>, EFqSponge, RandomOracles<Fr<G>>, Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>), ProofError>
+    {
+        let n = index.domain.size;
+        // commit to public input polynomial
+        let p_comm = Self::public_comm(lgr_comm, &self.public);
+
+        // the proof structure is checked along with the oracles
+        let (fq_sponge, _, oracles, alpha, p_eval, evlp, polys, _, _) = self.oracles_with_sponge::<EFqSponge, EFrSponge>(index, &p_comm, sponge)?;
+
+        // evaluate committed polynoms
+        let evals = self.evals.iter().zip(evlp.iter()).map(|(e, pt)| e.combine(*pt)).collect::<Vec<_>>();
+        let zh = index.domain.vanishing_poly_eval(oracles.zeta);
+        let zkp = index.zkpm.evaluate(oracles.zeta);
+
+        // evaluate auxiliary constraints
+        let aux = index.auxiliary.iter().zip(alpha[range::AUX].iter()).enumerate().map
+        (
+            |(i, (auxiliary, alpha))| (auxiliary.constraint)(&AuxiliaryPoint
             {
-                let n = index.domain.size;
-                // commit to public input polynomial
-                let p_comm = PolyComm::<G>::multi_scalar_mul
-                    (& lgr_comm.iter().take(proof.public.len()).map(|l| l).collect(), &proof.public.iter().map(|s| -*s).collect());
+                l: evals[0].l,
+                r: evals[0].r,
+                o: evals[0].o,
+                aux: DensePolynomial::eval_polynomial(&self.aux_evals[0][i], evlp[0]),
+                aux_w: DensePolynomial::eval_polynomial(&self.aux_evals[1][i], evlp[1]),
+                zkp,
+            }) * alpha
+        ).fold(Fr::<G>::zero(), |x, y| x + &y);
+
+        // evaluate lookup argument constraints
+        let lookup = if self.lookup_evals[0].len() == 0 {Fr::<G>::zero()} else
+        {
+            let e = |i: usize, j: usize| DensePolynomial::eval_polynomial(&self.lookup_evals[i][j], evlp[i]);
+            let width = self.lookup_evals[0].len() - 3;
+            let f = ConstraintSystem::lookup_combine(&[evals[0].l, evals[0].r, evals[0].o][0..width], oracles.lookup_combiner);
+            let t = ConstraintSystem::lookup_combine(&(0..width).map(|c| e(0, 3+c)).collect::<Vec<_>>(), oracles.lookup_combiner);
+            ConstraintSystem::lookup_eval
+            (
+                [f, e(0, 0), e(0, 2), t, e(0, 1), e(1, 1)],
+                oracles.lookup,
+                &alpha[range::AUX][index.auxiliary.len()..],
+                oracles.zeta,
+                zkp,
+                zh,
+                index.w,
+            )
+        };
 
-                let (fq_sponge, _, oracles, alpha, p_eval, evlp, polys, zeta1, _) = proof.oracles::<EFqSponge, EFrSponge>(index, &p_comm);
+        // compute linearization polynomial commitment
+        let p = vec!
+        [
+            // permutation polynomial commitments
+            &self.commitments.z_comm, &index.sigma_comm[COLUMNS-1],
+            // generic constraint polynomial commitments
+            &index.qm_comm, &index.ql_comm, &index.qr_comm, &index.qo_comm, &index.qc_comm,
+            // poseidon constraint polynomial commitments
+            &index.psm_comm, &index.rcm_comm[0], &index.rcm_comm[1], &index.rcm_comm[2],
+            // EC addition constraint polynomial commitments
+            &index.add_comm,
+            // EC variable base scalar multiplication constraint polynomial commitments
+            &index.mul1_comm, &index.mul2_comm,
+            // group endomorphism optimised variable base scalar multiplication constraint polynomial commitments
+            &index.emul1_comm, &index.emul2_comm, &index.emul3_comm,
+        ];
 
-                // evaluate committed polynoms
-                let evals = (0..2).map(|i| proof.evals[i].combine(evlp[i])).collect::<Vec<_>>();
+        // permutation linearization scalars
+        let mut s = ConstraintSystem::perm_scalars
+        (
+            &evals,
+            &oracles,
+            &index.shifts(),
+            &alpha[range::PERM],
+            n,
+            zkp,
+            index.w
+        );
+        // generic constraint/permutation linearization scalars
+        s.extend(&ConstraintSystem::gnrc_scalars(&evals[0]));
+        // poseidon constraint linearization scalars
+        s.extend(&ConstraintSystem::psdn_scalars(&evals, &index.fr_sponge_params, &alpha[range::PSDN]));
+        // EC addition constraint linearization scalars
+        s.extend(&ConstraintSystem::ecad_scalars(&evals, &alpha[range::ADD]));
+        // EC variable base scalar multiplication constraint linearization scalars
+        s.extend(&ConstraintSystem::vbmul_scalars(&evals, &alpha[range::MUL]));
+        // group endomorphism optimised variable base scalar multiplication constraint linearization scalars
+        s.extend(&ConstraintSystem::endomul_scalars(&evals, index.endo, &alpha[range::ENDML]));
+
+        let f_comm = PolyComm::multi_scalar_mul(&p, &s);
+
+        // check linearization polynomial evaluation consistency
+        if ConstraintSystem::quot_eval
+        (
+            &evals,
+            if p_eval[0].len() > 0 {p_eval[0][0]} else {Fr::<G>::zero()},
+            aux + &lookup,
+            &oracles,
+            &alpha[range::PERM],
+            zkp,
+            zh,
+            index.w
+        ) != evals[0].t * &zh {return Err(ProofError::ProofVerification)}
+
+        Ok((p_eval, p_comm, f_comm, fq_sponge, oracles, polys))
+    }
 
-                // compute linearization polynomial commitment
-                let p = vec!
-                [
-                    // permutation polynomial commitments
-                    &proof.commitments.z_comm, &index.sigma_comm[2],
-                    // generic constraint polynomial commitments
-                    &index.qm_comm, &index.ql_comm, &index.qr_comm, &index.qo_comm, &index.qc_comm,
-                    // poseidon constraint polynomial commitments
-                    &index.psm_comm, &index.rcm_comm[0], &index.rcm_comm[1], &index.rcm_comm[2],
-                    // EC addition constraint polynomial commitments
-                    &index.add_comm,
-                    // EC variable base scalar multiplication constraint polynomial commitments
-                    &index.mul1_comm, &index.mul2_comm,
-                    // group endomorphism optimised variable base scalar multiplication constraint polynomial commitments
-                    &index.emul1_comm, &index.emul2_comm, &index.emul3_comm,
-                ];
-
-                // permutation linearization scalars
-                let zkp = index.zkpm.evaluate(oracles.zeta);
-                let mut s = ConstraintSystem::perm_scalars
-                (
-                    &evals,
-                    &oracles,
-                    (index.r, index.o),
-                    &alpha[range::PERM],
-                    n,
-                    zkp,
-                    index.w
-                );
-                // generic constraint/permutation linearization scalars
-                s.extend(&ConstraintSystem::gnrc_scalars(&evals[0]));
-                // poseidon constraint linearization scalars
-                s.extend(&ConstraintSystem::psdn_scalars(&evals, &index.fr_sponge_params, &alpha[range::PSDN]));
-                // EC addition constraint linearization scalars
-                s.extend(&ConstraintSystem::ecad_scalars(&evals, &alpha[range::ADD]));
-                // EC variable base scalar multiplication constraint linearization scalars
-                s.extend(&ConstraintSystem::vbmul_scalars(&evals, &alpha[range::MUL]));
-                // group endomorphism optimised variable base scalar multiplication constraint linearization scalars
-                s.extend(&ConstraintSystem::endomul_scalars(&evals, index.endo, &alpha[range::ENDML]));
-
-                let f_comm = PolyComm::multi_scalar_mul(&p, &s);
-
-                // check linearization polynomial evaluation consistency
-                if
-                    (evals[0].f + &(if p_eval[0].len() > 0 {p_eval[0][0]} else {Fr::<G>::zero()})
-                    -
-                    ((evals[0].l + &(oracles.beta * &evals[0].sigma1) + &oracles.gamma) *
-                    &(evals[0].r + &(oracles.beta * &evals[0].sigma2) + &oracles.gamma) *
-                    (evals[0].o + &oracles.gamma) * &evals[1].z * &zkp * &oracles.alpha)
-                    -
-                    evals[0].t * &(zeta1 - &Fr::<G>::one())) * &(oracles.zeta - &Fr::<G>::one()) * &(oracles.zeta - &index.w)
-                !=
-                    ((zeta1 - &Fr::<G>::one()) * &alpha[3] * &(oracles.zeta - &index.w))
-                    +
-                    ((zeta1 - &Fr::<G>::one()) * &alpha[4] * &(oracles.zeta - &Fr::<G>::one()))
-                 {return Err(ProofError::ProofVerification)}
-
-                Ok((p_eval, p_comm, f_comm, fq_sponge, oracles, polys))
-            }
-        ).collect::<Result<Vec<_>, _>>()?;
-        
-        let mut batch = proofs.iter().zip(params.iter()).map
+    // This function lists the opened polynomials of the zk-proof in the order of the prover's opening:
+    // the previous challenge polynomials, public input, l, r, o, z, linearization, auxiliary witness,
+    // lookup multiplicities, running sum and the index lookup polynomials, sigma1, sigma2 and quotient
+    //     index: Index
+    //     p_eval, p_comm: public input polynomial evaluations and commitment
+    //     f_comm: linearization polynomial commitment
+    //     polys: previous challenge polynomial commitments and evaluations
+    //     RETURN: polynomials of the opening proof and, with the split opening groups, the sigma1
+    //         and sigma2 ones of the index opening proof rather than of the opening proof
+    fn opened_polynoms<'a>
+    (
+        &'a self,
+        index: &'a Index<G>,
+        p_eval: &'a Vec<Vec<Fr<G>>>,
+        p_comm: &'a PolyComm<G>,
+        f_comm: &'a PolyComm<G>,
+        polys: &'a Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>,
+    ) -> (Vec<(&'a PolyComm<G>, Vec<&'a Vec<Fr<G>>>, Option<usize>)>, Option<Vec<(&'a PolyComm<G>, Vec<&'a Vec<Fr<G>>>, Option<usize>)>>)
+    {
+        let mut polynoms = polys.iter().map
         (
-            |((index, _lgr_comm, proof), (p_eval, p_comm, f_comm, fq_sponge, oracles, polys))|
+            |(comm, evals)|
             {
-                let mut polynoms = polys.iter().map
-                (
-                    |(comm, evals)|
-                    {
-                        (comm, evals.iter().map(|x| x).collect(), None)
-                    }
-                ).collect::<Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>>();
-
-                polynoms.extend
-                (
-                    vec!
-                    [
-                        (p_comm, p_eval.iter().map(|e| e).collect::<Vec<_>>(), None),
-
-                        (&proof.commitments.l_comm, proof.evals.iter().map(|e| &e.l).collect::<Vec<_>>(), None),
-                        (&proof.commitments.r_comm, proof.evals.iter().map(|e| &e.r).collect::<Vec<_>>(), None),
-                        (&proof.commitments.o_comm, proof.evals.iter().map(|e| &e.o).collect::<Vec<_>>(), None),
-                        (&proof.commitments.z_comm, proof.evals.iter().map(|e| &e.z).collect::<Vec<_>>(), None),
-
-                        (f_comm, proof.evals.iter().map(|e| &e.f).collect::<Vec<_>>(), None),
-
-                        (&index.sigma_comm[0], proof.evals.iter().map(|e| &e.sigma1).collect::<Vec<_>>(), None),
-                        (&index.sigma_comm[1], proof.evals.iter().map(|e| &e.sigma2).collect::<Vec<_>>(), None),
-
-                        (&proof.commitments.t_comm, proof.evals.iter().map(|e| &e.t).collect::<Vec<_>>(), Some(index.max_quot_size)),
-                    ]
-                );
-
-                // prepare for the opening proof verification
-                (
-                    fq_sponge.clone(),
-                    vec![oracles.zeta, oracles.zeta * &index.domain.group_gen],
-                    oracles.v,
-                    oracles.u,
-                    polynoms,
-                    &proof.proof
-                )
+                (comm, evals.iter().map(|x| x).collect(), None)
             }
+        ).collect::<Vec<(&PolyComm<G>, Vec<&Vec<Fr<G>>>, Option<usize>)>>();
+
+        polynoms.extend
+        (
+            vec!
+            [
+                (p_comm, p_eval.iter().map(|e| e).collect::<Vec<_>>(), None),
+
+                (&self.commitments.l_comm, self.evals.iter().map(|e| &e.l).collect::<Vec<_>>(), None),
+                (&self.commitments.r_comm, self.evals.iter().map(|e| &e.r).collect::<Vec<_>>(), None),
+                (&self.commitments.o_comm, self.evals.iter().map(|e| &e.o).collect::<Vec<_>>(), None),
+                (&self.commitments.z_comm, self.evals.iter().map(|e| &e.z).collect::<Vec<_>>(), None),
+
+                (f_comm, self.evals.iter().map(|e| &e.f).collect::<Vec<_>>(), None),
+            ]
+        );
+        polynoms.extend(self.commitments.aux_comm.iter().enumerate().map(|(i, c)| (c, self.aux_evals.iter().map(|e| &e[i]).collect::<Vec<_>>(), None)));
+        polynoms.extend(self.commitments.lookup_comm.iter().chain(index.lookup_comm.iter().flat_map(|c| c.iter())).enumerate().
+            map(|(i, c)| (c, self.lookup_evals.iter().map(|e| &e[i]).collect::<Vec<_>>(), None)));
+
+        let mut index_polynoms = (0..COLUMNS-1).map
+        (
+            |c| (&index.sigma_comm[c], self.evals.iter().map(|e| e.sigma()[c]).collect::<Vec<_>>(), None)
         ).collect::<Vec<_>>();
+        let index_polynoms = match index.opening_groups
+        {
+            OpeningGroups::Single => {polynoms.append(&mut index_polynoms); None}
+            OpeningGroups::Split => Some(index_polynoms),
+        };
+
+        polynoms.push((&self.commitments.t_comm, self.evals.iter().map(|e| &e.t).collect::<Vec<_>>(), Some(index.max_quot_size)));
+        (polynoms, index_polynoms)
+    }
+
+    // This function computes the opening proof parts of the zk-proof for an aggregation layer,
+    // running the transcript checks of the verifier. The opened polynomials are listed in the order
+    // of the prover's opening, the index sigma polynomials being left out with the split opening groups
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: opening proof parts
+    pub fn opening_parts
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<OpeningParts<G, EFqSponge>, ProofError>
+    {
+        Ok(self.opening_parts_with_oracles::<EFqSponge, EFrSponge>(index, lgr_comm)?.0)
+    }
+
+    // This function computes the opening proof parts of the zk-proof along with the oracles of its
+    // transcript, the proof structure being checked against the index first
+    //     index: Index
+    //     lgr_comm: Lagrange base commitments of the public input
+    //     RETURN: opening proof parts and random oracles
+    pub(crate) fn opening_parts_with_oracles
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>,
+         EFrSponge: FrSponge<Fr<G>>,
+        >
+    (
+        &self,
+        index: &Index<G>,
+        lgr_comm: &Vec<PolyComm<G>>,
+    ) -> Result<(OpeningParts<G, EFqSponge>, RandomOracles<Fr<G>>), ProofError>
+    {
+        let (p_eval, p_comm, f_comm, fq_sponge, oracles, polys) =
+            self.verify_transcript::<EFqSponge, EFrSponge>(index, lgr_comm, EFqSponge::new(index.fq_sponge_params.clone()))?;
+        let (polynoms, _) = self.opened_polynoms(index, &p_eval, &p_comm, &f_comm, &polys);
+
+        Ok((OpeningParts
+        {
+            proof: &self.proof,
+            evals: &self.evals,
+            points: evaluation_points(index.domain, &index.rotations, oracles.zeta).ok_or(ProofError::ProofStructure)?,
+            v: oracles.v,
+            u: oracles.u,
+            commitments: polynoms.into_iter().map(|(c, e, s)| (c.clone(), e.into_iter().cloned().collect(), s)).collect(),
+            sponge: fq_sponge,
+        }, oracles))
+    }
+
+    // This function verifies the opening proofs of the batch of zk-proofs
+    fn verify_openings
+        <EFqSponge: Clone + FqSponge<Fq<G>, G, Fr<G>>, M: GroupMap<Fq<G>>>
+    (
+        group_map: &M,
+        proofs: &Vec<(&Index<G>, &Vec<PolyComm<G>>, &ProverProof<G>)>,
+        params: Vec<(Vec<Vec<Fr<G>>>, PolyComm<G>, PolyComm<G>, EFqSponge, RandomOracles<Fr<G>>, Vec<(PolyComm<G>, Vec<Vec<Fr<G>>>)>)>,
+    ) -> Result<bool, ProofError>
+    {
+        // the opening proofs against the same SRS are batched together
+        let mut batches: Vec<(&SRS<G>, Vec<_>)> = Vec::new();
+        for ((index, _lgr_comm, proof), (p_eval, p_comm, f_comm, fq_sponge, oracles, polys)) in proofs.iter().zip(params.iter())
+        {
+            let srs = index.srs.get_ref();
+            let group = match batches.iter().position(|(s, _)| std::ptr::eq(*s, srs) || s.g == srs.g)
+            {
+                Some(group) => group,
+                None => {batches.push((srs, Vec::new())); batches.len() - 1}
+            };
+            let batch = &mut batches[group].1;
+
+            let (polynoms, index_polynoms) = proof.opened_polynoms(index, p_eval, p_comm, f_comm, polys);
+            let evlp = evaluation_points(index.domain, &index.rotations, oracles.zeta).ok_or(ProofError::ProofStructure)?;
+
+            // the index polynomials are opened either together with or separately from the witness ones
+            match (index_polynoms, &proof.index_proof)
+            {
+                (None, None) => {}
+                (Some(index_polynoms), Some(index_proof)) =>
+                    batch.push((fq_sponge.clone(), evlp.clone(), oracles.v, oracles.u, index_polynoms, index_proof)),
+                _ => return Err(ProofError::ProofStructure),
+            }
 
-        // verify the opening proofs
-        // TODO: Account for the different SRS lengths
-        let srs = proofs[0].0.srs.get_ref();
-        for (index, _, _) in proofs.iter() {
-            assert_eq!(index.srs.get_ref().g.len(), srs.g.len());
+            // prepare for the opening proof verification
+            batch.push((fq_sponge.clone(), evlp, oracles.v, oracles.u, polynoms, &proof.proof));
         }
 
-        match srs.verify::<EFqSponge>(group_map, &mut batch, &mut thread_rng())
+        // verify the opening proofs with a single multi-scalar multiplication per SRS
+        let rng = &mut thread_rng();
+        match batches.iter_mut().all(|(srs, batch)| srs.verify::<EFqSponge, _>(group_map, batch, &mut *rng))
         {
             false => Err(ProofError::OpenProof),
             true => Ok(true)
@@ -0,0 +1,87 @@
+/*********************************************************************************************************
+
+This source file tests the verifier-only build of the crate against the precomputed Plonk proof
+of the following computation:
+
+    x * x = y
+
+with x being the public input. The proof is committed in the fixtures directory and is regenerated,
+along with its index changes, by the ignored verifier_fixture test of the dlog crate:
+
+    cargo test --release --test verifier_fixture -- --ignored
+
+**********************************************************************************************************/
+
+#![cfg(not(feature = "prover"))]
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 1;
+const FIXTURE: &[u8] = include_bytes!("fixtures/verifier_proof.bin");
+
+#[test]
+fn verifier_only()
+{
+    let srs = SRS::create(N);
+    let index = index(&srs);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = lgr_comms(&index);
+
+    let proof = ProverProof::<Affine>::deserialize_from(&mut &FIXTURE[..]).unwrap();
+    assert_eq!(proof.public, vec![Fp::from(5u64)]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the precomputed proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the proof does not verify against another public input
+    let mut forged = proof.clone();
+    forged.public[0] = Fp::from(6u64);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &forged)]).is_err(), true);
+}
+
+// x * x = y circuit with the public input x
+fn index(srs: &SRS<Affine>) -> Index<Affine>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(GateWires::wires((0,   1), (N,   N), (2*N,   2*N)), p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, N+1), (N+1, 0), (2*N+1, 2*N+1)), z, z, n, p, z),
+    ];
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(srs)
+    )
+}
+
+fn lgr_comms(index: &Index<Affine>) -> Vec<PolyComm<Affine>>
+{
+    (0..PUBLIC).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect()
+}
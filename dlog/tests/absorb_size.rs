@@ -0,0 +1,81 @@
+/*********************************************************************************************************
+
+This source file tests the fixed-size absorption of the Plonk proof commitments for the circuits of the
+distinct sizes of the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{FqSponge, poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp, fq::Fq};
+use plonk_protocol_dlog::{prover::ProverProof, index::Index};
+use oracle::rndoracle::ProofError;
+use groupmap::GroupMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+// number of the group elements absorbed by the counting sponges
+static ABSORBED: AtomicUsize = AtomicUsize::new(0);
+
+// transcript sponge counting the absorbed group elements
+#[derive(Clone)]
+struct CountingSponge(SpongeQ);
+
+impl FqSponge<Fq, Affine, Fp> for CountingSponge
+{
+    fn new(p: ArithmeticSpongeParams<Fq>) -> Self {CountingSponge(SpongeQ::new(p))}
+    fn absorb_g(&mut self, g: &[Affine]) {ABSORBED.fetch_add(g.len(), Ordering::SeqCst); self.0.absorb_g(g)}
+    fn absorb_fr(&mut self, x: &[Fp]) {self.0.absorb_fr(x)}
+    fn challenge(&mut self) -> Fp {self.0.challenge()}
+    fn challenge_fq(&mut self) -> Fq {self.0.challenge_fq()}
+    fn digest(self) -> Fp {self.0.digest()}
+}
+
+#[test]
+fn absorb_size()
+{
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the small circuit commits in single segments, the large one in several
+    let srs = SRS::create(128);
+    let mut small = common::index(&srs, 16);
+    let srs = SRS::create(64);
+    let mut large = common::index(&srs, 64);
+
+    // number of the group elements the verifier absorbs into the transcript before zeta
+    let absorbed = |index: &Index<Affine>|
+    {
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), index.cs.domain.d1.size as usize), &index, vec![]).unwrap();
+        let verifier_index = index.verifier_index();
+        let lgr_comms = common::lgr_comms(&index);
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+        ABSORBED.store(0, Ordering::SeqCst);
+        ProverProof::oracles::<CountingSponge, SpongeR>(&proof, &verifier_index, &ProverProof::public_comm(&lgr_comms, &proof.public)).unwrap();
+        ABSORBED.load(Ordering::SeqCst)
+    };
+    assert_ne!(absorbed(&small), absorbed(&large));
+
+    // the fixed-size absorption does not depend on the circuit size
+    small.absorb_size = Some(5);
+    large.absorb_size = Some(5);
+    assert_eq!(absorbed(&small), absorbed(&large));
+
+    // the fixed size has to fit the quotient segments
+    large.absorb_size = Some(4);
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), 64), &large, vec![])
+    {
+        Err(ProofError::ProofStructure) => {}
+        _ => panic!("fixed-size absorption is not validated"),
+    }
+}
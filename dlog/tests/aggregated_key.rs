@@ -0,0 +1,132 @@
+/*********************************************************************************************************
+
+This source file tests the aggregated verification key of the two circuits
+
+    0: x * x = y
+    1: x + x = y
+
+with the circuit selector and x being the public inputs
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}, aggregated::AggregatedVerifierKey};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 2;
+
+#[test]
+fn aggregated_key()
+{
+    let srs = SRS::create(N);
+    let indices = [index(&srs, 0), index(&srs, 1)];
+    let key = AggregatedVerifierKey::from_indices(&srs, &[&indices[0], &indices[1]]).unwrap();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the circuits of the same domain share the public input Lagrange base commitments
+    assert_eq!(key.lgr_comms.len(), 1);
+    for (i, l) in key.lgr_comms[0].1.iter().enumerate()
+    {
+        let mut v = vec![Fp::zero(); i+1];
+        v[i] = Fp::one();
+        let expected = srs.commit_non_hiding(&Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, indices[0].cs.domain.d1).interpolate(), None);
+        assert_eq!(l.unshifted, expected.unshifted);
+    }
+
+    // the family is over the single shared SRS
+    let other = SRS::create(N);
+    let foreign = index(&other, 1);
+    match AggregatedVerifierKey::from_indices(&srs, &[&indices[0], &foreign])
+    {
+        Err(ProofError::SrsMismatch) => {},
+        _ => panic!("index over another SRS is aggregated"),
+    }
+    match AggregatedVerifierKey::from_indices(&srs, &[])
+    {
+        Err(ProofError::PublicInputSchema) => {},
+        _ => panic!("empty family is aggregated"),
+    }
+
+    // proof of each circuit verifies against its sub-key
+    let x = Fp::from(5u64);
+    let proofs = [(0, x * &x), (1, x + &x)].iter().map
+    (
+        |(selector, y)|
+        {
+            let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness(*selector, x, *y), &indices[*selector as usize], vec![]).unwrap();
+            assert_eq!(key.selector(&proof).unwrap(), *selector as usize);
+            match key.verify::<SpongeQ, SpongeR, _>(&group_map, &proof)
+            {
+                Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+                Ok(_) => {}
+            }
+            proof
+        }
+    ).collect::<Vec<_>>();
+
+    // proof retargeted to the other circuit
+    let mut retargeted = proofs[0].clone();
+    retargeted.public[0] = Fp::one();
+    assert_eq!(key.verify::<SpongeQ, SpongeR, _>(&group_map, &retargeted).is_err(), true);
+
+    // selector out of the family
+    let mut unknown = proofs[1].clone();
+    unknown.public[0] = Fp::from(2u64);
+    match key.verify::<SpongeQ, SpongeR, _>(&group_map, &unknown)
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("proof of an unknown circuit is verified"),
+    }
+}
+
+// circuit of the selector public input constrained to the selector constant
+fn index(srs: &SRS<Affine>, selector: u64) -> Index<Affine>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    // permutation cycles: selector on the public and the constant rows, x on the public and the computation rows
+    let mut perm = (0..3*N).collect::<Vec<_>>();
+    for c in [vec![0, 2], vec![1, 3, N+3]].iter() {(0..c.len()).for_each(|j| perm[c[j]] = c[(j+1) % c.len()])}
+    let wires = (0..N).map(|i| GateWires::wires((i, perm[i]), (N+i, perm[N+i]), (2*N+i, perm[2*N+i]))).collect::<Vec<_>>();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(wires[0], p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(wires[1], p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(wires[2], p, z, z, z, -Fp::from(selector)),
+        if selector == 0 {CircuitGate::<Fp>::create_generic(wires[3], z, z, n, p, z)}
+        else {CircuitGate::<Fp>::create_generic(wires[3], p, p, n, z, z)},
+    ];
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(wires[i])));
+
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(srs)
+    )
+}
+
+fn witness(selector: u64, x: Fp, y: Fp) -> Vec<Fp>
+{
+    let mut witness = vec![Fp::zero(); 3*N];
+    witness[0] = Fp::from(selector);
+    witness[2] = Fp::from(selector);
+    witness[1] = x;
+    witness[3] = x;
+    witness[N+3] = x;
+    witness[2*N+3] = y;
+    witness
+}
@@ -0,0 +1,86 @@
+/*********************************************************************************************************
+
+This source file tests the aggregation of the opening proofs of several Plonk proofs into a single opening
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+use std::time::Instant;
+use colored::Colorize;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn aggregation()
+{
+    let rng = &mut rand::thread_rng();
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proofs = (1..5).map(|x| ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(x as u64), N), &index, vec![]).unwrap()).collect::<Vec<_>>();
+    let batch = proofs.iter().map(|proof| (&verifier_index, &lgr_comms, proof)).collect::<Vec<_>>();
+
+    let mut start = Instant::now();
+    let aggregated = ProverProof::aggregate::<SpongeQ, SpongeR, _>(&group_map, &batch, rng).unwrap();
+    println!("{}{:?}", "aggregation time: ".yellow(), start.elapsed());
+
+    start = Instant::now();
+    match ProverProof::verify_aggregated::<SpongeQ, SpongeR, _>(&group_map, &batch, &aggregated)
+    {
+        Err(error) => {panic!("Failure verifying the aggregated proofs: {}", error)},
+        Ok(_) => {}
+    }
+    println!("{}{:?}", "aggregated verification time: ".yellow(), start.elapsed());
+
+    // tampered aggregated opening proof
+    let mut malformed = aggregated.clone();
+    malformed.z1 += &Fp::one();
+    assert_eq!(ProverProof::verify_aggregated::<SpongeQ, SpongeR, _>(&group_map, &batch, &malformed).is_err(), true);
+
+    // the aggregated opening proof covers the very proofs it aggregates
+    let other = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(7u64), N), &index, vec![]).unwrap();
+    let mut substituted = batch.clone();
+    substituted[0].2 = &other;
+    assert_eq!(ProverProof::verify_aggregated::<SpongeQ, SpongeR, _>(&group_map, &substituted, &aggregated).is_err(), true);
+    assert_eq!(ProverProof::verify_aggregated::<SpongeQ, SpongeR, _>(&group_map, &batch[1..].to_vec(), &aggregated).is_err(), true);
+
+    // the succinct check of a tampered proof fails before the aggregation
+    let mut tampered = proofs[0].clone();
+    tampered.proof.sg = proofs[1].proof.sg;
+    let mut batch = batch.clone();
+    batch[0].2 = &tampered;
+    match ProverProof::aggregate::<SpongeQ, SpongeR, _>(&group_map, &batch, rng)
+    {
+        Err(ProofError::OpenProof) => {}
+        _ => panic!("proof with the tampered opening is aggregated"),
+    }
+    assert_eq!(ProverProof::aggregate::<SpongeQ, SpongeR, _>(&group_map, &vec![], rng).is_err(), true);
+
+    // the SRS of the size other than a power of two does not aggregate
+    let srs = SRS::create(N + N/2);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let lgr_comms = common::lgr_comms(&index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::aggregate::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)], rng)
+    {
+        Err(ProofError::OpenProof) => {}
+        _ => panic!("proof of the SRS size other than a power of two is aggregated"),
+    }
+}
@@ -0,0 +1,72 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof creation from the witness values supplied as futures
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverConfig};
+use groupmap::GroupMap;
+use std::{future::Future, task::{Context, Poll, RawWaker, RawWakerVTable, Waker}};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn async_witness()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    // witness values resolved by asynchronous sources
+    let source = |v: Fp| async move {Ok(v)};
+    let x = Fp::from(5u64);
+    let witness = common::witness(x, N).into_iter().map(|v| source(v)).collect::<Vec<_>>();
+
+    let proof = block_on(ProverProof::create_async::<SpongeQ, SpongeR, _, _>(&group_map, witness, &index, vec![], ProverConfig::new())).unwrap();
+    assert_eq!(proof.public, vec![x]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // failing source aborts the proof
+    let source = |i: usize, v: Fp| async move {if i == N+1 {Err(ProofError::RuntimeEnv)} else {Ok(v)}};
+    let witness = common::witness(x, N).into_iter().enumerate().map(|(i, v)| source(i, v)).collect::<Vec<_>>();
+    match block_on(ProverProof::create_async::<SpongeQ, SpongeR, _, _>(&group_map, witness, &index, vec![], ProverConfig::new()))
+    {
+        Err(ProofError::RuntimeEnv) => {},
+        _ => panic!("failing witness source is proven"),
+    }
+}
+
+// minimal executor polling the future to completion
+fn block_on<F: Future>(future: F) -> F::Output
+{
+    unsafe fn clone(_: *const ()) -> RawWaker {raw()}
+    unsafe fn noop(_: *const ()) {}
+    fn raw() -> RawWaker {RawWaker::new(std::ptr::null(), &VTABLE)}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let waker = unsafe {Waker::from_raw(raw())};
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop
+    {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {return output}
+    }
+}
@@ -0,0 +1,72 @@
+/*********************************************************************************************************
+
+This source file tests the auxiliary witness polynomials of custom constraints
+for the following computation:
+
+    x * x = y
+
+with x being the public input and the running sum of the left wires being committed
+as an auxiliary polynomial constrained as
+
+    (aux(w*X) - aux(X) - l(X)) * zkp(X) = 0
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::{ProofError, DivisionSite}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, auxiliary::{Auxiliary, AuxiliaryPoint}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn auxiliary()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    index.auxiliary.push(Auxiliary::create(|w: &Vec<Fp>| running_sum(w), constraint));
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    assert_eq!(proof.commitments.aux_comm.len(), 1);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // tampered auxiliary evaluation
+    let mut malformed = proof.clone();
+    malformed.aux_evals[0][0][0] += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &malformed)]).is_err(), true);
+
+    // auxiliary witness not satisfying the constraint
+    let mut index = common::index(&srs, N);
+    index.auxiliary.push(Auxiliary::create(|w: &Vec<Fp>| {let mut s = running_sum(w); s[3] += &Fp::one(); s}, constraint));
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![])
+    {
+        Err(ProofError::PolyDivisionAt(DivisionSite::Vanishing)) => {},
+        _ => panic!("auxiliary witness not satisfying the constraint is proven"),
+    }
+}
+
+// running sum of the left wires over the rows not blinded by the prover
+fn running_sum(witness: &Vec<Fp>) -> Vec<Fp>
+{
+    let mut sum = vec![Fp::zero(); N];
+    (0..N-3).for_each(|i| sum[i+1] = sum[i] + &witness[i]);
+    sum
+}
+
+fn constraint(p: &AuxiliaryPoint<Fp>) -> Fp
+{
+    (p.aux_w - &p.aux - &p.l) * &p.zkp
+}
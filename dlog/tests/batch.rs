@@ -79,7 +79,7 @@ where <Fp as std::str::FromStr>::Err : std::fmt::Debug
             commit += start.elapsed();
 
             start = Instant::now();
-            let proof = srs.open::<DefaultFqSponge<TweedledeeParameters, SC>>
+            let proof = srs.open::<DefaultFqSponge<TweedledeeParameters, SC>, _>
             (
                 &group_map,
                 (0..a.len()).map
@@ -134,6 +134,6 @@ where <Fp as std::str::FromStr>::Err : std::fmt::Debug
     println!("{}{:?}", "open time: ".magenta(), open);
 
     let start = Instant::now();
-    assert!(srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>>(&group_map, &mut proofs, rng));
+    assert!(srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>, _>(&group_map, &mut proofs, rng));
     println!("{}{:?}", "verification time: ".green(), start.elapsed());
 }
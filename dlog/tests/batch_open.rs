@@ -0,0 +1,64 @@
+/*****************************************************************************************************************
+
+This source file tests the single opening of the polynomial commitments of several proofs
+sharing the evaluation points against the separate openings of the proofs
+
+*****************************************************************************************************************/
+
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, Fp}, UniformRand};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, OpeningProof}};
+use oracle::utils::PolyUtils;
+use oracle::FqSponge;
+use oracle::sponge::{DefaultFqSponge};
+use oracle::poseidon::{PlonkSpongeConstants as SC};
+use ff_fft::DensePolynomial;
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+
+const PROOFS: usize = 4;
+
+// number of the group and scalar elements of the opening proof
+fn size(proof: &OpeningProof<Affine>) -> usize {2 * proof.lr.len() + 4}
+
+#[test]
+fn batch_open()
+{
+    let rng = &mut rand::thread_rng();
+
+    let size_srs = 1 << 6;
+    let srs = SRS::<Affine>::create(size_srs);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let sponge = SpongeQ::new(oracle::tweedle::fq::params());
+
+    // polynomials of each proof, one of them degree bounded
+    let polys = (0..PROOFS).map(|_| (0..3).map(|_| DensePolynomial::<Fp>::rand(size_srs - 1, rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+    let bound = |i: usize| if i == 0 {Some(size_srs)} else {None};
+    let comms = polys.iter().map(|p| p.iter().enumerate().map(|(i, a)| srs.commit(a, bound(i), rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    // the shared evaluation point and the combined challenge
+    let x = vec![Fp::rand(rng)];
+    let polyscale = Fp::rand(rng);
+    let evalscale = Fp::rand(rng);
+    let evals = polys.iter().map(|p| p.iter().map(|a| x.iter().map(|xx| a.eval(*xx, size_srs)).collect::<Vec<_>>()).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    let openings = |k: usize| polys[k].iter().zip(comms[k].iter()).enumerate().map(|(i, (a, c))| (a, bound(i), c.1.clone())).collect::<Vec<_>>();
+    let claims = |k: usize| comms[k].iter().zip(evals[k].iter()).enumerate().map(|(i, (c, e))| (&c.0, e.iter().collect::<Vec<_>>(), bound(i))).collect::<Vec<_>>();
+
+    let aggregated = srs.batch_open::<SpongeQ, _>(&group_map, (0..PROOFS).map(|k| openings(k)).collect(), &x, polyscale, evalscale, sponge.clone(), rng);
+    assert!(srs.batch_verify::<SpongeQ, _>(&group_map, (0..PROOFS).map(|k| claims(k)).collect(), &x, polyscale, evalscale, sponge.clone(), &aggregated, rng));
+
+    // the aggregated opening binds all the sets
+    let mut tampered = evals.clone();
+    tampered[PROOFS-1][1][0][0] += Fp::from(1u64);
+    let claims_tampered = (0..PROOFS).map(|k| comms[k].iter().zip(tampered[k].iter()).enumerate().
+        map(|(i, (c, e))| (&c.0, e.iter().collect::<Vec<_>>(), bound(i))).collect::<Vec<_>>()).collect();
+    assert!(!srs.batch_verify::<SpongeQ, _>(&group_map, claims_tampered, &x, polyscale, evalscale, sponge.clone(), &aggregated, rng));
+
+    // and is smaller than the separate openings
+    let separate = (0..PROOFS).map(|k| srs.open::<SpongeQ, _>(&group_map, openings(k), &x, polyscale, evalscale, sponge.clone(), rng)).collect::<Vec<_>>();
+    let mut batch = separate.iter().enumerate().map(|(k, p)| (sponge.clone(), x.clone(), polyscale, evalscale, claims(k), p)).collect::<Vec<_>>();
+    assert!(srs.verify::<SpongeQ, _>(&group_map, &mut batch, rng));
+    assert!(size(&aggregated) * PROOFS == separate.iter().map(|p| size(p)).sum::<usize>());
+    assert!(size(&aggregated) < separate.iter().map(|p| size(p)).sum::<usize>());
+}
@@ -0,0 +1,49 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof with the blinding disabled for a public wire
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::ZkConfig};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn blind_mask()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    index.set_zk_config(ZkConfig {wire_mask: [true, false, true], ..index.zk_config()});
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let witness = common::witness(x, N);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the right wire commitment is the unblinded one of its public values
+    let r = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(index.cs.gates.iter().map(|gate| witness[gate.wires.r.0]).collect(), index.cs.domain.d1).interpolate();
+    assert_eq!(proof.commitments.r_comm.unshifted, index.srs.get_ref().commit_non_hiding(&r, None).unshifted);
+    let l = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(index.cs.gates.iter().map(|gate| witness[gate.wires.l.0]).collect(), index.cs.domain.d1).interpolate();
+    assert_ne!(proof.commitments.l_comm.unshifted, index.srs.get_ref().commit_non_hiding(&l, None).unshifted);
+}
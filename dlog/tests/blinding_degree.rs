@@ -0,0 +1,72 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proofs with the wire polynomials blinded by the prover
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::ZkConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn blinding_degree()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    let proofs = [0, 1, 3].iter().map
+    (
+        |degree|
+        {
+            let mut index = common::index(&srs, N);
+            index.set_zk_config(ZkConfig {wire_degree: *degree, ..index.zk_config()});
+            let lgr_comms = common::lgr_comms(&index);
+            let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_deterministic_blinding()).unwrap();
+            match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &lgr_comms, &proof)])
+            {
+                Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+                Ok(_) => {}
+            }
+            proof
+        }
+    ).collect::<Vec<_>>();
+
+    // the blinded wires of degree n+b exceed the single segment
+    assert_eq!(proofs[0].commitments.l_comm.unshifted.len(), 1);
+    assert_eq!(proofs[1].commitments.l_comm.unshifted.len(), 2);
+    assert_eq!(proofs[2].evals[0].l.len(), 2);
+    assert_ne!(proofs[0].commitments.l_comm.unshifted[0], proofs[1].commitments.l_comm.unshifted[0]);
+
+    // the blinding polynomial does not fit a single segment
+    let mut index = common::index(&srs, N);
+    index.set_zk_config(ZkConfig {wire_degree: index.max_poly_size, ..index.zk_config()});
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![])
+    {
+        Err(ProofError::WitnessBlinding) => {},
+        _ => panic!("proof with the wire blinding exceeding the segment is created"),
+    }
+
+    // the blinding lowers the quotient bound below the generic gate degree
+    let mut index = common::index(&srs, N);
+    index.max_quot_size = 3*(N-1);
+    index.set_zk_config(ZkConfig {wire_degree: 6, ..index.zk_config()});
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![])
+    {
+        Err(ProofError::BlindingDegree {degree, gate_degree, bound}) => {assert_eq!((degree, gate_degree, bound), (6, 3, 2))}
+        _ => panic!("proof with the gate degree exceeding the blinded quotient bound is created"),
+    }
+}
@@ -0,0 +1,72 @@
+/*********************************************************************************************************
+
+This source file tests the circuit builder arithmetic gates for the following computation:
+
+    a - b = 2
+    (a - b) + b = a
+
+with a being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+#[test]
+fn builder_arithmetic()
+{
+    let circuit = |a: u64, b: u64|
+    {
+        let mut builder = CircuitBuilder::<Fp>::new();
+        let a = builder.public_input(Fp::from(a));
+        let b = builder.var(Fp::from(b));
+        let c = builder.sub(a, b);
+        builder.assert_constant(c, Fp::from(2u64));
+        let d = builder.add(c, b);
+        builder.assert_constant(d, builder.value(a));
+        builder
+    };
+
+    let builder = circuit(5, 3);
+    let (gates, witness) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..builder.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+
+    // 5 - 3 = 2
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // 5 - 4 != 2
+    let (_, witness) = circuit(5, 4).build();
+    assert_eq!(index.cs.verify(&witness), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+}
@@ -0,0 +1,84 @@
+/*********************************************************************************************************
+
+This source file tests the circuit builder multiplication, constant, equality and boolean gadgets
+for the following computation:
+
+    (a * b + 3) * bit = y, bit in {0, 1}
+
+with y being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+#[test]
+fn builder_gadgets()
+{
+    let circuit = |a: u64, b: u64, bit: u64, y: u64|
+    {
+        let mut builder = CircuitBuilder::<Fp>::new();
+        let y = builder.public_input(Fp::from(y));
+        let a = builder.var(Fp::from(a));
+        let b = builder.var(Fp::from(b));
+        let bit = builder.var(Fp::from(bit));
+        builder.boolean(bit);
+        let c = builder.constant(Fp::from(3u64));
+        let ab = builder.mul(a, b);
+        let s = builder.add(ab, c);
+        let out = builder.mul(s, bit);
+        builder.assert_eq(out, y);
+        builder
+    };
+
+    let builder = circuit(4, 5, 1, 23);
+    let (gates, _) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..builder.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+
+    // both bit values
+    for (bit, y) in [(1, 23), (0, 0)].iter()
+    {
+        let (_, witness) = circuit(4, 5, *bit, *y).build();
+        assert_eq!(index.cs.verify(&witness), true);
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+
+    // non-boolean bit, 23*2 = 46
+    let (_, witness) = circuit(4, 5, 2, 46).build();
+    assert_eq!(index.cs.verify(&witness), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+
+    // wrong public output
+    let (_, witness) = circuit(4, 5, 1, 24).build();
+    assert_eq!(index.cs.verify(&witness), false);
+}
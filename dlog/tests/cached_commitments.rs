@@ -0,0 +1,91 @@
+/*********************************************************************************************************
+
+This source file benchmarks the verifier index computation from the index polynomial commitments cached
+at the index construction against committing to the index polynomials for the following computation:
+
+    a_0 = x, a_i+1 = a_i + x
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec, IndexCommitments}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+use std::time::Instant;
+use colored::Colorize;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const ROWS: usize = 1000;
+const PROOFS: usize = 4;
+
+#[test]
+fn cached_commitments()
+{
+    let mut builder = CircuitBuilder::<Fp>::new();
+    let x = builder.public_input(Fp::from(3u64));
+    let mut a = x;
+    (1..ROWS).for_each(|_| a = builder.add(a, x));
+
+    let (gates, witness) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+
+    let mut start = Instant::now();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    println!("{}{:?}", "index construction with the commitments: ".yellow(), start.elapsed());
+
+    start = Instant::now();
+    let committed = IndexCommitments::<Affine>::create(&index.cs, &srs);
+    println!("{}{:?}", "index polynomial commitments: ".yellow(), start.elapsed());
+
+    // the verifier indexes of the repeated proofs reuse the cached commitments
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..builder.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+    for _ in 0..PROOFS
+    {
+        start = Instant::now();
+        let verifier_index = index.verifier_index();
+        println!("{}{:?}", "verifier index with the cached commitments: ".green(), start.elapsed());
+
+        for (a, b) in
+        [
+            (&verifier_index.sigma_comm[0], &committed.sigma_comm[0]), (&verifier_index.sigma_comm[1], &committed.sigma_comm[1]),
+            (&verifier_index.sigma_comm[2], &committed.sigma_comm[2]), (&verifier_index.ql_comm, &committed.ql_comm),
+            (&verifier_index.qr_comm, &committed.qr_comm), (&verifier_index.qo_comm, &committed.qo_comm),
+            (&verifier_index.qm_comm, &committed.qm_comm), (&verifier_index.qc_comm, &committed.qc_comm),
+            (&verifier_index.psm_comm, &committed.psm_comm), (&verifier_index.add_comm, &committed.add_comm),
+        ].iter()
+        {
+            assert_eq!(a.unshifted, b.unshifted);
+            assert_eq!(a.shifted, b.shifted);
+        }
+
+        start = Instant::now();
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+        println!("{}{:?}", "proof creation: ".yellow(), start.elapsed());
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+}
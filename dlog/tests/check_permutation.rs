@@ -0,0 +1,67 @@
+/*********************************************************************************************************
+
+This source file tests the validation of the witness against the copy constraints for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use oracle::rndoracle::ProofError;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn check_permutation()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    assert_eq!(index.check_permutation(&common::witness(x, N)).is_ok(), true);
+
+    // x and y swapped between the left wire of the multiplication gate and its output,
+    // breaking the cycle of x through the public input
+    let mut swapped = common::witness(x, N);
+    swapped.swap(1, 2*N+1);
+    match index.check_permutation(&swapped)
+    {
+        Err(ProofError::CopyConstraint {wire, next}) => {assert_eq!(wire, 0); assert_eq!(next, 1)}
+        _ => panic!("inconsistent wire pair is not reported"),
+    }
+
+    // the proof creation fails in the permutation product unless the witness is validated
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &swapped, &index, vec![])
+    {
+        Err(ProofError::CopyConstraint {..}) => panic!("witness is validated by default"),
+        Err(_) => {}
+        Ok(_) => panic!("proof creation with the broken copy constraint succeeded"),
+    }
+    index.permutation_check = true;
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &swapped, &index, vec![])
+    {
+        Err(ProofError::CopyConstraint {wire, next}) => {assert_eq!(wire, 0); assert_eq!(next, 1)}
+        _ => panic!("inconsistent wire pair is not reported"),
+    }
+
+    // the validated witness is proven as usual
+    let verifier_index = index.verifier_index();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
@@ -0,0 +1,71 @@
+/*********************************************************************************************************
+
+This source file tests the validation of the witness against the gate constraints for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use oracle::rndoracle::ProofError;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn check_witness()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    assert_eq!(index.check_witness(&common::witness(x, N)).is_ok(), true);
+
+    // unsatisfied multiplication gate
+    let mut unsatisfied = common::witness(x, N);
+    unsatisfied[2*N+1] += &Fp::one();
+    match index.check_witness(&unsatisfied)
+    {
+        Err(ProofError::GateUnsatisfied(gate)) => assert_eq!(gate, 1),
+        _ => panic!("unsatisfied gate is not reported"),
+    }
+    match index.check_witness(&unsatisfied[0..2*N].to_vec())
+    {
+        Err(ProofError::WitnessLength {expected, found}) => {assert_eq!(expected, 3*N); assert_eq!(found, 2*N)}
+        _ => panic!("witness length is not reported"),
+    }
+
+    // the proof creation fails in the quotient computation unless the witness is validated
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &unsatisfied, &index, vec![])
+    {
+        Err(ProofError::GateUnsatisfied(_)) => panic!("witness is validated by default"),
+        Err(_) => {}
+        Ok(_) => panic!("proof creation with the unsatisfied gate succeeded"),
+    }
+    index.witness_check = true;
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &unsatisfied, &index, vec![])
+    {
+        Err(ProofError::GateUnsatisfied(gate)) => assert_eq!(gate, 1),
+        _ => panic!("unsatisfied gate is not reported"),
+    }
+
+    // the validated witness is proven as usual
+    let verifier_index = index.verifier_index();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
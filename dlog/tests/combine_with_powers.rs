@@ -0,0 +1,60 @@
+/*********************************************************************************************************
+
+This source file tests the folding of the Plonk proof evaluations with the powers of the polynomial scaler
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::scalars::ProofEvaluations;
+use oracle::{poseidon::{SpongeConstants, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, combined_inner_product}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn combine_with_powers()
+{
+    // the powers of the scaler follow the opening order
+    let e = ProofEvaluations::<Fp>
+    {
+        l: Fp::from(1u64),
+        r: Fp::from(2u64),
+        o: Fp::from(3u64),
+        z: Fp::from(4u64),
+        t: Fp::from(8u64),
+        f: Fp::from(5u64),
+        sigma1: Fp::from(6u64),
+        sigma2: Fp::from(7u64),
+    };
+    assert_eq!(e.combine_with_powers(Fp::from(2u64)), Fp::from(1 + 2*2 + 3*4 + 4*8 + 5*16 + 6*32 + 7*64 + 8*128u64));
+
+    // the quotient polynomial of the proof is committed in a single segment with the large enough SRS
+    let srs = SRS::create(SC::SPONGE_BOX * N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    assert_eq!(proof.commitments.t_comm.unshifted.len(), 1);
+
+    // the fold matches the unshifted combined inner product of the polynomials at zeta
+    let oracles = ProverProof::oracles::<SpongeQ, SpongeR>(&proof, &verifier_index, &ProverProof::public_comm(&lgr_comms, &proof.public)).unwrap().2;
+    let e = &proof.evals[0];
+    let es = [&e.l, &e.r, &e.o, &e.z, &e.f, &e.sigma1, &e.sigma2, &e.t].iter().map(|e| (vec![*e], None)).collect::<Vec<_>>();
+    assert_eq!
+    (
+        e.combine(Fp::one()).combine_with_powers(oracles.v),
+        combined_inner_product::<Affine>(&[oracles.zeta], &oracles.v, &oracles.u, &es, srs.g.len())
+    );
+}
@@ -0,0 +1,85 @@
+/*********************************************************************************************************
+
+This source file implements the test fixture shared by the Plonk proof tests for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+#![allow(dead_code)]
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::poseidon::ArithmeticSpongeParams;
+use commitment_dlog::{srs::SRS, commitment::PolyComm};
+use algebra::{Field, tweedle::{dee::Affine, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::index::{Index, SRSSpec};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+
+pub const PUBLIC: usize = 1;
+
+// This function creates the x * x = y circuit gates padded to the domain size
+//     n: Plonk domain size
+//     RETURN: circuit gates
+pub fn gates(n: usize) -> Vec<CircuitGate<Fp>>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let m = -Fp::one();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(GateWires::wires((0,   1), (n,   n), (2*n,   2*n)), p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, n+1), (n+1, 0), (2*n+1, 2*n+1)), z, z, m, p, z),
+    ];
+    (gates.len()..n).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (n+i, n+i), (2*n+i, 2*n+i)))));
+    gates
+}
+
+// This function creates the constraint system of the x * x = y circuit
+//     n: Plonk domain size
+//     public: number of public inputs
+//     RETURN: constraint system and the endomorphism coefficient of the index
+pub fn constraints(n: usize, public: usize) -> (ConstraintSystem<Fp>, Fp)
+{
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    (ConstraintSystem::<Fp>::create(gates(n), oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, public).unwrap(), endo_q)
+}
+
+// This function creates the index of the x * x = y circuit with the public input x
+//     srs: SRS of the index
+//     n: Plonk domain size
+//     RETURN: Index
+pub fn index(srs: &SRS<Affine>, n: usize) -> Index<Affine>
+{
+    let (cs, endo_q) = constraints(n, PUBLIC);
+    Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(srs))
+}
+
+// This function computes the witness of the x * x = y circuit
+//     x: public input
+//     n: Plonk domain size
+//     RETURN: witness
+pub fn witness(x: Fp, n: usize) -> Vec<Fp>
+{
+    let mut witness = vec![Fp::zero(); 3*n];
+    witness[0] = x;
+    witness[1] = x;
+    witness[n+1] = x;
+    witness[2*n+1] = x.square();
+    witness
+}
+
+// This function commits the Lagrange bases of the public input of the index
+//     index: Index
+//     RETURN: Lagrange base commitments
+pub fn lgr_comms(index: &Index<Affine>) -> Vec<PolyComm<Affine>>
+{
+    (0..index.cs.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect()
+}
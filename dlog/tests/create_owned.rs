@@ -0,0 +1,54 @@
+/*********************************************************************************************************
+
+This source file tests the proof creation consuming and zeroing the witness for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn create_owned()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let mut buffer = common::witness(x, N);
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Owned(&mut buffer), &index, vec![], ProverConfig::new()).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the witness buffer is left zeroed
+    assert_eq!(buffer.len(), 3*N);
+    assert_eq!(buffer.iter().all(|w| w.is_zero()), true);
+
+    // the proof matches the one of the borrowed witness
+    let borrowed = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    assert_eq!(proof.public, borrowed.public);
+    assert_eq!(proof.commitments.t_comm.unshifted.len(), borrowed.commitments.t_comm.unshifted.len());
+
+    // the unsatisfied witness fails the proof creation
+    let mut unsatisfied = common::witness(x, N);
+    unsatisfied[2*N+1] += &Fp::one();
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Owned(&mut unsatisfied), &index, vec![], ProverConfig::new()).is_err(), true);
+}
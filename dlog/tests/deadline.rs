@@ -0,0 +1,54 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof creation within the deadline
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+use std::time::{Duration, Instant};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn deadline()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let x = Fp::from(5u64);
+
+    // generous deadline
+    let start = Instant::now();
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>
+        (&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_deadline(start + Duration::from_secs(3600))).unwrap();
+    let elapsed = start.elapsed();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // impossibly tight deadline terminates the proving before its first phase
+    let start = Instant::now();
+    match ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_deadline(start))
+    {
+        Err(ProofError::DeadlineExceeded) => {},
+        _ => panic!("proof is created past the deadline"),
+    }
+    assert!(start.elapsed() < elapsed);
+}
@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the polynomials computed by the Plonk prover for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn debug_polys()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let (proof, polys) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.debug_polys())).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the wire polynomials interpolate the witness columns
+    let domain = index.cs.domain.d1;
+    for (i, w) in [&polys.l, &polys.r, &polys.o].iter().enumerate()
+    {
+        assert_eq!(w.evaluate_over_domain_by_ref(domain).evals, common::witness(x, N)[i*N..(i+1)*N].to_vec());
+    }
+
+    // the permutation polynomial starts and ends in 1 before the zero-knowledge rows
+    let z = polys.z.evaluate_over_domain_by_ref(domain);
+    assert_eq!(z.evals[0], Fp::one());
+    assert_eq!(z.evals[N-3], Fp::one());
+
+    // the public input polynomial is the negated public input
+    assert_eq!(polys.p.evaluate_over_domain_by_ref(domain).evals[0], -x);
+
+    // the quotient polynomial is within its degree bound
+    assert_eq!(polys.t.coeffs.len(), index.max_quot_size);
+    assert_eq!(polys.t.is_zero(), false);
+}
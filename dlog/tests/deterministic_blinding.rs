@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the deterministic Plonk proofs blinded from the transcript sponge seeded by the witness
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn deterministic_blinding()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let serialize = |proof: &ProverProof<Affine>| {let mut bytes = vec![]; proof.serialize_to(&mut bytes).unwrap(); bytes};
+
+    // the proofs of the same witness are reproducible
+    let proofs = (0..2).map(|_| ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_deterministic_blinding()).map(|(proof, _)| proof).unwrap()).collect::<Vec<_>>();
+    assert_eq!(serialize(&proofs[0]), serialize(&proofs[1]));
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proofs[0])])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the witness differing in an unconstrained private right wire value only, of the same public input
+    // and the same output wire values, blinds the output wire commitment differently
+    let mut private = common::witness(x, N);
+    private[N+5] = Fp::from(7u64);
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&private), &index, vec![], ProverConfig::new().with_deterministic_blinding()).unwrap();
+    assert_eq!(proof.public, proofs[0].public);
+    assert_ne!(proof.commitments.o_comm.unshifted, proofs[0].commitments.o_comm.unshifted);
+    assert_ne!(serialize(&proof), serialize(&proofs[0]));
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
@@ -0,0 +1,39 @@
+/*********************************************************************************************************
+
+This source file tests the evaluation domain size planner
+
+**********************************************************************************************************/
+
+use plonk_circuits::domains::{optimal_domain_size, plan_domain_size, quotient_srs_size, DomainPlan};
+
+#[test]
+fn domain_planner()
+{
+    // circuits filling their domains are padded
+    assert_eq!(plan_domain_size(1024), DomainPlan::Pad(1024));
+    assert_eq!(optimal_domain_size(1024), (1024, 0));
+    assert_eq!(optimal_domain_size(1000), (1024, 24));
+
+    // circuits just above a power of two are split
+    assert_eq!(plan_domain_size(1025), DomainPlan::Split(1024, 1));
+    assert_eq!(plan_domain_size(1100), DomainPlan::Split(1024, 128));
+    assert_eq!(optimal_domain_size(1100), (1024, 52));
+
+    // the plans minimize the SRS over the single padded domain and the splits at the powers of two
+    for gates in [5, 17, 100, 600, 700, 1025, 1536, 1537, 2047].iter()
+    {
+        let plan = plan_domain_size(*gates);
+        let padded = gates.next_power_of_two();
+        assert!(plan.srs_size() <= quotient_srs_size(padded));
+        assert!(plan.wasted_rows(*gates) <= padded - gates);
+        match plan
+        {
+            DomainPlan::Pad(n) => assert_eq!(n, padded),
+            DomainPlan::Split(n, m) =>
+            {
+                assert_eq!(n + m >= *gates, true);
+                assert_eq!(plan.srs_size(), quotient_srs_size(padded / 2));
+            }
+        }
+    }
+}
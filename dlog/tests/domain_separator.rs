@@ -0,0 +1,57 @@
+/*********************************************************************************************************
+
+This source file tests the domain separation of the Plonk proof transcripts of the indices
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn domain_separator()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let separated = |separator: Option<&[u8]>|
+    {
+        let mut index = common::index(&srs, N);
+        index.domain_separator = separator.map(|s| s.to_vec());
+        index
+    };
+    let (first, second, none) = (separated(Some(b"first protocol")), separated(Some(b"second protocol")), separated(None));
+    let (first_verifier, second_verifier) = (first.verifier_index(), second.verifier_index());
+    let lgr_comms = common::lgr_comms(&first);
+
+    let (proof, oracles) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &first, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.oracles().clone())).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&first_verifier, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the separators change the challenges of the same witness
+    let (_, other) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &second, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.oracles().clone())).unwrap();
+    let (_, plain) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &none, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.oracles().clone())).unwrap();
+    assert_ne!(oracles.beta, other.beta);
+    assert_ne!(oracles.zeta, other.zeta);
+    assert_ne!(oracles.beta, plain.beta);
+
+    // the proof does not verify against the index of the other separator
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&second_verifier, &lgr_comms, &proof)]).is_err(), true);
+}
@@ -0,0 +1,64 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proofs with the empty and zero public input for the following computation:
+
+    x * x = y
+
+with x being the public input, or the private one with no public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec, PublicAbsorb}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn empty_public()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    for mode in [PublicAbsorb::Batched, PublicAbsorb::PerElement].iter()
+    {
+        // no public input
+        let mut empty = index(&srs, 0);
+        empty.public_absorb_mode = *mode;
+        let verifier_index = empty.verifier_index();
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &empty, vec![]).unwrap();
+        assert_eq!(proof.public.len(), 0);
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+
+        // zero public input, committed as the empty one
+        let mut zero = index(&srs, common::PUBLIC);
+        zero.public_absorb_mode = *mode;
+        let verifier_index = zero.verifier_index();
+        let lgr_comms = common::lgr_comms(&zero);
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::zero(), N), &zero, vec![]).unwrap();
+        assert_eq!(proof.public, vec![Fp::zero()]);
+        assert_eq!(ProverProof::public_comm(&lgr_comms, &proof.public).unshifted.len(), 0);
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+}
+
+// x * x = y circuit with the given number of public inputs
+fn index(srs: &SRS<Affine>, public: usize) -> Index<Affine>
+{
+    let (cs, endo_q) = common::constraints(N, public);
+    Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(srs))
+}
@@ -0,0 +1,48 @@
+/*********************************************************************************************************
+
+This source file tests the flattening of the Plonk proof into the base field elements
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp, fq::Fq}, Zero};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 1;
+
+#[test]
+fn field_vec()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    let v = proof.to_field_vec();
+
+    // 5 wire, permutation and quotient commitment chunks along with the quotient shift,
+    // 2 evaluation points of 7 single chunk and the quotient evaluations,
+    // opening proof rounds, delta, z1, z2, sg and the public input
+    let t = proof.evals[0].t.len();
+    let rounds = proof.proof.lr.len();
+    assert_eq!(v.len(), 2 * (4 + t + 1) + 2 * 2 * (7 + t) + (4 * rounds + 2 + 2 * 2 + 2) + 2 * PUBLIC);
+
+    // the layout is canonical
+    assert_eq!(v, proof.clone().to_field_vec());
+    let (x, y) = proof.commitments.l_comm.unshifted[0].to_coordinates().unwrap();
+    assert_eq!((v[0], v[1]), (x, y));
+    assert_eq!(v[v.len() - 2], Fq::from(5u64));
+    assert_eq!(v[v.len() - 1], Fq::zero());
+}
@@ -0,0 +1,56 @@
+/*********************************************************************************************************
+
+This source file tests the verification of the Plonk proof freshness
+for the following computation:
+
+    x * x = y
+
+with x being the public input timestamp
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn freshness()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let now = 1_600_000_000u64;
+    let max_age = 60;
+
+    // fresh proof
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(now - 10), N), &index, vec![]).unwrap();
+    match proof.verify_with_freshness::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, 0, max_age, now)
+    {
+        Err(error) => {panic!("Failure verifying the fresh proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // stale proof
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(now - 100), N), &index, vec![]).unwrap();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)]).is_ok(), true);
+    match proof.verify_with_freshness::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, 0, max_age, now)
+    {
+        Err(ProofError::ProofFreshness) => {},
+        _ => panic!("stale proof is verified"),
+    }
+
+    // proof from the future
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(now + 10), N), &index, vec![]).unwrap();
+    assert_eq!(proof.verify_with_freshness::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, 0, max_age, now).is_err(), true);
+}
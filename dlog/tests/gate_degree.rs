@@ -0,0 +1,88 @@
+/*********************************************************************************************************
+
+This source file tests the validation of the constraint degrees against the quotient polynomial bound
+for the following computation:
+
+    x * x = y
+
+with x being the public input and the running sum of the left wires being committed
+as an auxiliary polynomial constrained either as
+
+    (aux(w*X) - aux(X) - l(X)) * zkp(X) = 0
+
+or by the same constraint raised to the power exceeding the quotient polynomial bound
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate};
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{Field, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec}, auxiliary::{Auxiliary, AuxiliaryPoint}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn gate_degree()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    let index = common::index(&srs, N);
+    assert_eq!(index.cs.max_gate_degree(), 3);
+    assert_eq!(index.quotient_degree_bound(), 6);
+    assert_eq!(CircuitGate::<Fp>::zero(GateWires::wires((0, 0), (N, N), (2*N, 2*N))).degree(), 0);
+
+    // constraint of the degree within the bound
+    let (cs, endo_q) = common::constraints(N, common::PUBLIC);
+    let index = Index::<Affine>::create_with_auxiliary
+    (
+        cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs),
+        vec![Auxiliary::create(|w: &Vec<Fp>| running_sum(w), constraint).with_degree(2)],
+    ).unwrap();
+    assert_eq!(index.max_gate_degree(), 3);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // constraint of the degree exceeding the bound is rejected at the index construction
+    let high = || Auxiliary::create(|w: &Vec<Fp>| running_sum(w), |p: &AuxiliaryPoint<Fp>| constraint(p).pow(&[7u64])).with_degree(14);
+    let (cs, endo_q) = common::constraints(N, common::PUBLIC);
+    match Index::<Affine>::create_with_auxiliary(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs), vec![high()])
+    {
+        Err(ProofError::GateDegree) => {},
+        _ => panic!("constraint exceeding the quotient bound is accepted"),
+    }
+
+    // and by the prover before the quotient computation
+    let mut index = common::index(&srs, N);
+    index.auxiliary.push(high());
+    assert_eq!(index.max_gate_degree(), 14);
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![])
+    {
+        Err(ProofError::GateDegree) => {},
+        _ => panic!("constraint exceeding the quotient bound is proven"),
+    }
+}
+
+// running sum of the left wires over the rows not blinded by the prover
+fn running_sum(witness: &Vec<Fp>) -> Vec<Fp>
+{
+    let mut sum = vec![Fp::zero(); N];
+    (0..N-3).for_each(|i| sum[i+1] = sum[i] + &witness[i]);
+    sum
+}
+
+fn constraint(p: &AuxiliaryPoint<Fp>) -> Fp
+{
+    (p.aux_w - &p.aux - &p.l) * &p.zkp
+}
@@ -0,0 +1,87 @@
+/*********************************************************************************************************
+
+This source file tests the run-length encoded circuit of the following computation:
+
+    x_i+1 = x_i * y_i, 0 <= i < 1000
+
+with the 1000 identical multiplication gates wired by the output to the left wire of the next gate
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, rle::{GateRun, expand}};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero, UniformRand};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+use std::mem::size_of;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 1024; // Plonk domain size
+const RUN: usize = 1000;
+
+#[test]
+fn gate_runs()
+{
+    let rng = &mut rand::thread_rng();
+    let z = Fp::zero();
+
+    // the run of the multiplication gates and the wiring of the outputs to the next left wires
+    let gate = CircuitGate::<Fp>::create_generic(GateWires::wires((0, 0), (N, N), (2*N, 2*N)), z, z, -Fp::one(), Fp::one(), z);
+    let runs = vec![GateRun::new(gate, RUN)];
+    let cycles = (0..RUN-1).map(|i| vec![(2, i), (0, i+1)]).collect::<Vec<_>>();
+
+    // the run-length encoded circuit matches the explicit one
+    let gates = expand(&runs, &cycles, N).unwrap();
+    assert_eq!(gates.len(), RUN);
+    assert!(runs.len() * size_of::<GateRun<Fp>>() < gates.len() * size_of::<CircuitGate<Fp>>());
+
+    let cs = ConstraintSystem::<Fp>::create_rle(&runs, &cycles, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let explicit = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    assert_eq!(cs.sigmal1, explicit.sigmal1);
+    assert_eq!(cs.qmm, explicit.qmm);
+    assert_eq!(cs.qom, explicit.qom);
+
+    // the gate vector is never materialised, the gates being expanded from the runs row by row
+    assert!(cs.gates.is_empty() && cs.runs.is_some());
+    assert_eq!(cs.gate_iter().count(), N);
+    for (gate, expected) in cs.gate_iter().zip(explicit.gates.iter())
+    {
+        assert!(gate.typ == expected.typ && gate.c == expected.c);
+        assert!(gate.wires.l == expected.wires.l && gate.wires.r == expected.wires.r && gate.wires.o == expected.wires.o);
+    }
+
+    // malformed wiring is rejected
+    assert!(ConstraintSystem::<Fp>::create_rle(&runs, &vec![vec![(2, RUN), (0, 0)]], oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).is_none());
+    assert!(ConstraintSystem::<Fp>::create_rle(&runs, &vec![vec![(3, 0), (0, 1)]], oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).is_none());
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs));
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let mut witness = vec![Fp::zero(); 3*N];
+    witness[0] = Fp::rand(rng);
+    for i in 0..RUN
+    {
+        witness[N+i] = Fp::rand(rng);
+        witness[2*N+i] = witness[i] * &witness[N+i];
+        if i+1 < RUN {witness[i+1] = witness[2*N+i]}
+    }
+    assert_eq!(index.cs.verify(&witness), true);
+    assert!(index.cs.gates.is_empty());
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the broken chain is not satisfied
+    witness[RUN/2] += &Fp::one();
+    assert_eq!(index.cs.verify(&witness), false);
+}
@@ -0,0 +1,72 @@
+/*****************************************************************************************************************
+
+This source file tests that the hash-to-curve map used by the opening argument is selectable
+and that the prover and the verifier have to agree on it
+
+*****************************************************************************************************************/
+
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, Fp, Fq}, UniformRand};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve}};
+use oracle::utils::PolyUtils;
+use oracle::FqSponge;
+use oracle::sponge::{DefaultFqSponge};
+use oracle::poseidon::{PlonkSpongeConstants as SC};
+use ff_fft::DensePolynomial;
+use groupmap::GroupMap;
+
+type Map = <Affine as CommitmentCurve>::Map;
+
+// the default map composed with the curve negation
+struct NegatedMap(Map);
+
+impl GroupMap<Fq> for NegatedMap
+{
+    fn setup() -> Self {NegatedMap(Map::setup())}
+
+    fn to_group(&self, u: Fq) -> (Fq, Fq)
+    {
+        let (x, y) = self.0.to_group(u);
+        (x, -y)
+    }
+
+    fn batch_to_group_x(&self, ts: Vec<Fq>) -> Vec<[Fq; 3]> {self.0.batch_to_group_x(ts)}
+}
+
+#[test]
+fn group_map()
+{
+    let rng = &mut rand::thread_rng();
+
+    let size = 1 << 5;
+    let srs = SRS::<Affine>::create(size);
+    let sponge = DefaultFqSponge::<TweedledeeParameters, SC>::new(oracle::tweedle::fq::params());
+
+    let default = Map::setup();
+    let negated = NegatedMap::setup();
+
+    let a = DensePolynomial::<Fp>::rand(size - 1, rng);
+    let x = vec![Fp::rand(rng), Fp::rand(rng)];
+    let polymask = Fp::rand(rng);
+    let evalmask = Fp::rand(rng);
+
+    let (comm, omega) = srs.commit(&a, None, rng);
+    let evals = x.iter().map(|xx| a.eval(*xx, size)).collect::<Vec<_>>();
+
+    let proofs =
+    [
+        srs.open::<DefaultFqSponge<TweedledeeParameters, SC>, _>
+            (&default, vec![(&a, None, omega.clone())], &x, polymask, evalmask, sponge.clone(), rng),
+        srs.open::<DefaultFqSponge<TweedledeeParameters, SC>, _>
+            (&negated, vec![(&a, None, omega.clone())], &x, polymask, evalmask, sponge.clone(), rng),
+    ];
+
+    let batch = |proof| vec![(sponge.clone(), x.clone(), polymask, evalmask, vec![(&comm, evals.iter().collect::<Vec<_>>(), None)], proof)];
+
+    // the openings verify under the map they were produced with
+    assert!(srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>, _>(&default, &mut batch(&proofs[0]), rng));
+    assert!(srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>, _>(&negated, &mut batch(&proofs[1]), rng));
+
+    // and are rejected under a different one
+    assert!(!srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>, _>(&negated, &mut batch(&proofs[0]), rng));
+    assert!(!srs.verify::<DefaultFqSponge<TweedledeeParameters, SC>, _>(&default, &mut batch(&proofs[1]), rng));
+}
@@ -0,0 +1,120 @@
+/*********************************************************************************************************
+
+This source file tests the serde round trip of the Plonk index polynomial commitments through JSON and
+bincode, and the byte encodings of the index, commitments and opening proofs, for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, IndexCommitments, SRSSpec, PublicAbsorb}, stream::ByteEncoding};
+use commitment_dlog::commitment::{PolyComm, OpeningProof};
+use oracle::poseidon::ArithmeticSpongeParams;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn index_serde()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+
+    let mut bytes = Vec::new();
+    index.commitments.serialize_to(&mut bytes).unwrap();
+
+    let json = serde_json::to_string(&index.commitments).unwrap();
+    let binary = bincode::serialize(&index.commitments).unwrap();
+    for restored in [serde_json::from_str::<IndexCommitments<Affine>>(&json).unwrap(), bincode::deserialize::<IndexCommitments<Affine>>(&binary).unwrap()].iter()
+    {
+        let mut restored_bytes = Vec::new();
+        restored.serialize_to(&mut restored_bytes).unwrap();
+        assert_eq!(restored_bytes, bytes);
+
+        // the verifier key restored from the stored commitments verifies the proof
+        let mut restored_index = common::index(&srs, N);
+        restored_index.commitments = restored.clone();
+        let verifier_index = restored_index.verifier_index();
+        assert_eq!(verifier_index.sigma_comm, index.commitments.sigma_comm);
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+
+    // the standalone commitment encoding round trips
+    let mut comm = Vec::new();
+    plonk_protocol_dlog::stream::write_comm(&index.commitments.qm_comm, &mut comm).unwrap();
+    assert_eq!(plonk_protocol_dlog::stream::read_comm::<Affine, _>(&mut &comm[..]).unwrap(), index.commitments.qm_comm);
+
+    // truncated encodings are rejected
+    assert!(IndexCommitments::<Affine>::deserialize_from(&mut &bytes[..bytes.len()-1]).is_err());
+    assert!(bincode::deserialize::<IndexCommitments<Affine>>(&binary[..binary.len()-1]).is_err());
+}
+
+#[test]
+fn index_bytes()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    index.public_absorb_mode = PublicAbsorb::PerElement;
+    index.domain_separator = Some(b"index_bytes".to_vec());
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+
+    // the index restored from its encoding keeps the configuration and commitments
+    let bytes = index.to_bytes().unwrap();
+    let restored = Index::<Affine>::from_bytes
+    (
+        &bytes,
+        oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>,
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    ).unwrap();
+    assert_eq!(restored.to_bytes().unwrap(), bytes);
+    assert_eq!(restored.public_absorb_mode, PublicAbsorb::PerElement);
+    assert_eq!(restored.cs.sigmam, index.cs.sigmam);
+
+    // the proof of the restored index verifies against the original one
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &restored, vec![]).unwrap();
+    let verifier_index = index.verifier_index();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the commitments, opening proofs and proofs round trip through their byte encodings
+    assert_eq!(PolyComm::<Affine>::from_bytes(&index.commitments.qm_comm.to_bytes()).unwrap(), index.commitments.qm_comm);
+    assert_eq!(OpeningProof::<Affine>::from_bytes(&proof.proof.to_bytes()).unwrap(), proof.proof);
+    assert_eq!(ProverProof::<Affine>::from_bytes(&proof.to_bytes()).unwrap().to_bytes(), proof.to_bytes());
+    assert_eq!(IndexCommitments::<Affine>::from_bytes(&index.commitments.to_bytes()).unwrap().to_bytes(), index.commitments.to_bytes());
+
+    // truncated encodings and trailing bytes are rejected
+    let mut comm = index.commitments.qm_comm.to_bytes();
+    assert!(PolyComm::<Affine>::from_bytes(&comm[..comm.len()-1]).is_err());
+    comm.push(0);
+    assert!(PolyComm::<Affine>::from_bytes(&comm).is_err());
+    assert!(Index::<Affine>::from_bytes
+    (
+        &bytes[..bytes.len()-1],
+        oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>,
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    ).is_err());
+}
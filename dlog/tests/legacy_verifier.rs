@@ -0,0 +1,88 @@
+/*********************************************************************************************************
+
+This source file tests the verification of the Plonk proofs of the earlier format versions with the legacy verifier
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::OpeningOrder, stream::PROOF_VERSION, legacy::{LegacyVerifier, LEGACY_PROOF_VERSION}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn legacy_verifier()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    let mut bytes = vec![];
+    proof.serialize_to(&mut bytes).unwrap();
+
+    // issued proofs of all the legacy versions verify with the legacy verifier
+    let mut legacy = bytes.clone();
+    // the earlier versions lack the empty lookup argument commitment and evaluation vectors
+    // and the count of the evaluation points beyond the default two
+    legacy.truncate(legacy.len() - 4*8);
+    let latest_legacy = legacy.clone();
+    for version in (1..=LEGACY_PROOF_VERSION).rev()
+    {
+        legacy[0..4].copy_from_slice(&version.to_le_bytes());
+        let (issued, tag) = ProverProof::<Affine>::deserialize_versioned_from(&mut &legacy[..]).unwrap();
+        assert_eq!(tag, version);
+        assert_eq!(LegacyVerifier::supports(tag), true);
+        match LegacyVerifier::verify::<Affine, SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &issued)])
+        {
+            Err(error) => panic!("Failure verifying the legacy proof: {:?}", error),
+            Ok(_) => {}
+        }
+        match ProverProof::verify_versioned::<SpongeQ, SpongeR, _>(&group_map, tag, &vec![(&verifier_index, &lgr_comms, &issued)])
+        {
+            Err(error) => panic!("Failure verifying the legacy proof: {:?}", error),
+            Ok(_) => {}
+        }
+        // the earlier version lacks the latest trailing presence flag
+        assert_eq!(legacy.pop(), Some(0));
+    }
+
+    // once the transcript of the latest protocol evolves, here to the digested evaluations, the latest
+    // verifier rejects the issued proofs while the legacy one still verifies them
+    let (issued, tag) = ProverProof::<Affine>::deserialize_versioned_from(&mut &latest_legacy[..]).unwrap();
+    let mut evolved = index.verifier_index();
+    evolved.opening_order = OpeningOrder::DigestedEvaluations;
+    assert_eq!(tag, LEGACY_PROOF_VERSION);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&evolved, &lgr_comms, &issued)]).is_err(), true);
+    match ProverProof::verify_versioned::<SpongeQ, SpongeR, _>(&group_map, tag, &vec![(&evolved, &lgr_comms, &issued)])
+    {
+        Err(error) => panic!("Failure verifying the legacy proof after the protocol change: {:?}", error),
+        Ok(_) => {}
+    }
+
+    // the legacy verifier checks the proofs
+    let mut tampered = issued.clone();
+    tampered.evals[0].t[0] += &Fp::from(1u64);
+    assert_eq!(LegacyVerifier::verify::<Affine, SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &tampered)]).is_err(), true);
+
+    // unknown version
+    match ProverProof::verify_versioned::<SpongeQ, SpongeR, _>(&group_map, PROOF_VERSION + 1, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(ProofError::UnsupportedVersion {found, ..}) => assert_eq!(found, PROOF_VERSION + 1),
+        _ => panic!("proof of the unknown version is verified"),
+    }
+}
@@ -0,0 +1,81 @@
+/*********************************************************************************************************
+
+This source file tests the lookup argument of the Plonk proof for the range check
+
+    x_i in {0, ..., 7}
+
+with x_i being the private left wire values of the looked up rows
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, lookup::LookupTable};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero, One};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn lookup_argument()
+{
+    let table = LookupTable::create((0..8).map(|i| vec![Fp::from(i as u64)]).collect()).unwrap();
+    let rows = [0, 1, 2, 5];
+
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let cs = cs.with_lookup(&table, &rows).unwrap();
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs));
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let witness = |x: [u64; 4]|
+    {
+        let mut witness = vec![Fp::zero(); 3*N];
+        rows.iter().zip(x.iter()).for_each(|(r, x)| witness[*r] = Fp::from(*x));
+        // the rows not looked up are not range checked
+        witness[3] = Fp::from(100u64);
+        witness
+    };
+
+    // values in the table, repeated ones included
+    let witness_in = witness([3, 7, 3, 0]);
+    assert_eq!(index.cs.verify(&witness_in), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_in, &index, vec![]).unwrap();
+    assert_eq!(proof.commitments.lookup_comm.len(), 2);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the lookup proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // tampered running sum evaluation
+    let mut malformed = proof.clone();
+    malformed.lookup_evals[1][1][0] += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &malformed)]).is_err(), true);
+
+    // proof without the lookup argument
+    let mut malformed = proof.clone();
+    malformed.commitments.lookup_comm.clear();
+    malformed.lookup_evals = vec![Vec::new(), Vec::new()];
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &malformed)]).is_err(), true);
+
+    // value out of the table
+    let witness_out = witness([3, 8, 3, 0]);
+    assert_eq!(index.cs.verify(&witness_out), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_out, &index, vec![]).is_err(), true);
+
+    // the table and the looked up rows precede the zero-knowledge rows
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    assert_eq!(cs.clone().with_lookup(&table, &[N-3]).is_none(), true);
+    let large = LookupTable::create((0..N-2).map(|i| vec![Fp::from(i as u64)]).collect()).unwrap();
+    assert_eq!(cs.with_lookup(&large, &rows).is_none(), true);
+}
@@ -0,0 +1,104 @@
+/*********************************************************************************************************
+
+This source file tests the lookup tables loaded from the CSV and JSON encodings for the following
+computation:
+
+    table(k) = y
+
+with y being the public input and k the private key to the table
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem, lookup::LookupTable};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+#[test]
+fn lookup_table()
+{
+    let csv = "# x, x^2 + 1\n0, 1\n1, 2\n2, 5\n3, 0xa\n\n4, 17\n";
+    let table = LookupTable::<Fp>::from_csv(&mut csv.as_bytes()).unwrap();
+    assert_eq!(table.rows.len(), 5);
+    assert_eq!(table.get(Fp::from(3u64), 1), Some(Fp::from(10u64)));
+
+    let json = "[[0, 1], [1, \"2\"], [2, 5], [3, \"0xa\"], [4, 17]]";
+    assert_eq!(LookupTable::<Fp>::from_json(&mut json.as_bytes()).unwrap(), table);
+
+    // malformed tables
+    assert_eq!(LookupTable::<Fp>::from_csv(&mut "0, 1\n1, 2\n0, 3\n".as_bytes()).is_err(), true);
+    assert_eq!(LookupTable::<Fp>::from_csv(&mut "0, 1\n1\n".as_bytes()).is_err(), true);
+    assert_eq!(LookupTable::<Fp>::from_csv(&mut "0, 1\n1, z\n".as_bytes()).is_err(), true);
+    assert_eq!(LookupTable::<Fp>::from_json(&mut "[[0, 1], [1, 2]".as_bytes()).is_err(), true);
+    assert_eq!(LookupTable::<Fp>::from_csv(&mut "-1, 0x10\n".as_bytes()).unwrap().rows, vec![vec![-Fp::one(), Fp::from(16u64)]]);
+
+    let circuit = |y: u64, k: u64|
+    {
+        let mut builder = CircuitBuilder::<Fp>::new();
+        let y = builder.public_input(Fp::from(y));
+        let k = builder.var(Fp::from(k));
+        let v = builder.lookup(&table, k, 1).unwrap();
+        let d = builder.sub(v, y);
+        builder.assert_constant(d, Fp::zero());
+        builder
+    };
+
+    // the lookup takes a single row whatever the table size and the lookups of the circuit share the table
+    let large = LookupTable::<Fp>::create((0..40u64).map(|x| vec![Fp::from(x), Fp::from(x*x + 1)]).collect()).unwrap();
+    let mut builder = CircuitBuilder::<Fp>::new();
+    let k = builder.var(Fp::from(3u64));
+    assert_eq!(builder.lookup(&large, k, 1).map(|v| builder.value(v)), Some(Fp::from(10u64)));
+    let mut small = CircuitBuilder::<Fp>::new();
+    let key = small.var(Fp::from(3u64));
+    assert_eq!(small.lookup(&table, key, 1).is_some(), true);
+    assert_eq!(builder.rows(), small.rows());
+    assert_eq!(builder.rows(), 1);
+    assert_eq!(builder.lookup(&table, k, 1), None);
+    assert_eq!(builder.lookup(&large, k, 2), None);
+    assert_eq!(builder.domain_size(), 64);
+
+    let builder = circuit(10, 3);
+    assert_eq!(builder.rows(), 4);
+    let (gates, witness) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        builder.with_lookup(ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap()).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..builder.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+
+    // table(3) = 10
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the lookup proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // table(3) != 5 and 7 is not a table key
+    for (y, k) in [(5, 3), (50, 7)].iter()
+    {
+        let (_, witness) = circuit(*y, *k).build();
+        assert_eq!(index.cs.verify(&witness), false);
+        assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+    }
+}
@@ -0,0 +1,61 @@
+/*********************************************************************************************************
+
+This source file tests the low memory mode of the Plonk prover for a moderate domain
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+use std::time::Instant;
+use colored::Colorize;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 256; // Plonk domain size
+
+#[test]
+fn low_mem()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let mut start = Instant::now();
+    let proof = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(7)).unwrap();
+    println!("{}{:?}", "proof creation: ".yellow(), start.elapsed());
+
+    start = Instant::now();
+    let (low, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_low_mem().with_rng(&mut StdRng::seed_from_u64(7))).unwrap();
+    println!("{}{:?}", "low memory proof creation: ".yellow(), start.elapsed());
+
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &low)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the low memory mode only changes the peak memory, not the proof
+    let (mut bytes, mut low_bytes) = (Vec::new(), Vec::new());
+    proof.serialize_to(&mut bytes).unwrap();
+    low.serialize_to(&mut low_bytes).unwrap();
+    assert_eq!(bytes, low_bytes);
+
+    // the unsatisfied witness fails the same way
+    let mut bad = witness.clone();
+    bad[2*N+1] += &Fp::one();
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&bad), &index, vec![], ProverConfig::new().with_low_mem().with_rng(&mut StdRng::seed_from_u64(7))).is_err(), true);
+}
@@ -0,0 +1,112 @@
+/*********************************************************************************************************
+
+This source file tests the public set membership gadget
+
+    x in {s_0, s_1, s_2, s_3}
+
+with x being a private wire value
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, builder::CircuitBuilder};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+fn index<'a>(cs: ConstraintSystem<Fp>, srs: &'a SRS<Affine>) -> Index<'a, Affine>
+{
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(srs))
+}
+
+#[test]
+fn membership()
+{
+    let set = [Fp::from(3u64), Fp::from(7u64), Fp::from(11u64), Fp::from(19u64)];
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the single gadget gate with x on its left wire
+    let wires = (0..N).map(|i| GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i))).collect::<Vec<_>>();
+    let (gate, table) = CircuitGate::<Fp>::create_membership(wires[0], &set).unwrap();
+    let mut gates = vec![gate];
+    (1..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(wires[i])));
+    assert_eq!(CircuitGate::<Fp>::create_membership(wires[0], &[]).is_err(), true);
+
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let index = index(cs.with_lookup(&table, &[0]).unwrap(), &srs);
+    let verifier_index = index.verifier_index();
+
+    let witness = |x: Fp|
+    {
+        let mut witness = vec![Fp::zero(); 3*N];
+        witness[0] = x;
+        witness
+    };
+
+    // value in the set
+    let witness_in = witness(Fp::from(11u64));
+    assert_eq!(index.cs.verify(&witness_in), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_in, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the membership proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // value out of the set
+    let witness_out = witness(Fp::from(13u64));
+    assert_eq!(index.cs.verify(&witness_out), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_out, &index, vec![]).is_err(), true);
+}
+
+#[test]
+fn prove_membership()
+{
+    let set = (0..20u64).map(|i| Fp::from(3*i + 1)).collect::<Vec<_>>();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // x*x in the set
+    let circuit = |x: u64|
+    {
+        let mut builder = CircuitBuilder::<Fp>::new();
+        let x = builder.var(Fp::from(x));
+        let y = builder.mul(x, x);
+        builder.prove_membership(y, &set).unwrap();
+        builder
+    };
+
+    let builder = circuit(4);
+    assert_eq!(builder.rows(), 2);
+    let (gates, witness) = builder.build();
+    let srs = SRS::create(builder.domain_size());
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let index = index(builder.with_lookup(cs).unwrap(), &srs);
+
+    // 16 = 3*5 + 1 is in the set
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the membership proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // 9 is not in the set
+    let (_, witness) = circuit(3).build();
+    assert_eq!(index.cs.verify(&witness), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+
+    // the empty set and the set of a circuit looked up in another table are rejected
+    let mut builder = circuit(4);
+    let x = builder.var(Fp::from(2u64));
+    assert_eq!(builder.prove_membership(x, &[]).is_err(), true);
+    assert_eq!(builder.prove_membership(x, &set[1..]).is_err(), true);
+}
@@ -0,0 +1,56 @@
+/*********************************************************************************************************
+
+This source file tests opening the index polynomials in a separate batched opening proof
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::OpeningGroups};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn opening_groups()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    index.opening_groups = OpeningGroups::Split;
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    assert_eq!(proof.index_proof.is_some(), true);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proofs in batch: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the index opening proof is mandatory for the split grouping
+    let mut malformed = proof.clone();
+    malformed.index_proof = None;
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &malformed)])
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("proof without the index opening proof is verified"),
+    }
+
+    // and is rejected by the single grouping
+    let mut single = index.verifier_index();
+    single.opening_groups = OpeningGroups::Single;
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&single, &lgr_comms, &proof)]).is_err(), true);
+}
@@ -0,0 +1,70 @@
+/*********************************************************************************************************
+
+This source file tests the derivation order of the opening scaler challenges v, u relative to the absorption
+of the evaluations into the Fr sponge for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::OpeningOrder, plonk_sponge::FrSponge};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn opening_order()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let after = common::index(&srs, N);
+    let mut before = common::index(&srs, N);
+    before.opening_order = OpeningOrder::DigestedEvaluations;
+    let lgr_comms = common::lgr_comms(&after);
+    let x = Fp::from(5u64);
+
+    // by default v, u are queried from the Fr sponge absorbing the Fq sponge digest and then the evaluations
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &after, vec![]).unwrap();
+    let p_comm = PolyComm::<Affine>::multi_scalar_mul
+        (&lgr_comms.iter().take(proof.public.len()).collect(), &proof.public.iter().map(|s| -*s).collect());
+    let (_, digest, oracles, _, p_eval, _, _, _, _) = proof.oracles::<SpongeQ, SpongeR>(&after.verifier_index(), &p_comm).unwrap();
+    let mut fr_sponge = SpongeR::new(oracle::tweedle::fp::params());
+    fr_sponge.absorb(&digest);
+    for i in 0..2 {fr_sponge.absorb_evaluations(&p_eval[i], &proof.evals[i])}
+    assert_eq!(fr_sponge.challenge().0, oracles.v_chal.0);
+    assert_eq!(fr_sponge.challenge().0, oracles.u_chal.0);
+
+    // the alternate order queries them after the digest of the evaluations absorbed into a fresh sponge
+    let alternate = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &before, vec![]).unwrap();
+    let (_, digest, alternate_oracles, _, p_eval, _, _, _, _) = alternate.oracles::<SpongeQ, SpongeR>(&before.verifier_index(), &p_comm).unwrap();
+    let mut evals_sponge = SpongeR::new(oracle::tweedle::fp::params());
+    for i in 0..2 {evals_sponge.absorb_evaluations(&p_eval[i], &alternate.evals[i])}
+    let mut fr_sponge = SpongeR::new(oracle::tweedle::fp::params());
+    fr_sponge.absorb(&digest);
+    fr_sponge.absorb(&evals_sponge.digest());
+    assert_eq!(fr_sponge.challenge().0, alternate_oracles.v_chal.0);
+    assert_eq!(fr_sponge.challenge().0, alternate_oracles.u_chal.0);
+    assert_ne!(proof.oracles::<SpongeQ, SpongeR>(&before.verifier_index(), &p_comm).unwrap().2.v, oracles.v);
+
+    // either way tampering with an evaluation changes v, u
+    let mut tampered = alternate.clone();
+    tampered.evals[0].t[0] += &Fp::from(1u64);
+    assert_ne!(tampered.oracles::<SpongeQ, SpongeR>(&before.verifier_index(), &p_comm).unwrap().2.v, alternate_oracles.v);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&before.verifier_index(), &lgr_comms, &tampered)]).is_err(), true);
+
+    // the proofs are self-consistent in the order they were created in only
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&after.verifier_index(), &lgr_comms, &proof)]).is_ok(), true);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&before.verifier_index(), &lgr_comms, &alternate)]).is_ok(), true);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&before.verifier_index(), &lgr_comms, &proof)]).is_err(), true);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&after.verifier_index(), &lgr_comms, &alternate)]).is_err(), true);
+}
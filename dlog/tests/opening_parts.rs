@@ -0,0 +1,51 @@
+/*********************************************************************************************************
+
+This source file tests the opening proof parts of the Plonk proof for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn opening_parts()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    let parts = proof.opening_parts::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+
+    // public input, l, r, o, z, linearization, sigma1, sigma2 and quotient polynomials
+    assert_eq!(parts.commitments.len(), 9);
+    assert_eq!(parts.commitments[1].0, proof.commitments.l_comm);
+    assert_eq!(parts.commitments[4].0, proof.commitments.z_comm);
+    assert_eq!(parts.commitments[8].0, proof.commitments.t_comm);
+    assert_eq!(parts.commitments[8].2, Some(verifier_index.max_quot_size));
+    assert_eq!(parts.points.len(), proof.evals.len());
+    assert_eq!(parts.points[0], ProverProof::oracles::<SpongeQ, SpongeR>(&proof, &verifier_index, &ProverProof::public_comm(&lgr_comms, &proof.public)).unwrap().2.zeta);
+
+    // the parts verify in the batched inner product argument check on their own
+    assert_eq!(srs.verify::<SpongeQ, _>(&group_map, &mut vec![parts.batch()], &mut rand::thread_rng()), true);
+
+    let mut tampered = proof.opening_parts::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+    tampered.commitments[1].1[0][0] += &Fp::one();
+    assert_eq!(srs.verify::<SpongeQ, _>(&group_map, &mut vec![tampered.batch()], &mut rand::thread_rng()), false);
+}
@@ -0,0 +1,78 @@
+/*********************************************************************************************************
+
+This source file tests the proof of the circuit of the size that is not a power of two, padded by
+the constraint system to the radix-2 evaluation domain, and the padding diagnostic
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, domains::PaddingDiagnostic};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 8; // Plonk domain size
+const GATES: usize = 5; // circuit size
+const PUBLIC: usize = 1;
+
+#[test]
+fn padding_diagnostic()
+{
+    let srs = SRS::create(N);
+    let index = index(&srs, GATES);
+    assert_eq!(index.cs.gates.len(), N);
+    assert_eq!(index.cs.padding(), PaddingDiagnostic {rows: 5, domain: N});
+    assert_eq!(index.cs.padding().excessive(), false);
+
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the circuit padded beyond the smallest domain that fits it is diagnosed
+    let srs = SRS::create(4*N);
+    let padded = index(&srs, 4*N);
+    assert_eq!(padded.cs.padding(), PaddingDiagnostic {rows: 5, domain: 4*N});
+    assert_eq!(padded.cs.padding().excessive(), true);
+}
+
+// x * x = y circuit with the public input x, of the given number of gates
+fn index(srs: &SRS<Affine>, size: usize) -> Index<Affine>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    // the wires are positioned over the domain the circuit is padded to
+    let d = size.next_power_of_two();
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(GateWires::wires((0,   1), (d,   d), (2*d,   2*d)), p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, d+1), (d+1, 0), (2*d+1, 2*d+1)), z, z, n, p, z),
+    ];
+    (gates.len()..size).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (d+i, d+i), (2*d+i, 2*d+i)))));
+
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(srs)
+    )
+}
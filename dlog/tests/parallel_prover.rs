@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk prover with the parallel wire commitments and evaluations to produce
+the proof of the sequential wire commitments, and with the parallel quotient contributions the proof of
+the sequentially accumulated ones, the timings being benchmarked on the 2^16 domain by the parallel_prover
+bench, for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::Index};
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn parallel_prover()
+{
+    let srs = SRS::create(N);
+    let pipelined = common::index(&srs, N);
+    let mut sequential = common::index(&srs, N);
+    sequential.pipelined_commit = false;
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&pipelined);
+
+    let x = Fp::from(5u64);
+    let prove = |index: &Index<Affine>|
+    {
+        let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), index, vec![], ProverConfig::new().with_deterministic_blinding()).unwrap();
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+        let mut bytes = vec![];
+        proof.serialize_to(&mut bytes).unwrap();
+        bytes
+    };
+
+    // the transcript is independent of the commitment and evaluation scheduling
+    assert_eq!(prove(&sequential), prove(&pipelined));
+
+    // the quotient contributions are accumulated sequentially in the low memory mode
+    let proof = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &common::witness(x, N), &pipelined, vec![], &mut StdRng::seed_from_u64(7)).unwrap();
+    let (low, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &pipelined, vec![], ProverConfig::new().with_low_mem().with_rng(&mut StdRng::seed_from_u64(7))).unwrap();
+    assert!(proof == low);
+}
@@ -0,0 +1,51 @@
+/*********************************************************************************************************
+
+This source file tests the parallel batch verification of the Plonk proofs
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+#![cfg(feature = "parallel")]
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn parallel_verify()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let proofs = (1..5).map(|x| ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(x as u64), N), &index, vec![]).unwrap()).collect::<Vec<_>>();
+    let mut malformed = proofs[2].clone();
+    malformed.evals[0].l[0] += &Fp::one();
+
+    let valid = proofs.iter().map(|p| (&verifier_index, &lgr_comms, p)).collect::<Vec<_>>();
+    let mut mixed = valid.clone();
+    mixed[2] = (&verifier_index, &lgr_comms, &malformed);
+
+    for batch in [valid, mixed, vec![]].iter()
+    {
+        let sequential = ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, batch);
+        let parallel = ProverProof::verify_parallel::<SpongeQ, SpongeR, _>(&group_map, batch, &pool);
+        assert_eq!(sequential.is_ok(), parallel.is_ok());
+        assert_eq!(format!("{:?}", sequential), format!("{:?}", parallel));
+    }
+}
@@ -0,0 +1,59 @@
+/*********************************************************************************************************
+
+This source file tests continuing the transcript of a parent protocol by the Plonk proof
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, FqSponge};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn parent_sponge()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    // parent protocol transcript
+    let mut parent = SpongeQ::new(index.fq_sponge_params.clone());
+    parent.absorb_fr(&[Fp::from(42u64)]);
+
+    let x = Fp::from(5u64);
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_sponge(parent.clone())).unwrap();
+
+    // the proof verifies with the same parent state
+    match ProverProof::verify_with_sponges::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)], vec![parent.clone()])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // and is rejected with a fresh or a different parent state
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)]).is_err(), true);
+    let mut other = SpongeQ::new(index.fq_sponge_params.clone());
+    other.absorb_fr(&[Fp::from(43u64)]);
+    assert_eq!(ProverProof::verify_with_sponges::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)], vec![other.clone()]).is_err(), true);
+
+    // the outer nonce bound into the parent state changes the evaluation point
+    let p_comm = ProverProof::public_comm(&lgr_comms, &proof.public);
+    let zeta = |sponge: SpongeQ| proof.oracles_with_sponge::<SpongeQ, SpongeR>(&verifier_index, &p_comm, sponge).unwrap().2.zeta;
+    let (_, oracles) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.oracles().clone())).unwrap();
+    assert_ne!(zeta(parent.clone()), zeta(other));
+    assert_ne!(zeta(parent.clone()), oracles.zeta);
+}
@@ -0,0 +1,54 @@
+/*********************************************************************************************************
+
+This source file tests the verifier enforcing the boundary conditions of the permutation polynomial,
+rejecting the proof of the prover skipping its normalization, for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn permutation_boundary()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let scaled = |scale: Option<Fp>|
+    {
+        let mut index = common::index(&srs, N);
+        index.z_scale = scale;
+        index
+    };
+
+    // the normalized permutation polynomial verifies
+    let normalized = scaled(Some(Fp::one()));
+    let lgr_comms = common::lgr_comms(&normalized);
+    let verifier_index = normalized.verifier_index();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &normalized, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the scaled one satisfies the transition constraint but not the boundary conditions
+    let unnormalized = scaled(Some(Fp::from(3u64)));
+    let verifier_index = unnormalized.verifier_index();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &unnormalized, vec![]).unwrap();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)]).is_err(), true);
+}
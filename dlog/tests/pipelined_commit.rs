@@ -0,0 +1,45 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof of the pipelined wire commitments against the sequential one
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::Index};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn pipelined_commit()
+{
+    let srs = SRS::create(N);
+    let pipelined = common::index(&srs, N);
+    let mut sequential = common::index(&srs, N);
+    sequential.pipelined_commit = false;
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&pipelined);
+
+    // deterministic proofs consume the same randomness in both modes
+    let x = Fp::from(5u64);
+    let serialize = |index: &Index<Affine>|
+    {
+        let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), index, vec![], ProverConfig::new().with_deterministic_blinding()).unwrap();
+        assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &lgr_comms, &proof)]).is_ok(), true);
+        let mut bytes = vec![];
+        proof.serialize_to(&mut bytes).unwrap();
+        bytes
+    };
+    assert_eq!(serialize(&pipelined), serialize(&sequential));
+}
@@ -0,0 +1,72 @@
+/*********************************************************************************************************
+
+This source file tests the Poseidon permutation gadget for the computation
+
+    y = H(a, b)
+
+with H being the Poseidon sponge hash and a, b, y being private wire values
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSponge, ArithmeticSpongeParams, Sponge, SpongeConstants, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero, One};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 128; // Plonk domain size
+
+#[test]
+fn poseidon_gadget()
+{
+    let params = oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>;
+    let k = SC::ROUNDS_FULL + 1;
+
+    let wires = (0..N).map(|i| GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i))).collect::<Vec<_>>();
+    let mut gates = CircuitGate::<Fp>::create_poseidon_permutation(&wires[0..k], &params);
+    (k..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(wires[i])));
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, params.clone(), 0).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the sponge absorbs the rate elements into the zero state
+    let (a, b) = (Fp::from(3u64), Fp::from(7u64));
+    let rows = CircuitGate::<Fp>::poseidon_permutation_witness([a, b, Fp::zero()], &params);
+    assert_eq!(rows.len(), k);
+    let mut sponge = <ArithmeticSponge<Fp, SC> as Sponge<Fp, Fp>>::new();
+    sponge.absorb(&params, &[a, b]);
+    assert_eq!(rows[k-1].0, sponge.squeeze(&params));
+
+    let mut witness = vec![Fp::zero(); 3*N];
+    for (i, (l, r, o)) in rows.iter().enumerate()
+    {
+        witness[i] = *l;
+        witness[N+i] = *r;
+        witness[2*N+i] = *o;
+    }
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the Poseidon proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // tampered permutation output
+    witness[k-1] += &Fp::one();
+    assert_eq!(index.cs.verify(&witness), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+}
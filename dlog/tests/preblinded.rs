@@ -0,0 +1,80 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proofs with the wire polynomials blinded externally
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use ff_fft::{DensePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn preblinded()
+{
+    let rng = &mut rand::thread_rng();
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let witness = common::witness(x, N);
+
+    // wire polynomials blinded by the parties as w(X) + (b0 + b1*X)*Z_H(X)
+    let zh: DensePolynomial<Fp> = index.cs.domain.d1.vanishing_polynomial().into();
+    let wires = (0..3).map
+    (
+        |i|
+        {
+            let w = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(index.cs.gates.iter().map(|gate|
+                witness[[gate.wires.l.0, gate.wires.r.0, gate.wires.o.0][i]]).collect(), index.cs.domain.d1).interpolate();
+            &w + &(&zh * &DensePolynomial::rand(1, rng))
+        }
+    ).collect::<Vec<_>>();
+
+    let internal = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    let (external, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_blinded_wires([&wires[0], &wires[1], &wires[2]])).unwrap();
+    for proof in [internal, external].iter()
+    {
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+
+    // wires without the blinding
+    let unblinded = wires.iter().map(|w| w.divide_by_vanishing_poly(index.cs.domain.d1).unwrap().1).collect::<Vec<_>>();
+    match ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_blinded_wires([&unblinded[0], &unblinded[1], &unblinded[2]]))
+    {
+        Err(ProofError::WitnessBlinding) => {},
+        _ => panic!("wires without the blinding are accepted"),
+    }
+
+    // wires blinded past the blinding degree of the index
+    let overblinded = wires.iter().map(|w| w + &(&zh * &DensePolynomial::from_coefficients_slice(&[Fp::zero(), Fp::zero(), Fp::one()]))).collect::<Vec<_>>();
+    match ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_blinded_wires([&overblinded[0], &overblinded[1], &overblinded[2]]))
+    {
+        Err(ProofError::WitnessBlinding) => {},
+        _ => panic!("wires blinded past the blinding degree are accepted"),
+    }
+
+    // wires disagreeing with the witness
+    let other = &wires[0] + &DensePolynomial::from_coefficients_slice(&[Fp::one()]);
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_blinded_wires([&other, &wires[1], &wires[2]])).is_err(), true);
+}
@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file tests the commitment blinders returned by the prover, reproducing and re-randomizing
+the commitments of the proof for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, UniformRand};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_blinders()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let (proof, blinders) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&witness), &index, vec![], ProverConfig::new().with_rng(&mut StdRng::seed_from_u64(7))).map(|(proof, polys)| (proof, polys.blinders())).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // capturing the blinders does not change the proof
+    let plain = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(7)).unwrap();
+    let (mut bytes, mut plain_bytes) = (Vec::new(), Vec::new());
+    proof.serialize_to(&mut bytes).unwrap();
+    plain.serialize_to(&mut plain_bytes).unwrap();
+    assert_eq!(bytes, plain_bytes);
+
+    // the wire commitments are reproduced from the wire polynomials and the blinders
+    let srs = index.srs.get_ref();
+    let column = |c: usize| Evaluations::<Fp, D<Fp>>::from_vec_and_domain(witness[c*N..(c+1)*N].to_vec(), index.cs.domain.d1).interpolate();
+    assert_eq!(srs.mask_with(&srs.commit_non_hiding(&column(0), None), &blinders.l).unshifted, proof.commitments.l_comm.unshifted);
+    assert_eq!(srs.mask_with(&srs.commit_non_hiding(&column(1), None), &blinders.r).unshifted, proof.commitments.r_comm.unshifted);
+    assert_eq!(srs.mask_with(&srs.commit_non_hiding(&column(2), None), &blinders.o).unshifted, proof.commitments.o_comm.unshifted);
+
+    // the re-randomized commitment is the one of the summed blinders
+    let delta = blinders.l.map(|_| Fp::rand(&mut rand::thread_rng()));
+    let summed = PolyComm::<Fp> {unshifted: blinders.l.unshifted.iter().zip(delta.unshifted.iter()).map(|(w, d)| *w + d).collect(), shifted: None};
+    assert_eq!
+    (
+        srs.mask_with(&proof.commitments.l_comm, &delta).unshifted,
+        srs.mask_with(&srs.commit_non_hiding(&column(0), None), &summed).unshifted
+    );
+}
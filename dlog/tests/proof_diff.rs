@@ -0,0 +1,51 @@
+/*********************************************************************************************************
+
+This source file tests the equality and the structural diff of the Plonk proofs for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_diff()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let proof = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(7)).unwrap();
+    let same = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(7)).unwrap();
+    assert_eq!(proof == same, true);
+    assert_eq!(proof.diff(&same).len(), 0);
+
+    // the single evaluation change is reported in the evaluations only
+    let mut tampered = proof.clone();
+    tampered.evals[0].l[0] += &Fp::one();
+    assert_eq!(proof == tampered, false);
+    assert_eq!(proof.diff(&tampered), vec!["evals"]);
+
+    // the differently blinded proof differs in the commitments, the evaluations and the opening proof
+    let other = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(8)).unwrap();
+    assert_eq!(proof.diff(&other), vec!["commitments", "proof", "evals"]);
+
+    let mut renonced = proof.clone();
+    renonced.nonce = Some(Fp::one());
+    renonced.public.clear();
+    assert_eq!(proof.diff(&renonced), vec!["public", "nonce"]);
+}
@@ -0,0 +1,64 @@
+/*********************************************************************************************************
+
+This source file tests the error reporting of the Plonk prover for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::{ProofError, DivisionSite}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_error()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // witness of the wrong length reports the expected and the actual lengths
+    let mut short = common::witness(Fp::from(5u64), N);
+    short.pop();
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &short, &index, vec![])
+    {
+        Err(error) =>
+        {
+            match error
+            {
+                ProofError::WitnessLength {expected, found} => {assert_eq!(expected, 3*N); assert_eq!(found, 3*N-1)}
+                _ => panic!("unexpected error: {}", error),
+            }
+            assert_eq!(error.to_string(), format!("witness length {} does not match the expected {}", 3*N-1, 3*N));
+        }
+        Ok(_) => panic!("proof of the short witness is created"),
+    }
+
+    // unsatisfied gate fails the vanishing polynomial division
+    let mut wrong = common::witness(Fp::from(5u64), N);
+    wrong[2*N+1] += &Fp::one();
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &wrong, &index, vec![])
+    {
+        Err(error) =>
+        {
+            match error
+            {
+                ProofError::PolyDivisionAt(site) => assert_eq!(site, DivisionSite::Vanishing),
+                _ => panic!("unexpected error: {}", error),
+            }
+            let error: Box<dyn std::error::Error> = Box::new(error);
+            assert_eq!(error.to_string(), "polynomial division of the constraint contributions by the vanishing polynomial failed");
+        }
+        Ok(_) => panic!("proof of the unsatisfied witness is created"),
+    }
+}
@@ -0,0 +1,49 @@
+/*********************************************************************************************************
+
+This source file tests the iteration, the column access, the mapping and the display of the proof evaluations
+
+**********************************************************************************************************/
+
+use plonk_circuits::{scalars::ProofEvaluations, wires::COLUMNS};
+use algebra::{tweedle::fp::Fp, UniformRand};
+
+#[test]
+fn proof_evaluations()
+{
+    let rng = &mut rand::thread_rng();
+    let mut sample = || vec![Fp::rand(rng), Fp::rand(rng)];
+    let e = ProofEvaluations::<Vec<Fp>>
+    {
+        l: sample(),
+        r: sample(),
+        o: sample(),
+        z: sample(),
+        t: sample(),
+        f: sample(),
+        sigma1: sample(),
+        sigma2: sample(),
+    };
+
+    // the evaluations are iterated in the declaration order
+    assert_eq!(e.iter().count(), 8);
+    assert_eq!(e.iter().map(|(name, _)| name).collect::<Vec<_>>(), vec!["l", "r", "o", "z", "t", "f", "sigma1", "sigma2"]);
+    assert_eq!(e.iter().nth(4).unwrap().1, &e.t);
+
+    // the wire and permutation evaluations are accessed in the column order
+    assert_eq!(e.w().len(), COLUMNS);
+    assert_eq!(e.w()[COLUMNS-1], &e.o);
+    assert_eq!(e.sigma(), [&e.sigma1, &e.sigma2]);
+
+    // the mapping applies to each of the evaluations
+    let first = e.map(|v| v[0]);
+    assert_eq!(first.iter().map(|(_, x)| *x).collect::<Vec<_>>(), e.iter().map(|(_, v)| v[0]).collect::<Vec<_>>());
+
+    // the combination is the mapping of the chunk evaluation
+    let pt = Fp::rand(rng);
+    let combined = e.combine(pt);
+    assert_eq!(combined.sigma2, e.sigma2[0] + &(e.sigma2[1] * &pt));
+
+    let display = e.to_string();
+    assert_eq!(display.lines().count(), 8);
+    assert_eq!(display.lines().nth(6).unwrap().starts_with("sigma1: ["), true);
+}
@@ -0,0 +1,75 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proofs bound to the per-proof nonce against replay
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+use std::collections::HashSet;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_nonce()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    // proofs of the same witness bound to different nonces
+    let nonces = [Fp::from(1u64), Fp::from(2u64)];
+    let proofs = nonces.iter().map(|nonce| ProverProof::prove::<SpongeQ, SpongeR, _>
+        (&group_map, ProverWitness::Field(&common::witness(Fp::from(5u64), N)), &index, vec![], ProverConfig::new().with_nonce(*nonce)).map(|(proof, _)| proof).unwrap()).collect::<Vec<_>>();
+    assert_eq!(proofs[0].nonce, Some(nonces[0]));
+    assert_eq!(proofs[1].nonce, Some(nonces[1]));
+    assert_ne!(proofs[0].evals[0].l, proofs[1].evals[0].l);
+
+    let mut seen = HashSet::new();
+    for (proof, nonce) in proofs.iter().zip(nonces.iter())
+    {
+        match proof.verify_with_nonce::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, *nonce, &seen)
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+        seen.insert(*nonce);
+    }
+
+    // replayed proof
+    match proofs[0].verify_with_nonce::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, nonces[0], &seen)
+    {
+        Err(ProofError::ProofFreshness) => {},
+        _ => panic!("replayed proof is verified"),
+    }
+
+    // proof bound to an unexpected nonce
+    match proofs[0].verify_with_nonce::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, Fp::from(3u64), &HashSet::new())
+    {
+        Err(ProofError::ProofFreshness) => {},
+        _ => panic!("proof of an unexpected nonce is verified"),
+    }
+
+    // nonce substituted in the proof does not match its transcript
+    let mut substituted = proofs[0].clone();
+    substituted.nonce = Some(Fp::from(3u64));
+    assert_eq!(substituted.verify_with_nonce::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, Fp::from(3u64), &HashSet::new()).is_err(), true);
+
+    // proof without the nonce
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    assert_eq!(proof.verify_with_nonce::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, nonces[0], &HashSet::new()).is_err(), true);
+}
@@ -0,0 +1,73 @@
+/*********************************************************************************************************
+
+This source file tests the proof size and SRS requirements profile of the Plonk index for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_profile()
+{
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    // the quotient polynomial is committed in several segments with the SRS of the circuit size
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let profile = index.proof_profile();
+    assert_eq!(profile.srs_degree, index.max_quot_size);
+    assert_eq!(profile.srs_sufficient, false);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    assert_eq!(profile.group_elements, group_elements(&proof));
+    assert_eq!(profile.field_elements, field_elements(&proof));
+
+    // the SRS of the quotient polynomial size commits it in a single segment
+    let srs = SRS::create(profile.srs_degree);
+    let index = common::index(&srs, N);
+    let profile = index.proof_profile();
+    assert_eq!(profile.srs_sufficient, true);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    assert_eq!(proof.commitments.t_comm.unshifted.len(), 1);
+    assert_eq!(profile.group_elements, group_elements(&proof));
+    assert_eq!(profile.field_elements, field_elements(&proof));
+}
+
+fn group_elements(proof: &ProverProof<Affine>) -> usize
+{
+    let c = &proof.commitments;
+    [&c.l_comm, &c.r_comm, &c.o_comm, &c.z_comm, &c.t_comm].iter().
+        map(|c| c.unshifted.len() + if c.shifted.is_some() {1} else {0}).sum::<usize>() +
+        2 * proof.proof.lr.len() + 2
+}
+
+fn field_elements(proof: &ProverProof<Affine>) -> usize
+{
+    proof.evals.iter().map(|e| [&e.l, &e.r, &e.o, &e.z, &e.t, &e.f, &e.sigma1, &e.sigma2].iter().map(|e| e.len()).sum::<usize>()).sum()
+}
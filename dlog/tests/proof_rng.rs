@@ -0,0 +1,51 @@
+/*********************************************************************************************************
+
+This source file tests the reproducibility of the Plonk proofs created with the seeded RNG
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_rng()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let witness = common::witness(Fp::from(5u64), N);
+
+    let serialized = |seed: u64|
+    {
+        let proof = ProverProof::create_with_rng::<SpongeQ, SpongeR, _, _>(&group_map, &witness, &index, vec![], &mut StdRng::seed_from_u64(seed)).unwrap();
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+        let mut bytes = Vec::new();
+        proof.serialize_to(&mut bytes).unwrap();
+        bytes
+    };
+
+    // the same seed reproduces the proof, another one blinds it differently
+    assert_eq!(serialized(7), serialized(7));
+    assert_ne!(serialized(7), serialized(8));
+}
@@ -0,0 +1,57 @@
+/*********************************************************************************************************
+
+This source file tests the serde round trip of the Plonk proof through JSON and bincode
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_serde()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    let mut bytes = Vec::new();
+    proof.serialize_to(&mut bytes).unwrap();
+
+    let json = serde_json::to_string(&proof).unwrap();
+    let binary = bincode::serialize(&proof).unwrap();
+    for restored in [serde_json::from_str::<ProverProof<Affine>>(&json).unwrap(), bincode::deserialize::<ProverProof<Affine>>(&binary).unwrap()].iter()
+    {
+        let mut restored_bytes = Vec::new();
+        restored.serialize_to(&mut restored_bytes).unwrap();
+        assert_eq!(restored_bytes, bytes);
+        assert_eq!(restored.public, proof.public);
+        assert_eq!(restored.commitments.t_comm.unshifted, proof.commitments.t_comm.unshifted);
+        (0..2).for_each(|i| assert_eq!(restored.evals[i].t, proof.evals[i].t));
+
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, restored)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+
+    // truncated encodings are rejected
+    assert!(bincode::deserialize::<ProverProof<Affine>>(&binary[..binary.len()-1]).is_err());
+}
@@ -0,0 +1,81 @@
+/*********************************************************************************************************
+
+This source file tests the streaming serialization of the Plonk proof through a pipe
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, ToBytes};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+use std::{os::unix::net::UnixStream, thread};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_stream()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proofs = (1..4).map(|x|
+        ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(Fp::from(x as u64), N)), &index, vec![], ProverConfig::new().with_checksum()).map(|(proof, _)| proof).unwrap()
+    ).collect::<Vec<_>>();
+
+    // write the proofs to one end of the pipe while reading them from the other one
+    let (mut reader, mut writer) = UnixStream::pair().unwrap();
+    let sent = proofs.clone();
+    let sender = thread::spawn(move || for proof in sent.iter() {proof.serialize_to(&mut writer).unwrap()});
+
+    let received = (0..proofs.len()).map(|_| ProverProof::<Affine>::deserialize_from(&mut reader).unwrap()).collect::<Vec<_>>();
+    sender.join().unwrap();
+
+    for (proof, sent) in received.iter().zip(proofs.iter())
+    {
+        assert_eq!(proof.public, sent.public);
+        assert_eq!(proof.witness_checksum, sent.witness_checksum);
+        assert_eq!(proof.commitments.t_comm.unshifted, sent.commitments.t_comm.unshifted);
+    }
+    let batch = received.iter().map(|proof| (&verifier_index, &lgr_comms, proof)).collect::<Vec<_>>();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &batch)
+    {
+        Err(error) => panic!("Failure verifying the deserialized proofs: {:?}", error),
+        Ok(_) => {}
+    }
+
+    // the version and the length of the left wire commitment precede its points
+    let mut bytes = vec![];
+    proofs[0].serialize_to(&mut bytes).unwrap();
+    let mut point = vec![];
+    proofs[0].commitments.l_comm.unshifted[0].write(&mut point).unwrap();
+    let points = 4 + 8;
+
+    // point off the curve
+    let mut off = bytes.clone();
+    off[points] ^= 1;
+    assert_eq!(ProverProof::<Affine>::deserialize_from(&mut &off[..]).is_err(), true);
+
+    // presence flag of the shifted left wire commitment other than 0 and 1
+    let mut flag = bytes.clone();
+    flag[points + proofs[0].commitments.l_comm.unshifted.len() * point.len()] = 2;
+    assert_eq!(ProverProof::<Affine>::deserialize_from(&mut &flag[..]).is_err(), true);
+
+    // truncated stream
+    let mut bytes = vec![];
+    proofs[0].serialize_to(&mut bytes).unwrap();
+    bytes.truncate(bytes.len() / 2);
+    assert_eq!(ProverProof::<Affine>::deserialize_from(&mut &bytes[..]).is_err(), true);
+}
@@ -0,0 +1,80 @@
+/*********************************************************************************************************
+
+This source file tests the structural checks of the Plonk proof against the verifier index
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use ff_fft::DensePolynomial;
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_structure()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    assert_eq!(proof.check_structure(&verifier_index).is_ok(), true);
+
+    // quotient commitment without the degree bound shift
+    let mut malformed = proof.clone();
+    malformed.commitments.t_comm.shifted = None;
+    match malformed.check_structure(&verifier_index)
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("quotient commitment without shift is accepted"),
+    }
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &malformed)])
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("quotient commitment without shift is verified"),
+    }
+
+    // wire commitment with a spurious degree bound shift
+    let mut malformed = proof.clone();
+    malformed.commitments.l_comm.shifted = Some(malformed.commitments.l_comm.unshifted[0]);
+    assert_eq!(malformed.check_structure(&verifier_index).is_err(), true);
+
+    // quotient evaluations with a missing chunk
+    let mut malformed = proof.clone();
+    malformed.evals[0].t.pop();
+    assert_eq!(malformed.check_structure(&verifier_index).is_err(), true);
+
+    // the quotient bound of a multiple of the segment size is committed without the shift
+    let mut aligned = common::index(&srs, N);
+    aligned.max_quot_size = 5*N;
+    let aligned_verifier_index = aligned.verifier_index();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &aligned, vec![]).unwrap();
+    assert_eq!(proof.commitments.t_comm.shifted.is_none(), true);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&aligned_verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the shape of the commitment depends on the bound only, the zero and the short polynomials included
+    for plnm in [DensePolynomial::<Fp>::zero(), DensePolynomial::<Fp>::from_coefficients_vec(vec![Fp::one(); 2])].iter()
+    {
+        assert_eq!(srs.commit_non_hiding(plnm, Some(5*N)).shifted.is_none(), true);
+        assert_eq!(srs.commit_non_hiding(plnm, Some(5*N-5)).shifted.is_some(), true);
+    }
+}
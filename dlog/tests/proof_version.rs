@@ -0,0 +1,66 @@
+/*********************************************************************************************************
+
+This source file tests the versioned serialization of the Plonk proof
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, stream::PROOF_VERSION};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn proof_version()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    let mut bytes = vec![];
+    proof.serialize_to(&mut bytes).unwrap();
+    assert_eq!(bytes[0..4].to_vec(), PROOF_VERSION.to_le_bytes().to_vec());
+
+    // version 1 proof has neither the witness checksum nor the nonce presence flags nor the lookup argument
+    // nor the evaluations beyond the default two points and is upgraded to the latest version
+    let mut legacy = bytes.clone();
+    legacy[0..4].copy_from_slice(&1u32.to_le_bytes());
+    legacy.truncate(legacy.len() - 4*8);
+    assert_eq!(legacy.pop(), Some(0));
+    assert_eq!(legacy.pop(), Some(0));
+    let upgraded = ProverProof::<Affine>::deserialize_from(&mut &legacy[..]).unwrap();
+    assert_eq!(upgraded.witness_checksum, None);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &upgraded)])
+    {
+        Err(error) => panic!("Failure verifying the upgraded proof: {:?}", error),
+        Ok(_) => {}
+    }
+
+    // unknown version
+    let mut unknown = bytes.clone();
+    unknown[0..4].copy_from_slice(&(PROOF_VERSION + 1).to_le_bytes());
+    match ProverProof::<Affine>::deserialize_from(&mut &unknown[..])
+    {
+        Err(ProofError::UnsupportedVersion {found, supported}) =>
+        {
+            assert_eq!(found, PROOF_VERSION + 1);
+            assert_eq!(supported, PROOF_VERSION);
+        }
+        _ => panic!("proof of the unknown version is deserialized"),
+    }
+}
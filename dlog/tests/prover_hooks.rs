@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file tests the instrumentation hooks of the prover phases for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, hooks::{ProverHooks, ProverPhase, PhaseTimer}};
+use ff_fft::Evaluations;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+// hooks recording the phase boundaries
+#[derive(Default)]
+struct Boundaries
+{
+    events: Vec<(bool, ProverPhase)>,
+}
+
+impl ProverHooks for Boundaries
+{
+    fn begin(&mut self, phase: ProverPhase) {self.events.push((true, phase))}
+    fn end(&mut self, phase: ProverPhase) {self.events.push((false, phase))}
+}
+
+#[test]
+fn prover_hooks()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let phases = [ProverPhase::Wires, ProverPhase::Permutation, ProverPhase::Quotient, ProverPhase::Evaluations, ProverPhase::Opening];
+
+    // the phases are reported in order, each one ending before the next one begins
+    let mut boundaries = Boundaries::default();
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(Fp::from(5u64), N)), &index, vec![], ProverConfig::new().with_hooks(&mut boundaries)).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    assert_eq!(boundaries.events, phases.iter().flat_map(|p| vec![(true, *p), (false, *p)]).collect::<Vec<_>>());
+
+    let mut timer = PhaseTimer::default();
+    ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(Fp::from(5u64), N)), &index, vec![], ProverConfig::new().with_hooks(&mut timer)).unwrap();
+    assert_eq!(timer.phases.iter().map(|(p, _)| *p).collect::<Vec<_>>(), phases.to_vec());
+
+    // the phase the proof creation fails in does not end
+    let mut boundaries = Boundaries::default();
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(Fp::from(5u64), N).iter().map(|x| *x + &Fp::one()).collect::<Vec<_>>()), &index, vec![], ProverConfig::new().with_hooks(&mut boundaries)).is_err(), true);
+    assert_eq!(boundaries.events.last().map(|(begin, _)| *begin), Some(true));
+}
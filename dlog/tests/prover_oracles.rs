@@ -0,0 +1,74 @@
+/*********************************************************************************************************
+
+This source file tests the random oracles returned by the prover against the ones the verifier derives
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn prover_oracles()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let (proof, oracles) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(Fp::from(5u64), N)), &index, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.oracles().clone())).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    let p_comm = PolyComm::<Affine>::multi_scalar_mul(&lgr_comms.iter().collect(), &proof.public.iter().map(|s| -*s).collect());
+    let (_, _, derived, _, _, _, _, _, _) = proof.oracles::<SpongeQ, SpongeR>(&verifier_index, &p_comm).unwrap();
+    assert_eq!
+    (
+        [oracles.beta, oracles.gamma, oracles.alpha, oracles.zeta, oracles.v, oracles.u],
+        [derived.beta, derived.gamma, derived.alpha, derived.zeta, derived.v, derived.u]
+    );
+    assert_eq!
+    (
+        [oracles.alpha_chal.0, oracles.zeta_chal.0, oracles.v_chal.0, oracles.u_chal.0],
+        [derived.alpha_chal.0, derived.zeta_chal.0, derived.v_chal.0, derived.u_chal.0]
+    );
+
+    // the oracles are re-derived from the public input without the verification
+    let random = proof.random_oracles::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+    assert_eq!
+    (
+        [oracles.beta, oracles.gamma, oracles.alpha, oracles.zeta, oracles.v, oracles.u],
+        [random.beta, random.gamma, random.alpha, random.zeta, random.v, random.u]
+    );
+    let mut malformed = proof.clone();
+    malformed.evals[0].l[0] += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &malformed)]).is_err(), true);
+    assert_eq!(malformed.random_oracles::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap().zeta, oracles.zeta);
+
+    // the oracles of the proof malformed against the index structure are not derived
+    let mut truncated = proof.clone();
+    truncated.lookup_evals.clear();
+    match truncated.random_oracles::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms)
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("oracles of the malformed proof are derived"),
+    }
+    assert_eq!(truncated.oracles::<SpongeQ, SpongeR>(&verifier_index, &p_comm).is_err(), true);
+}
@@ -0,0 +1,57 @@
+/*********************************************************************************************************
+
+This source file tests the batched and per-element absorption of the public input into the transcript
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::PublicAbsorb};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn public_absorb()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let batched = common::index(&srs, N);
+    let mut per_element = common::index(&srs, N);
+    per_element.public_absorb_mode = PublicAbsorb::PerElement;
+    let lgr_comms = common::lgr_comms(&batched);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &per_element, vec![]).unwrap();
+    let p_comm = PolyComm::<Affine>::multi_scalar_mul
+        (&lgr_comms.iter().take(proof.public.len()).collect(), &proof.public.iter().map(|s| -*s).collect());
+
+    // the two modes produce different challenges
+    let batched_oracles = proof.oracles::<SpongeQ, SpongeR>(&batched.verifier_index(), &p_comm).unwrap().2;
+    let per_element_oracles = proof.oracles::<SpongeQ, SpongeR>(&per_element.verifier_index(), &p_comm).unwrap().2;
+    assert_ne!(batched_oracles.beta, per_element_oracles.beta);
+    assert_ne!(batched_oracles.zeta, per_element_oracles.zeta);
+
+    // the proof verifies in the mode it was created in only
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&per_element.verifier_index(), &lgr_comms, &proof)])
+    {
+        Err(error) => panic!("Failure verifying the per-element absorption proof: {:?}", error),
+        Ok(_) => {}
+    }
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&batched.verifier_index(), &lgr_comms, &proof)]).is_err(), true);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &batched, vec![]).unwrap();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&batched.verifier_index(), &lgr_comms, &proof)]).is_ok(), true);
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&per_element.verifier_index(), &lgr_comms, &proof)]).is_err(), true);
+}
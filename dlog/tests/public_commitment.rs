@@ -0,0 +1,60 @@
+/*********************************************************************************************************
+
+This source file tests the verification of the Plonk proof against the Poseidon and Pedersen public input
+commitments for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, public::PublicCommitment};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn public_commitment()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+
+    let params = &verifier_index.fr_sponge_params;
+    for (expected, other) in
+    [
+        (PublicCommitment::poseidon(params, &[x]), PublicCommitment::poseidon(params, &[Fp::from(6u64)])),
+        (PublicCommitment::pedersen(&lgr_comms, &[x]), PublicCommitment::pedersen(&lgr_comms, &[Fp::from(6u64)])),
+    ].iter()
+    {
+        // matching commitment
+        match proof.verify_against_public_commitment::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, expected)
+        {
+            Err(error) => panic!("Failure verifying the proof against the public input commitment: {:?}", error),
+            Ok(_) => {}
+        }
+
+        // mismatching commitment
+        match proof.verify_against_public_commitment::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, other)
+        {
+            Err(ProofError::PublicInputCommitment) => {},
+            _ => panic!("proof is verified against the mismatching public input commitment"),
+        }
+    }
+
+    // the Poseidon commitment binds the public input length
+    assert_eq!(PublicCommitment::<Affine>::poseidon(params, &[x]).matches(params, &lgr_comms, &[x, Fp::zero()]), false);
+}
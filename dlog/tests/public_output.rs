@@ -0,0 +1,88 @@
+/*********************************************************************************************************
+
+This source file tests the public input bound to the output wire of a gate for the following computation:
+
+    x * x = y
+
+with y being the public input, x being private
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{Field, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 1;
+
+#[test]
+fn public_output()
+{
+    let srs = SRS::create(N);
+    let index = index(&srs);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let witness = witness(Fp::from(5u64));
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    assert_eq!(proof.public, vec![Fp::from(25u64)]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the announced public input has to be the output of the gate
+    let mut tampered = proof.clone();
+    tampered.public[0] += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &tampered)]).is_err(), true);
+
+    // the public input not matching the output does not satisfy the copy constraint
+    let mut unbound = witness.clone();
+    unbound[0] += &Fp::one();
+    assert_eq!(index.cs.verify(&unbound), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &unbound, &index, vec![]).is_err(), true);
+}
+
+// x * x = y circuit with the public input y wired to the output of the multiplication gate
+fn index(srs: &SRS<Affine>) -> Index<Affine>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_public(GateWires::wires((0, 2*N+1), (N, N), (2*N, 2*N))),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, N+1), (N+1, 1), (2*N+1, 0)), z, z, n, p, z),
+    ];
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(srs)
+    )
+}
+
+fn witness(x: Fp) -> Vec<Fp>
+{
+    let mut witness = vec![Fp::zero(); 3*N];
+    witness[0] = x.square();
+    witness[1] = x;
+    witness[N+1] = x;
+    witness[2*N+1] = x.square();
+    witness
+}
@@ -0,0 +1,39 @@
+/*********************************************************************************************************
+
+This source file tests the public input polynomial computation for sparse public inputs
+
+**********************************************************************************************************/
+
+use plonk_circuits::constraints::public_polynomial;
+use algebra::{tweedle::fp::Fp, Zero, UniformRand};
+use ff_fft::{EvaluationDomain, Evaluations, Radix2EvaluationDomain as D};
+use std::time::Instant;
+use colored::Colorize;
+
+const N: usize = 1024; // domain size
+
+#[test]
+fn public_polynomial_sparse()
+{
+    let rng = &mut rand::thread_rng();
+    let domain = D::<Fp>::new(N).unwrap();
+
+    let mut public = vec![Fp::zero(); N];
+    for i in [5, 300, 1000].iter() {public[*i] = Fp::rand(rng)}
+
+    let mut start = Instant::now();
+    let p = public_polynomial(domain, &public);
+    println!("{}{:?}", "sparse public input polynomial: ".yellow(), start.elapsed());
+
+    start = Instant::now();
+    let q = -Evaluations::<Fp, D<Fp>>::from_vec_and_domain(public.clone(), domain).interpolate();
+    println!("{}{:?}", "interpolated public input polynomial: ".yellow(), start.elapsed());
+
+    assert_eq!(p, q);
+    assert_eq!(p.evaluate_over_domain_by_ref(domain).evals, public.iter().map(|x| -*x).collect::<Vec<_>>());
+
+    // dense public inputs go through the interpolation
+    let public = (0..N).map(|_| Fp::rand(rng)).collect::<Vec<_>>();
+    assert_eq!(public_polynomial(domain, &public), -Evaluations::<Fp, D<Fp>>::from_vec_and_domain(public.clone(), domain).interpolate());
+    assert_eq!(public_polynomial(domain, &vec![Fp::zero(); N]).is_zero(), true);
+}
@@ -0,0 +1,86 @@
+/*********************************************************************************************************
+
+This source file tests the decoding of the public input according to the public input schema
+
+    version, length, data_0, ..., data_length-1
+
+with the public input being constrained by the public input gates
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec}, schema::{PublicInputSchema, SchemaField, PublicInputValue}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 4;
+
+#[test]
+fn public_schema()
+{
+    let srs = SRS::create(N);
+    let index = index(&srs);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+    let schema = PublicInputSchema::create(vec![SchemaField::Version(Fp::one()), SchemaField::Data(2)]);
+
+    // conforming public input
+    let public = [Fp::one(), Fp::from(2u64), Fp::from(7u64), Fp::from(9u64)];
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness(&public), &index, vec![]).unwrap();
+    match proof.verify_with_schema::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, &schema)
+    {
+        Ok(values) => assert_eq!(values, vec![PublicInputValue::Version(Fp::one()), PublicInputValue::Data(vec![Fp::from(7u64), Fp::from(9u64)])]),
+        Err(error) => panic!("Failure decoding the public input: {:?}", error),
+    }
+
+    // non-conforming public inputs: wrong version, excessive length, trailing element
+    for public in
+    [
+        [Fp::from(2u64), Fp::from(2u64), Fp::from(7u64), Fp::from(9u64)],
+        [Fp::one(), Fp::from(3u64), Fp::from(7u64), Fp::from(9u64)],
+        [Fp::one(), Fp::from(1u64), Fp::from(7u64), Fp::from(9u64)],
+    ].iter()
+    {
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness(public), &index, vec![]).unwrap();
+        assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)]).is_ok(), true);
+        match proof.verify_with_schema::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms, &schema)
+        {
+            Err(ProofError::PublicInputSchema) => {},
+            _ => panic!("non-conforming public input is accepted"),
+        }
+    }
+}
+
+// public input gates
+fn index(srs: &SRS<Affine>) -> Index<Affine>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+
+    let mut gates = (0..PUBLIC).map(|i| CircuitGate::<Fp>::create_generic(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)), p, z, z, z, z)).collect::<Vec<_>>();
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(srs)
+    )
+}
+
+fn witness(public: &[Fp]) -> Vec<Fp>
+{
+    let mut witness = vec![Fp::zero(); 3*N];
+    witness[0..PUBLIC].copy_from_slice(public);
+    witness
+}
@@ -0,0 +1,86 @@
+/*********************************************************************************************************
+
+This source file tests the commitments to the generic constraint selectors of the values drawn
+from the {-1, 0, 1} set over the Lagrange base against their multi-scalar multiplication commitments
+for the following computation:
+
+    a_0 = x, a_i+1 = a_i + x
+
+with x being the public input
+
+The timing of the commitments is in benches/quantized_selectors.rs
+
+**********************************************************************************************************/
+
+use plonk_circuits::{builder::CircuitBuilder, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero, UniformRand};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec, commit_quantized}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const ROWS: usize = 1000;
+
+#[test]
+fn quantized_selectors()
+{
+    let rng = &mut rand::thread_rng();
+    let mut builder = CircuitBuilder::<Fp>::new();
+    let x = builder.public_input(Fp::from(3u64));
+    let mut a = x;
+    (1..ROWS).for_each(|_| a = builder.add(a, x));
+
+    let (gates, witness) = builder.build();
+    let n = builder.domain_size();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, builder.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+
+    let basis = srs.lagrange_basis(index.cs.domain.d1);
+    let msm = index.verifier_index();
+    let quantized = index.verifier_index_with_lagrange_basis(&basis);
+
+    for (a, b) in
+    [
+        (&msm.ql_comm, &quantized.ql_comm), (&msm.qr_comm, &quantized.qr_comm), (&msm.qo_comm, &quantized.qo_comm),
+        (&msm.qm_comm, &quantized.qm_comm), (&msm.qc_comm, &quantized.qc_comm),
+    ].iter()
+    {
+        assert_eq!(a.unshifted, b.unshifted);
+        assert_eq!(a.shifted, b.shifted);
+    }
+
+    // the Lagrange base commits to the Lagrange polynomials
+    let mut v = vec![Fp::zero(); n];
+    v[5] = Fp::one();
+    let l5 = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+    assert_eq!(srs.commit_non_hiding(&l5, None).unshifted, vec![basis[5]]);
+
+    // the evaluations not drawn from a small set are not quantized
+    let evals = (0..n).map(|_| Fp::rand(rng)).collect::<Vec<_>>();
+    assert_eq!(commit_quantized(&basis, &evals).is_none(), true);
+
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..builder.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&quantized, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
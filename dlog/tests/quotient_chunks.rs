@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the chunking of the quotient polynomial commitment by the polynomial segment size
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn quotient_chunks()
+{
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    for size in [N, 4*N].iter()
+    {
+        let srs = SRS::create(*size);
+        let index = common::index(&srs, N);
+        let verifier_index = index.verifier_index();
+        let lgr_comms = common::lgr_comms(&index);
+
+        // the quotient of the degree bound 5(n-1) spans at least two segments
+        let chunks = (index.max_quot_size + index.max_poly_size - 1) / index.max_poly_size;
+        assert_eq!(chunks >= 2, true);
+
+        let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+        assert_eq!(proof.commitments.t_comm.unshifted.len(), chunks);
+        assert_eq!(proof.evals.iter().all(|e| e.t.len() == chunks), true);
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+
+        // every chunk is opened and recombined
+        for i in 0..chunks
+        {
+            let mut malformed = proof.clone();
+            malformed.evals[0].t[i] += &Fp::one();
+            assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &malformed)]).is_err(), true);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+/*********************************************************************************************************
+
+This source file tests the degree metrics of the quotient polynomial of the Plonk proof
+for the following computation:
+
+    x * x = y
+
+with x being the public input. Over the generic gates the quotient polynomial is of degree 3n-1,
+driven by the permutation argument, while the degenerate circuit of the zero gates with the identity
+wiring and the zero witness collapses it far below that
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn quotient_degree()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let x = Fp::from(5u64);
+    let (proof, metrics) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.quotient_metrics())).unwrap();
+    assert_eq!(metrics.degree, 3*N-1);
+    assert_eq!(metrics.leading.is_zero(), false);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // degenerate circuit
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let degenerate = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let (_, metrics) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&vec![Fp::zero(); 3*N]), &degenerate, vec![], ProverConfig::new()).map(|(proof, polys)| (proof, polys.quotient_metrics())).unwrap();
+    assert!(metrics.degree < 3*N-1, "degenerate circuit quotient of degree {}", metrics.degree);
+}
@@ -0,0 +1,45 @@
+/*********************************************************************************************************
+
+This source file tests the diagnostic of the quotient identity failure naming the violated constraint rows
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::{ProofError, DivisionSite}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn quotient_diagnostic()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // satisfied constraints
+    let x = Fp::from(5u64);
+    let mut violated = vec![];
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&common::witness(x, N)), &index, vec![], ProverConfig::new().with_diagnostic(&mut violated)).is_ok(), true);
+    assert_eq!(violated, Vec::<usize>::new());
+
+    // the multiplication gate of the row 1 violated
+    let mut wrong = common::witness(x, N);
+    wrong[2*N+1] += Fp::one();
+    match ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&wrong), &index, vec![], ProverConfig::new().with_diagnostic(&mut violated))
+    {
+        Err(ProofError::PolyDivisionAt(DivisionSite::Vanishing)) => assert_eq!(violated, vec![1]),
+        _ => panic!("violated constraint is proven"),
+    }
+}
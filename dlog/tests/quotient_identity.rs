@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file tests the evaluation of the quotient identity from the proof evaluations against
+the quotient polynomial value for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::constraints::ConstraintSystem;
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::ProverProof, range};
+use plonk_circuits::domains::VanishingPolyEval;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn quotient_identity()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    let identity = |proof: &ProverProof<Affine>|
+    {
+        let p_comm = ProverProof::public_comm(&lgr_comms, &proof.public);
+        let (_, _, oracles, alpha, p_eval, evlp, _, _, _) = proof.oracles::<SpongeQ, SpongeR>(&verifier_index, &p_comm).unwrap();
+        let evals = (0..2).map(|i| proof.evals[i].combine(evlp[i])).collect::<Vec<_>>();
+        let zh = verifier_index.domain.vanishing_poly_eval(oracles.zeta);
+        let p = if p_eval[0].len() > 0 {p_eval[0][0]} else {Fp::zero()};
+        let claimed = ConstraintSystem::quot_eval
+        (
+            &evals, p, Fp::zero(), &oracles, &alpha[range::PERM], verifier_index.zkpm.evaluate(oracles.zeta), zh, verifier_index.w
+        );
+        (claimed, evals[0].t * &zh)
+    };
+
+    // the linearization recombines to t(zeta)*zh(zeta) for the valid proof
+    let (claimed, t) = identity(&proof);
+    assert_eq!(claimed, t);
+
+    // and does not for the tampered linearization evaluation
+    let mut tampered = proof.clone();
+    tampered.evals[0].f[0] += &Fp::one();
+    let (claimed, t) = identity(&tampered);
+    assert_ne!(claimed, t);
+}
@@ -0,0 +1,64 @@
+/*********************************************************************************************************
+
+This source file tests the quotient polynomial commitment layout of the Plonk proof for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{Field, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn quotient_layout()
+{
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    // the quotient polynomial is committed in several segments with the SRS of the circuit size
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let lgr_comms = common::lgr_comms(&index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    let layout = proof.quotient_layout(&verifier_index);
+    assert_eq!(layout.bound, index.max_quot_size);
+    assert_eq!(layout.max_poly_size, N);
+    assert_eq!(layout.chunks.len(), proof.commitments.t_comm.unshifted.len());
+    assert_eq!(layout.chunks.len(), proof.evals[0].t.len());
+    assert_eq!(layout.chunks.iter().all(|c| *c <= N), true);
+    assert_eq!(layout.absorbed, (index.max_quot_size + N - 1) / N);
+    assert_eq!(layout.chunks.len() <= layout.absorbed, true);
+    assert_eq!(layout.shifted, Some(index.max_quot_size % N));
+
+    // the segment evaluations recombine into the quotient evaluation
+    let zeta = ProverProof::oracles::<SpongeQ, SpongeR>(&proof, &verifier_index, &ProverProof::public_comm(&lgr_comms, &proof.public)).unwrap().2.zeta;
+    let t = proof.evals[0].t.iter().enumerate().fold(Fp::zero(), |t, (k, e)| t + &(*e * &zeta.pow(&[(k * layout.max_poly_size) as u64])));
+    assert_eq!(t, proof.evals[0].combine(zeta.pow(&[layout.max_poly_size as u64])).t);
+
+    // the quotient polynomial is committed in a single segment with the large enough SRS
+    let srs = SRS::create(index.max_quot_size.next_power_of_two());
+    let index = common::index(&srs, N);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap();
+    let layout = proof.quotient_layout(&index.verifier_index());
+    assert_eq!(layout.chunks.len(), 1);
+    assert_eq!(layout.absorbed, 1);
+    assert_eq!(layout.shifted, Some(index.max_quot_size));
+}
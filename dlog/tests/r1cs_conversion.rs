@@ -0,0 +1,77 @@
+/*********************************************************************************************************
+
+This source file tests the conversion of the R1CS instance into the Plonk circuit for the knowledge
+of the square root x of the public input y:
+
+    x * x = y
+    (x + 1) * (x + 1) = y + 2x + 1
+
+over the assignment z = (1, y, x)
+
+**********************************************************************************************************/
+
+use plonk_circuits::{r1cs::R1CS, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use ff_fft::{Evaluations, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+#[test]
+fn r1cs_conversion()
+{
+    let one = Fp::one();
+    let r1cs = R1CS::<Fp>
+    {
+        a: vec![vec![(2, one)], vec![(2, one), (0, one)]],
+        b: vec![vec![(2, one)], vec![(2, one), (0, one)]],
+        c: vec![vec![(1, one)], vec![(1, one), (2, Fp::from(2u64)), (0, one)]],
+        public: 1,
+        variables: 3,
+    };
+    let assignment = |x: u64, y: u64| vec![one, Fp::from(y), Fp::from(x)];
+
+    let (gates, witness) = r1cs.convert(&assignment(6, 36)).unwrap();
+    let n = gates.len();
+    let srs = SRS::create(n);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, r1cs.public).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = (0..r1cs.public).map(|i| {
+        let mut v = vec![Fp::zero(); i + 1];
+        v[i] = Fp::one();
+        let p = Evaluations::<Fp, D<Fp>>::from_vec_and_domain(v, index.cs.domain.d1).interpolate();
+        index.srs.get_ref().commit_non_hiding(&p, None)
+    }).collect::<Vec<PolyComm<Affine>>>();
+
+    // 6 * 6 = 36
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    assert_eq!(proof.public, vec![Fp::from(36u64)]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the circuit does not depend on the assignment, 5 * 5 != 36
+    let (gates, witness) = r1cs.convert(&assignment(5, 36)).unwrap();
+    assert_eq!(gates.len(), n);
+    assert_eq!(index.cs.verify(&witness), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).is_err(), true);
+
+    // malformed assignments are rejected
+    assert_eq!(r1cs.convert(&assignment(6, 36)[0..2]).is_none(), true);
+    assert_eq!(r1cs.convert(&vec![Fp::zero(), Fp::from(36u64), Fp::from(6u64)]).is_none(), true);
+}
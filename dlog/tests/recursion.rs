@@ -0,0 +1,73 @@
+/*********************************************************************************************************
+
+This source file tests the deferred values of the Plonk proof verification for the recursive composition
+of the proofs for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge, CHALLENGE_LENGTH_IN_LIMBS}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm, b_poly, ceil_log2}};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn recursion()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    let deferred = proof.deferred_values::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+
+    // the deferred values match the ones of the verifier transcript
+    let p_comm = PolyComm::<Affine>::multi_scalar_mul(&lgr_comms.iter().collect(), &proof.public.iter().map(|s| -*s).collect());
+    let (_, _, oracles, _, _, _, _, _, combined_inner_product) = proof.oracles::<SpongeQ, SpongeR>(&verifier_index, &p_comm).unwrap();
+    assert_eq!(deferred.oracles.zeta, oracles.zeta);
+    assert_eq!(deferred.combined_inner_product, combined_inner_product);
+    assert_eq!(deferred.challenges.len(), ceil_log2(N));
+    assert_eq!(deferred.b, b_poly(&deferred.challenges, oracles.zeta) +
+        &(oracles.u * &b_poly(&deferred.challenges, oracles.zeta * &index.cs.domain.d1.group_gen)));
+    assert_eq!(deferred.sg, proof.proof.sg);
+
+    let input = proof.to_public_input::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+    assert_eq!(input, deferred.to_public_input());
+    assert_eq!(input.len(), (6 + ceil_log2(N)) * CHALLENGE_LENGTH_IN_LIMBS + 2 * 4 + 2);
+
+    // the deferred values of the proof malformed against the index structure are not computed
+    let mut truncated = proof.clone();
+    truncated.aux_evals.pop();
+    match truncated.deferred_values::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms)
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("deferred values of the malformed proof are computed"),
+    }
+    assert_eq!(truncated.to_public_input::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).is_err(), true);
+
+    // the next proof of the recursion accumulates the opening challenges of the previous one
+    let next = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(6u64), N), &index, vec![deferred.prev_challenges()]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &next)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the accumulated challenge polynomial commitment has to match the challenges
+    let mut malformed = deferred.clone();
+    malformed.challenges[0] += &Fp::one();
+    let next = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(6u64), N), &index, vec![malformed.prev_challenges()]).unwrap();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &next)]).is_err(), true);
+}
@@ -0,0 +1,66 @@
+/*********************************************************************************************************
+
+This source file tests the layout of the Fiat-Shamir challenges of the Plonk proof as the recursion witness
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge, CHALLENGE_LENGTH_IN_LIMBS}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::{CommitmentCurve, PolyComm}};
+use algebra::{Field, PrimeField, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn recursion_witness()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    let segment = proof.challenges_as_recursion_witness::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap();
+    assert_eq!(segment.len(), 6 * CHALLENGE_LENGTH_IN_LIMBS);
+
+    // the challenges in the transcript order, each as its lower and higher 64-bit limbs
+    let p_comm = PolyComm::<Affine>::multi_scalar_mul(&lgr_comms.iter().collect(), &proof.public.iter().map(|s| -*s).collect());
+    let (_, _, oracles, _, _, _, _, _, _) = proof.oracles::<SpongeQ, SpongeR>(&verifier_index, &p_comm).unwrap();
+    let shift = Fp::from(2u64).pow(&[64]);
+    for (i, chal) in [oracles.beta, oracles.gamma, oracles.alpha_chal.0, oracles.zeta_chal.0, oracles.v_chal.0, oracles.u_chal.0].iter().enumerate()
+    {
+        let limbs = segment[2*i..2*i+2].iter().map(|x| x.into_repr().as_ref()[0]).collect::<Vec<_>>();
+        assert!(segment[2*i..2*i+2].iter().all(|x| x.into_repr().as_ref()[1..].iter().all(|l| *l == 0)));
+        assert_eq!(Fp::from(limbs[0]) + &(Fp::from(limbs[1]) * &shift), *chal);
+    }
+
+    // the witness is bound to the proof transcript
+    let other = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    assert_ne!(other.challenges_as_recursion_witness::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms).unwrap(), segment);
+
+    // the witness of the proof malformed against the index structure is not computed
+    let mut truncated = proof.clone();
+    truncated.evals[1].t.pop();
+    match truncated.challenges_as_recursion_witness::<SpongeQ, SpongeR>(&verifier_index, &lgr_comms)
+    {
+        Err(ProofError::ProofStructure) => {},
+        _ => panic!("recursion witness of the malformed proof is computed"),
+    }
+}
@@ -0,0 +1,29 @@
+/*****************************************************************************************************************
+
+This source file tests the polynomial commitment against the naive reference commitment
+for the random polynomials of several degrees
+
+*****************************************************************************************************************/
+
+use algebra::tweedle::{dee::Affine, Fp};
+use commitment_dlog::{srs::SRS, reference::expected_commitment};
+use ff_fft::DensePolynomial;
+
+#[test]
+fn reference_commitment()
+{
+    let rng = &mut rand::thread_rng();
+
+    let size = 1 << 5;
+    let srs = SRS::<Affine>::create(size);
+
+    // the polynomials within a segment, filling the segment and spanning several segments
+    for degree in [0, 1, 7, size - 1, size, 3 * size + 5].iter()
+    {
+        let poly = DensePolynomial::<Fp>::rand(*degree, rng);
+        let comm = srs.commit_non_hiding(&poly, None);
+        let expected = expected_commitment(&poly, &srs);
+        assert_eq!(comm.unshifted, expected.unshifted, "commitment mismatch at degree {}", degree);
+        assert_eq!(comm.shifted, None);
+    }
+}
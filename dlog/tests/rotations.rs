@@ -0,0 +1,86 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof opened at the configurable evaluation point rotations
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, SRSSpec, ZkConfig}};
+use oracle::rndoracle::ProofError;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn rotations()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+
+    // the default rotations open at zeta and zeta*w
+    let default = common::index(&srs, N);
+    assert_eq!(default.rotations, vec![0, 1]);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &default, vec![]).unwrap();
+    assert_eq!(proof.evals.len(), 2);
+
+    // the next-next row rotation opens at zeta*w^2 as well
+    let rotate = |rotations: Vec<u64>|
+    {
+        let (cs, endo_q) = common::constraints(N, common::PUBLIC);
+        Index::<Affine>::create_with_rotations(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs), rotations)
+    };
+    let mut rotated = rotate(vec![0, 1, 2]).unwrap();
+    rotated.set_zk_config(ZkConfig {wire_degree: 2, ..rotated.zk_config()});
+    let verifier_index = rotated.verifier_index();
+    let lgr_comms = common::lgr_comms(&rotated);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &rotated, vec![]).unwrap();
+    assert_eq!(proof.evals.len(), 3);
+    assert_eq!(proof.aux_evals.len(), 3);
+    assert_eq!(proof.lookup_evals.len(), 3);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the rotated evaluations are serialized
+    let mut bytes = vec![];
+    proof.serialize_to(&mut bytes).unwrap();
+    let restored = ProverProof::<Affine>::deserialize_from(&mut &bytes[..]).unwrap();
+    assert_eq!(restored.evals[2].l, proof.evals[2].l);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &restored)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the proof of the default rotations does not verify against the rotated index
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &default, vec![]).unwrap();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)]).is_err(), true);
+
+    // the constraint rotations come first
+    match rotate(vec![1, 0])
+    {
+        Err(ProofError::EvaluationRotations) => {}
+        _ => panic!("malformed rotations are accepted"),
+    }
+    let mut malformed = common::index(&srs, N);
+    malformed.rotations = vec![1, 0];
+    match ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &malformed, vec![])
+    {
+        Err(ProofError::EvaluationRotations) => {}
+        _ => panic!("malformed rotations are accepted"),
+    }
+}
@@ -0,0 +1,31 @@
+/*********************************************************************************************************
+
+This source file tests the evaluation domain selection of the circuit against the SRS
+
+**********************************************************************************************************/
+
+use commitment_dlog::srs::SRS;
+use algebra::tweedle::dee::Affine;
+use plonk_protocol_dlog::index::select_domains;
+use oracle::rndoracle::ProofError;
+
+#[test]
+fn select_domains_srs()
+{
+    let srs = SRS::<Affine>::create(1024);
+
+    // the gates and the zero-knowledge rows fit the smallest domain
+    let (domains, max_poly_size) = select_domains(1000, &srs).unwrap();
+    assert_eq!(domains.d1.size, 1024);
+    assert_eq!(domains.d8.size, 8 * 1024);
+    assert_eq!(max_poly_size, 1024);
+    assert_eq!(select_domains(1021, &srs).unwrap().0.d1.size, 1024);
+
+    // the zero-knowledge rows overflow the filled domain, the doubled one exceeding the SRS
+    match select_domains(1022, &srs)
+    {
+        Err(ProofError::SrsSize {required, found}) => {assert_eq!(required, 2048); assert_eq!(found, 1024)}
+        _ => panic!("SRS size is not validated"),
+    }
+    assert_eq!(select_domains(1022, &SRS::<Affine>::create(2048)).unwrap().0.d1.size, 2048);
+}
@@ -0,0 +1,64 @@
+/*********************************************************************************************************
+
+This source file tests the selective disclosure proof of the Plonk polynomials
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::selective::{SelectiveProof, PolyId};
+use groupmap::GroupMap;
+use rand::{SeedableRng, rngs::StdRng};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn selective()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let proof = SelectiveProof::create_selective::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, &[PolyId::O]).unwrap();
+
+    // the proof carries the output wire evaluation only
+    assert_eq!(proof.evals.len(), 1);
+    assert_eq!(proof.evals.iter().any(|(id, _)| *id == PolyId::L || *id == PolyId::R), false);
+
+    // the verifier checks the output wire evaluation at zeta
+    let (zeta, evals) = proof.verify::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms).unwrap();
+    assert_eq!(evals[0].0, PolyId::O);
+    assert_eq!(zeta.is_zero(), false);
+
+    // tampered evaluation
+    let mut malformed = proof.clone();
+    malformed.evals[0].1[0] += &Fp::one();
+    assert_eq!(malformed.verify::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms).is_err(), true);
+
+    // tampered unrevealed commitment changes zeta
+    let mut malformed = proof.clone();
+    malformed.commitments.l_comm = malformed.commitments.r_comm.clone();
+    assert_eq!(malformed.verify::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms).is_err(), true);
+
+    // the same seed reproduces the proof, another one blinds it differently
+    let seeded = |seed: u64| SelectiveProof::create_selective_with_rng::<SpongeQ, SpongeR, _, _>
+        (&group_map, &common::witness(x, N), &index, &[PolyId::O], &mut StdRng::seed_from_u64(seed)).unwrap();
+    let (proof, same, other) = (seeded(1), seeded(1), seeded(2));
+    assert_eq!(proof.commitments.o_comm, same.commitments.o_comm);
+    assert_eq!(proof.proof, same.proof);
+    assert_ne!(proof.commitments.o_comm, other.commitments.o_comm);
+    assert!(proof.verify::<SpongeQ, SpongeR, _>(&group_map, &verifier_index, &lgr_comms).is_ok());
+}
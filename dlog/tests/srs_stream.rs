@@ -0,0 +1,141 @@
+/*********************************************************************************************************
+
+This source file tests the polynomial commitments and their openings against the chunk-streamed and the
+memory mapped SRS, and the index key generation against the chunk-streamed SRS for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use commitment_dlog::{srs::SRS, stream::SRSStream, commitment::CommitmentCurve};
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, FqSponge};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, UniformRand};
+use oracle::utils::PolyUtils;
+use rand::{SeedableRng, rngs::StdRng};
+use plonk_protocol_dlog::{prover::ProverProof, index::{Index, IndexCommitments, SRSSpec}};
+use groupmap::GroupMap;
+use ff_fft::DensePolynomial;
+use std::{fs::File, io::{BufReader, Cursor}};
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 64; // SRS size
+
+#[test]
+fn srs_stream()
+{
+    let rng = &mut rand::thread_rng();
+    let srs = SRS::<Affine>::create(N);
+    let mut bytes = Vec::new();
+    srs.write(&mut bytes).unwrap();
+
+    let mut stream = SRSStream::<Affine, _>::open(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(stream.max_degree(), N);
+    assert_eq!(stream.h, srs.h);
+    assert_eq!(stream.chunk(10..20).unwrap(), srs.g[10..20].to_vec());
+    assert_eq!(stream.chunk(N-1..N+1).is_err(), true);
+    assert_eq!(stream.load().unwrap().g, srs.g);
+
+    // segmented, bounded and zero polynomials, chunks not dividing the SRS size included
+    for (size, max) in [(N/2, None), (3*N/2, None), (2*N+5, None), (N-7, Some(N-7)), (N+11, Some(N+11)), (0, Some(N+1))].iter()
+    {
+        let plnm = DensePolynomial::<Fp>::from_coefficients_vec((0..*size).map(|_| Fp::rand(rng)).collect());
+        let comm = srs.commit_non_hiding(&plnm, *max);
+        for chunk in [1, 7, N, 2*N].iter()
+        {
+            assert_eq!(stream.commit_non_hiding(&plnm, *max, *chunk).unwrap(), comm);
+        }
+    }
+
+    // memory mapped SRS file
+    let path = std::env::temp_dir().join("srs_stream.bin");
+    srs.write(&mut File::create(&path).unwrap()).unwrap();
+    let plnm = DensePolynomial::<Fp>::from_coefficients_vec((0..3*N/2).map(|_| Fp::rand(rng)).collect());
+    let mut mapped = SRSStream::<Affine, _>::load_mmap(&path).unwrap();
+    assert_eq!(mapped.commit_non_hiding(&plnm, None, 16).unwrap(), srs.commit_non_hiding(&plnm, None));
+    let mut file = SRSStream::<Affine, _>::open(BufReader::new(File::open(&path).unwrap())).unwrap();
+    assert_eq!(file.commit_non_hiding(&plnm, Some(3*N/2), 16).unwrap(), srs.commit_non_hiding(&plnm, Some(3*N/2)));
+}
+
+#[test]
+fn open_stream()
+{
+    let rng = &mut rand::thread_rng();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let sponge = SpongeQ::new(oracle::tweedle::fq::params());
+
+    // the SRS sizes of a power of two and of the padded one
+    for size in [N, 3*N/4].iter()
+    {
+        let srs = SRS::<Affine>::create(*size);
+        let mut bytes = Vec::new();
+        srs.write(&mut bytes).unwrap();
+        let mut stream = SRSStream::<Affine, _>::open(Cursor::new(bytes)).unwrap();
+
+        let polys = [(*size - 3, None), (2 * *size, None), (*size + 5, Some(*size + 5))].iter().
+            map(|(len, max)| (DensePolynomial::<Fp>::from_coefficients_vec((0..*len).map(|_| Fp::rand(rng)).collect()), *max)).collect::<Vec<_>>();
+        let comms = polys.iter().map(|(p, max)| stream.commit(p, *max, 7, rng).unwrap()).collect::<Vec<_>>();
+        for ((p, max), (comm, omega)) in polys.iter().zip(comms.iter())
+        {
+            assert_eq!(srs.mask_with(&srs.commit_non_hiding(p, *max), omega), *comm);
+        }
+
+        let x = vec![Fp::rand(rng), Fp::rand(rng)];
+        let polyscale = Fp::rand(rng);
+        let evalscale = Fp::rand(rng);
+        let openings = || polys.iter().zip(comms.iter()).map(|((p, max), (_, omega))| (p, *max, omega.clone())).collect::<Vec<_>>();
+
+        // the streamed opening is the one of the loaded SRS with the same randomness
+        let proof = srs.open::<SpongeQ, _>(&group_map, openings(), &x, polyscale, evalscale, sponge.clone(), &mut StdRng::seed_from_u64(7));
+        for chunk in [1, 5, N].iter()
+        {
+            let mut scratch = Cursor::new(Vec::new());
+            let streamed = stream.open::<SpongeQ, _, _>(&group_map, openings(), &x, polyscale, evalscale, sponge.clone(),
+                &mut StdRng::seed_from_u64(7), *chunk, &mut scratch).unwrap();
+            assert_eq!(streamed, proof);
+            assert!(scratch.into_inner().len() <= *size * bytes_per_element());
+        }
+
+        let evals = polys.iter().map(|(p, _)| x.iter().map(|e| p.eval(*e, *size)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let claims = comms.iter().zip(evals.iter()).zip(polys.iter()).map(|(((c, _), e), (_, max))| (c, e.iter().collect::<Vec<_>>(), *max)).collect::<Vec<_>>();
+        assert!(srs.verify::<SpongeQ, _>(&group_map, &mut vec![(sponge.clone(), x.clone(), polyscale, evalscale, claims, &proof)], rng));
+    }
+}
+
+// serialized size of the group element
+fn bytes_per_element() -> usize
+{
+    let mut bytes = Vec::new();
+    algebra::ToBytes::write(&<Affine as algebra::AffineCurve>::prime_subgroup_generator(), &mut bytes).unwrap();
+    bytes.len()
+}
+
+#[test]
+fn index_stream()
+{
+    let srs = SRS::<Affine>::create(N);
+    let mut bytes = Vec::new();
+    srs.write(&mut bytes).unwrap();
+    let mut stream = SRSStream::<Affine, _>::open(Cursor::new(bytes)).unwrap();
+
+    // the index commitments generated against the streamed SRS are the ones of the loaded SRS
+    let (cs, endo_q) = common::constraints(N, common::PUBLIC);
+    let commitments = IndexCommitments::create_streamed(&cs, &mut stream, 7).unwrap();
+    let loaded = IndexCommitments::create(&cs, &srs);
+    assert_eq!(commitments.sigma_comm, loaded.sigma_comm);
+    assert_eq!(commitments.qm_comm, loaded.qm_comm);
+
+    // the index restored from the streamed commitments proves against the loaded SRS
+    let index = Index::<Affine>::create_with_commitments(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs), commitments);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the commitment to the execution trace of the Plonk proof and its row openings
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{Field, tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::{ProverProof, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn trace_commitment()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let (proof, trace) = ProverProof::create_with_trace_commitment::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![], ProverConfig::new()).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    let commitment = &trace.commitment;
+    assert_eq!(commitment.l_comm.unshifted, proof.commitments.l_comm.unshifted);
+
+    // the multiplication row had x, x, x^2 values
+    let opening = trace.open_row::<SpongeQ, _>(&group_map, &index, 1).unwrap();
+    assert_eq!(opening.values[0], vec![x]);
+    assert_eq!(opening.values[1], vec![x]);
+    assert_eq!(opening.values[2], vec![x.square()]);
+    assert_eq!(commitment.verify_row::<SpongeQ, _>(&group_map, &verifier_index, &opening), true);
+
+    // tampered row values and row
+    let mut tampered = opening.clone();
+    tampered.values[2][0] += &Fp::one();
+    assert_eq!(commitment.verify_row::<SpongeQ, _>(&group_map, &verifier_index, &tampered), false);
+    let mut tampered = opening.clone();
+    tampered.row = 2;
+    assert_eq!(commitment.verify_row::<SpongeQ, _>(&group_map, &verifier_index, &tampered), false);
+    assert_eq!(trace.open_row::<SpongeQ, _>(&group_map, &index, N).is_err(), true);
+}
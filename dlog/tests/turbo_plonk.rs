@@ -363,7 +363,7 @@ where <Fp as std::str::FromStr>::Err : std::fmt::Debug
         };
 
         // add the proof to the batch
-        batch.push(ProverProof::create::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>>(
+        batch.push(ProverProof::create::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>, _>(
             &group_map, &witness, &index, vec![prev]).unwrap());
 
         print!("{:?}\r", test);
@@ -372,7 +372,7 @@ where <Fp as std::str::FromStr>::Err : std::fmt::Debug
     println!("{}{:?}", "Execution time: ".yellow(), start.elapsed());
 
     // verify one proof serially
-    match ProverProof::verify::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>>(
+    match ProverProof::verify::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>, _>(
         &group_map, &vec![ (&verifier_index, &lgr_comms, &batch[0]) ])
     {
         Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
@@ -383,7 +383,7 @@ where <Fp as std::str::FromStr>::Err : std::fmt::Debug
     println!("{}", "Verifier zk-proofs verification".green());
     start = Instant::now();
     let batch : Vec<_> = batch.iter().map(|p| (&verifier_index, &lgr_comms, p)).collect();
-    match ProverProof::verify::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>>(
+    match ProverProof::verify::<DefaultFqSponge<TweedledeeParameters, SC>, DefaultFrSponge<Fp, SC>, _>(
         &group_map, &batch)
     {
         Err(error) => {panic!("Failure verifying the prover's proofs in batch: {}", error)},
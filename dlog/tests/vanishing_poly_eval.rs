@@ -0,0 +1,27 @@
+/*********************************************************************************************************
+
+This source file tests the evaluation of the domain vanishing polynomial
+
+**********************************************************************************************************/
+
+use plonk_circuits::domains::VanishingPolyEval;
+use algebra::{tweedle::fp::Fp, UniformRand};
+use ff_fft::{DensePolynomial, EvaluationDomain, Radix2EvaluationDomain as D};
+
+#[test]
+fn vanishing_poly_eval()
+{
+    let rng = &mut rand::thread_rng();
+    for size in [1, 16, 1024].iter()
+    {
+        let domain = D::<Fp>::new(*size).unwrap();
+        let zh: DensePolynomial<Fp> = domain.vanishing_polynomial().into();
+        for _ in 0..10
+        {
+            let zeta = Fp::rand(rng);
+            assert_eq!(domain.vanishing_poly_eval(zeta), zh.evaluate(zeta));
+        }
+        // the polynomial vanishes over the domain
+        assert_eq!(domain.elements().all(|w| domain.vanishing_poly_eval(w) == Fp::from(0u64)), true);
+    }
+}
@@ -0,0 +1,83 @@
+/*********************************************************************************************************
+
+This source file tests the vector lookup argument of the Plonk proof for the bitwise XOR
+
+    o_i = l_i ^ r_i, l_i, r_i in {0, ..., 3}
+
+with l_i, r_i, o_i being the private wire values of the looked up rows
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, lookup::LookupTable};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, Zero, One};
+use plonk_protocol_dlog::{prover::{ProverProof}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 32; // Plonk domain size
+
+#[test]
+fn vector_lookup()
+{
+    // 2-bit XOR table of the rows (l, r, l ^ r)
+    let table = LookupTable::create_vector((0..16u64).map(|i| vec![Fp::from(i & 3), Fp::from(i >> 2), Fp::from((i & 3) ^ (i >> 2))]).collect()).unwrap();
+    let rows = [0, 1, 2, 5];
+
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let cs = cs.with_vector_lookup(&table, &rows).unwrap();
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create(cs, oracle::tweedle::fq::params(), endo_q, SRSSpec::Use(&srs));
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    assert_eq!(verifier_index.lookup_comm.as_ref().unwrap().len(), 4);
+
+    let witness = |x: [(u64, u64, u64); 4]|
+    {
+        let mut witness = vec![Fp::zero(); 3*N];
+        rows.iter().zip(x.iter()).for_each
+        (
+            |(j, (l, r, o))|
+            {
+                witness[*j] = Fp::from(*l);
+                witness[N+*j] = Fp::from(*r);
+                witness[2*N+*j] = Fp::from(*o);
+            }
+        );
+        witness
+    };
+
+    // rows in the table, repeated ones included
+    let witness_in = witness([(1, 2, 3), (3, 3, 0), (1, 2, 3), (0, 1, 1)]);
+    assert_eq!(index.cs.verify(&witness_in), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_in, &index, vec![]).unwrap();
+    assert_eq!(proof.lookup_evals[0].len(), 6);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the lookup proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // tampered table column evaluation
+    let mut malformed = proof.clone();
+    malformed.lookup_evals[0][5][0] += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &malformed)]).is_err(), true);
+
+    // the values of the row are in the table columns, the row is not
+    let witness_out = witness([(1, 2, 3), (3, 3, 1), (1, 2, 3), (0, 1, 1)]);
+    assert_eq!(index.cs.verify(&witness_out), false);
+    assert_eq!(ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness_out, &index, vec![]).is_err(), true);
+
+    // the tables wider than the wires are not supported
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let wide = LookupTable::create_vector(vec![vec![Fp::zero(); 4]]).unwrap();
+    assert_eq!(cs.with_vector_lookup(&wide, &rows).is_none(), true);
+}
+
@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file checks the serialized Plonk proof verified by the verifier-only build of the
+plonk_protocol_dlog crate for the following computation:
+
+    x * x = y
+
+with x being the public input. The ignored regenerate_verifier_fixture test rewrites the committed
+proof, which has to be done whenever the index or the proof layout changes:
+
+    cargo test --release --test verifier_fixture -- --ignored
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+use std::fs::File;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+const FIXTURE: &[u8] = include_bytes!("../plonk/tests/fixtures/verifier_proof.bin");
+
+#[test]
+fn verifier_fixture()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the committed proof verifies against the index of the prover build as well
+    let proof = ProverProof::<Affine>::deserialize_from(&mut &FIXTURE[..]).unwrap();
+    assert_eq!(proof.public, vec![Fp::from(5u64)]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the committed proof: {}", error)},
+        Ok(_) => {}
+    }
+}
+
+#[test]
+#[ignore]
+fn regenerate_verifier_fixture()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &common::lgr_comms(&index), &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("plonk/tests/fixtures/verifier_proof.bin");
+    proof.serialize_to(&mut File::create(&path).unwrap()).unwrap();
+}
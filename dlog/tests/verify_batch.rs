@@ -0,0 +1,83 @@
+/*********************************************************************************************************
+
+This source file tests and times the batch verification of the Plonk proofs of the same index against their
+sequential verification, and tests the batch verification of the proofs against the distinct SRS lengths,
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+use std::time::Instant;
+use colored::Colorize;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+const BATCH: usize = 16;
+
+#[test]
+fn verify_batch()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let proofs = (0..BATCH).map
+    (
+        |i| ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(i as u64 + 2), N), &index, vec![]).unwrap()
+    ).collect::<Vec<_>>();
+
+    let start = Instant::now();
+    match ProverProof::verify_batch::<SpongeQ, SpongeR, _>(&group_map, &proofs, &verifier_index, &lgr_comms)
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    println!("{}{:?}", "batched verification time: ".green(), start.elapsed());
+
+    let start = Instant::now();
+    for proof in proofs.iter()
+    {
+        match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, proof)])
+        {
+            Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+            Ok(_) => {}
+        }
+    }
+    println!("{}{:?}", "sequential verification time: ".green(), start.elapsed());
+
+    // a single invalid proof fails the batch
+    let mut proofs = proofs;
+    proofs[BATCH/2].evals[0].l[0] += &Fp::one();
+    assert_eq!(ProverProof::verify_batch::<SpongeQ, SpongeR, _>(&group_map, &proofs, &verifier_index, &lgr_comms).is_err(), true);
+    assert_eq!(ProverProof::verify_batch::<SpongeQ, SpongeR, _>(&group_map, &[], &verifier_index, &lgr_comms).is_ok(), true);
+
+    // the proofs against the indexes of the distinct SRS lengths are batched per SRS
+    let large_srs = SRS::create(2*N);
+    let large_index = common::index(&large_srs, N);
+    let large_verifier_index = large_index.verifier_index();
+    let large_lgr_comms = common::lgr_comms(&large_index);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(3u64), N), &index, vec![]).unwrap();
+    let mut large_proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(3u64), N), &large_index, vec![]).unwrap();
+    let mixed = vec![(&verifier_index, &lgr_comms, &proof), (&large_verifier_index, &large_lgr_comms, &large_proof), (&verifier_index, &lgr_comms, &proof)];
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &mixed)
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+    large_proof.proof.z1 += &Fp::one();
+    assert_eq!(ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof), (&large_verifier_index, &large_lgr_comms, &large_proof)]).is_err(), true);
+}
@@ -0,0 +1,66 @@
+/*********************************************************************************************************
+
+This source file tests the validation of the witness against the constraint system for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::{CircuitGate, GateType}, constraints::{ConstraintSystem, UnsatisfiedConstraint}};
+use oracle::poseidon::ArithmeticSpongeParams;
+use algebra::{Field, tweedle::fp::Fp, One, Zero};
+mod common;
+
+const N: usize = 16; // Plonk domain size
+const PUBLIC: usize = 1;
+
+#[test]
+fn verify_witness()
+{
+    let cs = cs();
+    let x = Fp::from(5u64);
+    assert_eq!(cs.verify_witness(&common::witness(x, N)), Ok(()));
+
+    // unsatisfied multiplication gate, reported with its wire values
+    let mut unsatisfied = common::witness(x, N);
+    unsatisfied[2*N+1] += &Fp::one();
+    assert_eq!(cs.verify(&unsatisfied), false);
+    assert_eq!
+    (
+        cs.verify_witness(&unsatisfied),
+        Err(UnsatisfiedConstraint::Gate {row: 1, typ: GateType::Generic, wires: (x, x, x.square() + &Fp::one())})
+    );
+
+    // public input distinct from its copies
+    let mut inconsistent = common::witness(x, N);
+    inconsistent[0] += &Fp::one();
+    assert_eq!
+    (
+        cs.verify_witness(&inconsistent),
+        Err(UnsatisfiedConstraint::CopyConstraint {wire: 0, next: 1, values: (x + &Fp::one(), x)})
+    );
+
+    assert_eq!
+    (
+        cs.verify_witness(&common::witness(x, N)[0..2*N]),
+        Err(UnsatisfiedConstraint::WitnessLength {expected: 3*N, found: 2*N})
+    );
+}
+
+// x * x = y circuit with the public input x
+fn cs() -> ConstraintSystem<Fp>
+{
+    let z = Fp::zero();
+    let p = Fp::one();
+    let n = -Fp::one();
+
+    let mut gates = vec!
+    [
+        CircuitGate::<Fp>::create_generic(GateWires::wires((0,   1), (N,   N), (2*N,   2*N)), p, z, z, z, z),
+        CircuitGate::<Fp>::create_generic(GateWires::wires((1, N+1), (N+1, 0), (2*N+1, 2*N+1)), z, z, n, p, z),
+    ];
+    (gates.len()..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+    ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, PUBLIC).unwrap()
+}
@@ -0,0 +1,86 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof with the witness generated by a WASM module
+for the following computation:
+
+    o = l * r
+
+with l and r being the computation inputs
+
+**********************************************************************************************************/
+
+#![cfg(feature = "wasm_witness")]
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem, wasm::WasmWitnessGenerator, witness::WitnessSource};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp}, One, Zero};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverConfig}, index::{Index, SRSSpec}};
+use groupmap::GroupMap;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+// witness function multiplying the lowest limbs of the two inputs
+const MODULE: &str = r#"
+(module
+    (memory (export "memory") 1)
+    (global $top (mut i32) (i32.const 16))
+    (func (export "alloc") (param $size i32) (result i32)
+        (local $p i32)
+        (local.set $p (global.get $top))
+        (global.set $top (i32.add (global.get $top) (local.get $size)))
+        (local.get $p))
+    (func (export "witness") (param $in i32) (param $nin i32) (param $out i32) (param $nout i32) (result i32)
+        (local $n i32) (local $l i64) (local $r i64)
+        (local.set $n (i32.div_u (local.get $nout) (i32.const 3)))
+        (local.set $l (i64.load (local.get $in)))
+        (local.set $r (i64.load (i32.add (local.get $in) (i32.const 32))))
+        (i64.store (local.get $out) (local.get $l))
+        (i64.store (i32.add (local.get $out) (i32.mul (local.get $n) (i32.const 32))) (local.get $r))
+        (i64.store (i32.add (local.get $out) (i32.mul (local.get $n) (i32.const 64))) (i64.mul (local.get $l) (local.get $r)))
+        (i32.const 0)))
+"#;
+
+#[test]
+fn wasm_witness()
+{
+    let z = Fp::zero();
+    let mut gates = vec![CircuitGate::<Fp>::create_generic(GateWires::wires((0, 0), (N, N), (2*N, 2*N)), z, z, -Fp::one(), Fp::one(), z)];
+    (1..N).for_each(|i| gates.push(CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))));
+
+    let srs = SRS::create(N);
+    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<algebra::tweedle::dum::Affine>();
+    let index = Index::<Affine>::create
+    (
+        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap(),
+        oracle::tweedle::fq::params(),
+        endo_q,
+        SRSSpec::Use(&srs)
+    );
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    let generator = WasmWitnessGenerator::create(MODULE.as_bytes()).unwrap();
+    let witness = generator.generate(&[Fp::from(3u64), Fp::from(4u64)], N).unwrap();
+    assert_eq!((witness[0], witness[N], witness[2*N]), (Fp::from(3u64), Fp::from(4u64), Fp::from(12u64)));
+
+    assert_eq!(index.cs.verify(&witness), true);
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witness, &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the witness generated by the prover from the source
+    let proof = ProverProof::create_from_source::<SpongeQ, SpongeR, _>
+        (&group_map, &generator, &[Fp::from(3u64), Fp::from(4u64)], &index, vec![], ProverConfig::new()).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &vec![], &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+}
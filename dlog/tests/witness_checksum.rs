@@ -0,0 +1,50 @@
+/*********************************************************************************************************
+
+This source file tests the witness checksum embedded into the Plonk proof
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn witness_checksum()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let witnesses = [common::witness(Fp::from(5u64), N), common::witness(Fp::from(6u64), N)];
+    let proofs = witnesses.iter().map(|w| ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(w), &index, vec![], ProverConfig::new().with_checksum()).map(|(proof, _)| proof).unwrap()).collect::<Vec<_>>();
+
+    // the checksums identify the witnesses
+    assert_eq!(proofs[0].witness_checksum(), Some(&ProverProof::<Affine>::checksum(&witnesses[0])));
+    assert_eq!(proofs[1].witness_checksum(), Some(&ProverProof::<Affine>::checksum(&witnesses[1])));
+    assert_ne!(proofs[0].witness_checksum(), proofs[1].witness_checksum());
+
+    // and do not affect the verification
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &witnesses[0], &index, vec![]).unwrap();
+    assert_eq!(proof.witness_checksum(), None);
+    let batch = proofs.iter().chain([proof].iter()).map(|p| (&verifier_index, &lgr_comms, p)).collect::<Vec<_>>();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &batch)
+    {
+        Err(error) => {panic!("Failure verifying the prover's proofs in batch: {}", error)},
+        Ok(_) => {}
+    }
+}
@@ -0,0 +1,67 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof created from the i128 witness converted to the field on the fly
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::{ProverProof, ProverWitness, ProverConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+// range checked conversion of the 64 bit values
+fn convert(x: &i128) -> Option<Fp>
+{
+    let a = x.checked_abs()?;
+    if a > u64::MAX as i128 {return None}
+    let a = Fp::from(a as u64);
+    Some(if *x < 0 {-a} else {a})
+}
+
+#[test]
+fn witness_convert()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x: i128 = -5;
+    let mut witness = vec![0i128; 3*N];
+    witness[0] = x;
+    witness[1] = x;
+    witness[N+1] = x;
+    witness[2*N+1] = x * x;
+    assert_eq!(witness.iter().map(|w| convert(w).unwrap()).collect::<Vec<_>>(), common::witness(-Fp::from(5u64), N));
+
+    let value = |i: usize| convert(&witness[i]);
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Converted(witness.len(), &value), &index, vec![], ProverConfig::new()).unwrap();
+    assert_eq!(proof.public, vec![-Fp::from(5u64)]);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // value out of the conversion range
+    witness[N+5] = i128::MAX;
+    let value = |i: usize| convert(&witness[i]);
+    match ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Converted(witness.len(), &value), &index, vec![], ProverConfig::new())
+    {
+        Err(ProofError::WitnessCsInconsistent) => {},
+        _ => panic!("witness out of the conversion range is proven"),
+    }
+}
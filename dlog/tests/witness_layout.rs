@@ -0,0 +1,58 @@
+/*********************************************************************************************************
+
+This source file tests the Plonk proof creation from the witness of the interleaved layout
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::witness::WitnessLayout;
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::{ProverProof, ProverWitness, ProverConfig}, index::ZkConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn witness_layout()
+{
+    let srs = SRS::create(N);
+    let mut index = common::index(&srs, N);
+    // unblinded wire commitments are deterministic in the witness
+    index.set_zk_config(ZkConfig {wire_mask: [false; 3], ..index.zk_config()});
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    let x = Fp::from(5u64);
+    let blocks = common::witness(x, N);
+    let interleaved = (0..N).flat_map(|i| vec![blocks[i], blocks[N+i], blocks[2*N+i]]).collect::<Vec<_>>();
+    assert_eq!(WitnessLayout::Interleaved.to_blocks(&interleaved, N), blocks);
+    assert_eq!(WitnessLayout::default(), WitnessLayout::Blocks);
+
+    let reshaped = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &blocks, &index, vec![]).unwrap();
+    let (proof, _) = ProverProof::prove::<SpongeQ, SpongeR, _>
+        (&group_map, ProverWitness::Field(&interleaved), &index, vec![], ProverConfig::new().with_layout(WitnessLayout::Interleaved)).unwrap();
+
+    assert_eq!(proof.public, reshaped.public);
+    assert_eq!(proof.commitments.l_comm.unshifted, reshaped.commitments.l_comm.unshifted);
+    assert_eq!(proof.commitments.r_comm.unshifted, reshaped.commitments.r_comm.unshifted);
+    assert_eq!(proof.commitments.o_comm.unshifted, reshaped.commitments.o_comm.unshifted);
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof), (&verifier_index, &lgr_comms, &reshaped)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proofs: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the block witness misread as the interleaved one
+    assert_eq!(ProverProof::prove::<SpongeQ, SpongeR, _>(&group_map, ProverWitness::Field(&blocks), &index, vec![], ProverConfig::new().with_layout(WitnessLayout::Interleaved)).is_err(), true);
+}
@@ -0,0 +1,85 @@
+/*********************************************************************************************************
+
+This source file tests the zero permutation denominator reporting of the Plonk prover, which the zero beta
+and gamma challenges expose for the zero wire values, for the following computation:
+
+    x * x = y
+
+with x being the public input, and the zero lookup denominator reporting of the running sum of the range check
+
+    x_i in {0, ..., 7}
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::{GateWires, COLUMNS}, gate::CircuitGate, constraints::ConstraintSystem, lookup::LookupTable};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}, rndoracle::ProofError, FqSponge};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::{tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp, fq::Fq}, Zero};
+use plonk_protocol_dlog::prover::ProverProof;
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+// transcript sponge squeezing the zero challenges
+#[derive(Clone)]
+struct ZeroSponge(SpongeQ);
+
+impl FqSponge<Fq, Affine, Fp> for ZeroSponge
+{
+    fn new(p: ArithmeticSpongeParams<Fq>) -> Self {ZeroSponge(SpongeQ::new(p))}
+    fn absorb_g(&mut self, g: &[Affine]) {self.0.absorb_g(g)}
+    fn absorb_fr(&mut self, x: &[Fp]) {self.0.absorb_fr(x)}
+    fn challenge(&mut self) -> Fp {Fp::zero()}
+    fn challenge_fq(&mut self) -> Fq {self.0.challenge_fq()}
+    fn digest(self) -> Fp {self.0.digest()}
+}
+
+#[test]
+fn zero_denominator()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+    // the right wire of the first row is zero
+    let x = Fp::from(5u64);
+    match ProverProof::create::<ZeroSponge, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![])
+    {
+        Err(ProofError::ZeroDenominator {column, row}) => {assert_eq!(column, 1); assert_eq!(row, 0)}
+        Err(error) => panic!("unexpected error: {}", error),
+        Ok(_) => panic!("proof of the zero denominator is created"),
+    }
+}
+
+#[test]
+fn zero_lookup_denominator()
+{
+    let table = LookupTable::create((0..8).map(|i| vec![Fp::from(i as u64)]).collect()).unwrap();
+    let rows = [0, 1, 2, 5];
+    let gates = (0..N).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (N+i, N+i), (2*N+i, 2*N+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    let cs = cs.with_lookup(&table, &rows).unwrap();
+
+    let mut w = vec![vec![Fp::from(1u64); N]; COLUMNS];
+    rows.iter().zip([3u64, 7, 3, 4].iter()).for_each(|(r, x)| w[0][*r] = Fp::from(*x));
+    let w = w.iter().map(|w| &w[..]).collect::<Vec<_>>();
+    let m = cs.lookup_multiplicities(&w).unwrap();
+    assert_eq!(cs.lookup_sum(&w, &m, Fp::from(9u64), Fp::zero()).is_ok(), true);
+
+    // the looked up value cancels the challenge
+    match cs.lookup_sum(&w, &m, -Fp::from(3u64), Fp::zero())
+    {
+        Err(ProofError::ZeroDenominator {column, row}) => {assert_eq!(column, COLUMNS); assert_eq!(row, 0)}
+        _ => panic!("running sum of the zero denominator is computed"),
+    }
+    // the table value cancels the challenge, the looked up values not
+    match cs.lookup_sum(&w, &m, -Fp::from(6u64), Fp::zero())
+    {
+        Err(ProofError::ZeroDenominator {column, row}) => {assert_eq!(column, COLUMNS+1); assert_eq!(row, 6)}
+        _ => panic!("running sum of the zero denominator is computed"),
+    }
+}
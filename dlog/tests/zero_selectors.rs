@@ -0,0 +1,83 @@
+/*********************************************************************************************************
+
+This source file tests the folding of the identically zero generic constraint selectors for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use plonk_circuits::{wires::GateWires, gate::CircuitGate, constraints::ConstraintSystem};
+use oracle::{poseidon::{ArithmeticSpongeParams, PlonkSpongeConstants as SC}, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::prover::ProverProof;
+use plonk_circuits::polynomial::{WitnessEvals, WitnessShifts, WitnessOverDomains};
+use std::time::Instant;
+use colored::Colorize;
+use ff_fft::{DensePolynomial, Radix2EvaluationDomain as D};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn zero_selectors()
+{
+    let srs = SRS::create(N);
+    let index = common::index(&srs, N);
+    let verifier_index = index.verifier_index();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let lgr_comms = common::lgr_comms(&index);
+
+    // the circuit has neither the right wire nor the constant terms
+    assert_eq!(index.cs.gnrc_zero, [false, true, false, false, true]);
+
+    let proof = ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(Fp::from(5u64), N), &index, vec![]).unwrap();
+    match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&verifier_index, &lgr_comms, &proof)])
+    {
+        Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+        Ok(_) => {}
+    }
+
+    // the folded generic quotient contribution matches the full one over the circuit of the zero
+    // gates, all of its selectors being zero
+    let n = 1 << 12;
+    let gates = (0..n).map(|i| CircuitGate::<Fp>::zero(GateWires::wires((i, i), (n+i, n+i), (2*n+i, 2*n+i)))).collect::<Vec<_>>();
+    let cs = ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params() as ArithmeticSpongeParams<Fp>, 0).unwrap();
+    assert_eq!(cs.gnrc_zero, [true; 5]);
+
+    let rng = &mut rand::thread_rng();
+    let mut evals = |d: D<Fp>| WitnessEvals
+    {
+        l: DensePolynomial::<Fp>::rand(n, rng).evaluate_over_domain_by_ref(d),
+        r: DensePolynomial::<Fp>::rand(n, rng).evaluate_over_domain_by_ref(d),
+        o: DensePolynomial::<Fp>::rand(n, rng).evaluate_over_domain_by_ref(d),
+        z: DensePolynomial::<Fp>::rand(n, rng).evaluate_over_domain_by_ref(d),
+    };
+    let d4 = evals(cs.domain.d4);
+    let d8 = evals(cs.domain.d8);
+    let polys = WitnessOverDomains
+    {
+        d4: WitnessShifts {this: d4.clone(), next: d4},
+        d8: WitnessShifts {this: d8.clone(), next: d8},
+    };
+    let p = DensePolynomial::<Fp>::rand(n, rng);
+
+    let mut start = Instant::now();
+    let (folded, foldedp) = cs.gnrc_quot(&polys, &p);
+    println!("{}{:?}", "folded generic quotient contribution: ".yellow(), start.elapsed());
+
+    start = Instant::now();
+    let this = &polys.d4.this;
+    let full = &(&(&this.l * &this.r) * &cs.qml) + &(&(&(&this.l * &cs.qll) + &(&this.r * &cs.qrl)) + &(&this.o * &cs.qol));
+    let fullp = &cs.qc + &p;
+    println!("{}{:?}", "full generic quotient contribution: ".yellow(), start.elapsed());
+
+    assert_eq!(folded.evals, full.evals);
+    assert_eq!(foldedp, fullp);
+}
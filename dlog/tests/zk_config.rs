@@ -0,0 +1,65 @@
+/*********************************************************************************************************
+
+This source file tests the zero-knowledge blinding configuration of the prover
+for the following computation:
+
+    x * x = y
+
+with x being the public input
+
+**********************************************************************************************************/
+
+use oracle::{poseidon::PlonkSpongeConstants as SC, sponge::{DefaultFqSponge, DefaultFrSponge}};
+use commitment_dlog::{srs::SRS, commitment::CommitmentCurve};
+use algebra::tweedle::{dee::{Affine, TweedledeeParameters}, fp::Fp};
+use plonk_protocol_dlog::{prover::ProverProof, index::ZkConfig};
+use groupmap::GroupMap;
+mod common;
+
+type SpongeQ = DefaultFqSponge<TweedledeeParameters, SC>;
+type SpongeR = DefaultFrSponge<Fp, SC>;
+
+const N: usize = 16; // Plonk domain size
+
+#[test]
+fn zk_config()
+{
+    let srs = SRS::create(N);
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    let x = Fp::from(5u64);
+    assert_eq!(common::index(&srs, N).zk_config(), ZkConfig::default());
+
+    let proofs = [ZkConfig::default(), ZkConfig::none(), ZkConfig {wire_degree: 1, ..ZkConfig::default()}].iter().map
+    (
+        |config|
+        {
+            let mut index = common::index(&srs, N);
+            index.set_zk_config(*config);
+            assert_eq!(index.zk_config(), *config);
+            let lgr_comms = common::lgr_comms(&index);
+            let proofs = (0..2).map(|_| ProverProof::create::<SpongeQ, SpongeR, _>(&group_map, &common::witness(x, N), &index, vec![]).unwrap()).collect::<Vec<_>>();
+            for proof in proofs.iter()
+            {
+                match ProverProof::verify::<SpongeQ, SpongeR, _>(&group_map, &vec![(&index.verifier_index(), &lgr_comms, proof)])
+                {
+                    Err(error) => {panic!("Failure verifying the prover's proof: {}", error)},
+                    Ok(_) => {}
+                }
+            }
+            proofs
+        }
+    ).collect::<Vec<_>>();
+
+    // the blinded commitments differ between the proofs of the same witness
+    assert_ne!(proofs[0][0].commitments.l_comm, proofs[0][1].commitments.l_comm);
+    assert_ne!(proofs[0][0].commitments.z_comm, proofs[0][1].commitments.z_comm);
+    assert_ne!(proofs[0][0].commitments.t_comm, proofs[0][1].commitments.t_comm);
+
+    // the commitments are determined by the witness without the zero-knowledge
+    assert_eq!(proofs[1][0].commitments.l_comm, proofs[1][1].commitments.l_comm);
+    assert_eq!(proofs[1][0].commitments.z_comm, proofs[1][1].commitments.z_comm);
+    assert_eq!(proofs[1][0].commitments.t_comm, proofs[1][1].commitments.t_comm);
+
+    // the wires blinded with the degree 1 polynomial take two segments
+    assert_eq!(proofs[2][0].commitments.l_comm.unshifted.len(), 2);
+}
@@ -7,6 +7,16 @@ This source file implements the random oracle argument API.
 use std::fmt;
 pub use super::poseidon::{ArithmeticSpongeParams, ArithmeticSponge, Sponge};
 
+// polynomial division site of the prover
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionSite
+{
+    Vanishing,              // constraint contributions by the vanishing polynomial
+    PermutationBoundary,    // permutation product boundary conditions
+    LookupBoundary,         // lookup running sum boundary conditions
+    WireBlinding,           // externally blinded wire polynomials by the vanishing polynomial
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ProofError
 {
@@ -23,7 +33,38 @@ pub enum ProofError
     ConstraintInconsist,
     EvaluationGroup,
     OracleCommit,
-    RuntimeEnv
+    RuntimeEnv,
+    ProofStructure,
+    WitnessBlinding,
+    ProofFreshness,
+    PublicInputSchema,
+    GateDegree,
+    UnsupportedVersion {found: u32, supported: u32},
+    PublicInputCommitment,
+    DeadlineExceeded,
+    PolyDivisionAt(DivisionSite),
+    WitnessLength {expected: usize, found: usize},
+    ZeroDenominator {column: usize, row: usize},
+    GateUnsatisfied(usize),
+    EvaluationRotations,
+    CopyConstraint {wire: usize, next: usize},
+    SrsSize {required: usize, found: usize},
+    SrsMismatch,
+    BlindingDegree {degree: usize, gate_degree: usize, bound: usize},
+}
+
+impl fmt::Display for DivisionSite
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            DivisionSite::Vanishing => write!(f, "constraint contributions by the vanishing polynomial"),
+            DivisionSite::PermutationBoundary => write!(f, "permutation boundary conditions"),
+            DivisionSite::LookupBoundary => write!(f, "lookup boundary conditions"),
+            DivisionSite::WireBlinding => write!(f, "blinded wire polynomials by the vanishing polynomial"),
+        }
+    }
 }
 
 // Implement `Display` for ProofError
@@ -31,6 +72,47 @@ impl fmt::Display for ProofError
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
     {
-        write!(f, "({})", self)
+        match self
+        {
+            ProofError::WitnessCsInconsistent => write!(f, "witness is inconsistent with the constraint system"),
+            ProofError::DomainCreation => write!(f, "evaluation domain creation failed"),
+            ProofError::PolyDivision => write!(f, "polynomial division failed"),
+            ProofError::PolyCommit => write!(f, "polynomial commitment failed"),
+            ProofError::PolyCommitWithBound => write!(f, "polynomial commitment with degree bound failed"),
+            ProofError::PolyExponentiate => write!(f, "polynomial exponentiation failed"),
+            ProofError::ProofCreation => write!(f, "proof creation failed"),
+            ProofError::ProofVerification => write!(f, "proof verification failed"),
+            ProofError::OpenProof => write!(f, "opening proof verification failed"),
+            ProofError::SumCheck => write!(f, "sum check failed"),
+            ProofError::ConstraintInconsist => write!(f, "constraints are inconsistent"),
+            ProofError::EvaluationGroup => write!(f, "evaluation group check failed"),
+            ProofError::OracleCommit => write!(f, "oracle commitment failed"),
+            ProofError::RuntimeEnv => write!(f, "runtime environment error"),
+            ProofError::ProofStructure => write!(f, "proof structure is inconsistent with the index"),
+            ProofError::WitnessBlinding => write!(f, "wire blinding is invalid"),
+            ProofError::ProofFreshness => write!(f, "proof is not fresh"),
+            ProofError::PublicInputSchema => write!(f, "public input does not match the schema"),
+            ProofError::GateDegree => write!(f, "gate degree exceeds the quotient bound"),
+            ProofError::UnsupportedVersion {found, supported} =>
+                write!(f, "unsupported proof version {}, latest supported version is {}", found, supported),
+            ProofError::PublicInputCommitment => write!(f, "public input commitment mismatch"),
+            ProofError::DeadlineExceeded => write!(f, "proving deadline exceeded"),
+            ProofError::PolyDivisionAt(site) => write!(f, "polynomial division of the {} failed", site),
+            ProofError::WitnessLength {expected, found} =>
+                write!(f, "witness length {} does not match the expected {}", found, expected),
+            ProofError::ZeroDenominator {column, row} =>
+                write!(f, "permutation or lookup denominator of the column {} at the row {} is zero", column, row),
+            ProofError::GateUnsatisfied(gate) => write!(f, "gate {} is unsatisfied by the witness", gate),
+            ProofError::EvaluationRotations => write!(f, "evaluation point rotations do not start with the 0 and 1 ones"),
+            ProofError::CopyConstraint {wire, next} =>
+                write!(f, "wires {} and {} of the permutation cycle hold distinct values", wire, next),
+            ProofError::SrsSize {required, found} =>
+                write!(f, "SRS size {} is smaller than the required {}", found, required),
+            ProofError::SrsMismatch => write!(f, "index is not over the shared SRS"),
+            ProofError::BlindingDegree {degree, gate_degree, bound} =>
+                write!(f, "wire blinding degree {} lowers the quotient bound to the constraint degree {} below the gate degree {}", degree, bound, gate_degree),
+        }
     }
 }
+
+impl std::error::Error for ProofError {}